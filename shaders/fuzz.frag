@@ -0,0 +1,19 @@
+#version 450
+
+layout(set = 1, binding = 0) uniform sampler2D texture_sampler;
+
+layout(location = 0) in vec2 in_texture_coord;
+
+layout(location = 0) out vec4 out_color;
+
+void main() {
+	vec4 texture_texel = texture(texture_sampler, in_texture_coord);
+
+	// Draw a scattered pattern of dark pixels in the shape of the sprite
+	float noise = fract(sin(dot(floor(gl_FragCoord.xy), vec2(12.9898, 78.233))) * 43758.5453);
+
+	if (texture_texel.a < 0.5 || noise < 0.5)
+		discard;
+
+	out_color = vec4(0.0, 0.0, 0.0, 1.0);
+}
@@ -3,6 +3,7 @@
 layout(set = 0, binding = 0) uniform Matrices {
 	mat4 proj;
 	mat4 view;
+	uint fixed_colormap;
 };
 
 layout(location = 0) in vec3 in_position;
@@ -11,6 +12,7 @@ layout(location = 2) in float in_light_level;
 
 layout(location = 0) out vec2 out_texture_coord;
 layout(location = 1) out float out_light_level;
+layout(location = 2) flat out uint out_fixed_colormap;
 
 out gl_PerVertex {
 	vec4 gl_Position;
@@ -20,4 +22,5 @@ void main() {
 	gl_Position = proj * view * vec4(in_position, 1.0);
 	out_texture_coord = in_texture_coord;
 	out_light_level = in_light_level;
+	out_fixed_colormap = fixed_colormap;
 }
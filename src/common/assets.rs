@@ -216,6 +216,28 @@ impl AssetStorage {
 			}
 		}
 	}
+
+	/// Throws away the imported data of unprocessed assets of the given type, instead of
+	/// processing it. The handles stay valid, but `get` will return `None` for them.
+	/// This is used when the things needed to process an asset type, like a GPU, are not there.
+	#[inline]
+	pub fn discard_unprocessed<A: Asset>(&mut self) {
+		let storage = storage_mut::<A>(&mut self.storages);
+		let unprocessed = if let Some(unprocessed) = &mut storage.unprocessed {
+			std::mem::replace(unprocessed, Vec::new())
+		} else {
+			return;
+		};
+
+		for (handle, data, name) in unprocessed {
+			if let Err(e) = data {
+				panic!("\"{}\" could not be loaded: {}", name, e);
+			}
+
+			log::trace!("Discarded \"{}\" with id {}", name, handle.id());
+			storage.handles.push(handle);
+		}
+	}
 }
 
 #[inline]
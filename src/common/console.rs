@@ -144,8 +144,9 @@ fn tokenize(mut text: &str) -> anyhow::Result<Vec<String>> {
 	// Whitespace, except newlines
 	static RE_SPACE: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^[^\S\n]+"#).unwrap());
 
-	// C identifier or number literal
-	static RE_UNQUOTED: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^[=+-]?[.0-9A-Za-z_]+"#).unwrap());
+	// C identifier, number literal or --option
+	static RE_UNQUOTED: Lazy<Regex> =
+		Lazy::new(|| Regex::new(r#"^(?:--|[=+-])?[.0-9A-Za-z_-]+"#).unwrap());
 
 	// Quoted string, with escapes
 	static RE_QUOTED: Lazy<Regex> = Lazy::new(|| Regex::new(r#"^"(?:[^"\\]*(?:\\.)?)*""#).unwrap());
@@ -8,6 +8,7 @@ pub mod geometry;
 pub mod input;
 pub mod logger;
 pub mod quadtree;
+pub mod random;
 pub mod sound;
 pub mod spawn;
 pub mod time;
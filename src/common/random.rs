@@ -0,0 +1,131 @@
+use rand::{Error, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Random number generator whose state can be saved and restored, so that a game run with the
+/// same seed and the same inputs always plays out the same way.
+///
+/// This is the xoshiro256++ algorithm.
+#[derive(Clone, Debug, Hash, Serialize, Deserialize)]
+pub struct GameRng {
+	state: [u64; 4],
+}
+
+impl RngCore for GameRng {
+	#[inline]
+	fn next_u32(&mut self) -> u32 {
+		(self.next_u64() >> 32) as u32
+	}
+
+	#[inline]
+	fn next_u64(&mut self) -> u64 {
+		let s = &mut self.state;
+		let result = (s[0].wrapping_add(s[3])).rotate_left(23).wrapping_add(s[0]);
+		let t = s[1] << 17;
+
+		s[2] ^= s[0];
+		s[3] ^= s[1];
+		s[1] ^= s[2];
+		s[0] ^= s[3];
+		s[2] ^= t;
+		s[3] = s[3].rotate_left(45);
+
+		result
+	}
+
+	#[inline]
+	fn fill_bytes(&mut self, dest: &mut [u8]) {
+		for chunk in dest.chunks_mut(8) {
+			let bytes = self.next_u64().to_le_bytes();
+			chunk.copy_from_slice(&bytes[..chunk.len()]);
+		}
+	}
+
+	#[inline]
+	fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+		self.fill_bytes(dest);
+		Ok(())
+	}
+}
+
+impl SeedableRng for GameRng {
+	type Seed = [u8; 32];
+
+	fn from_seed(seed: [u8; 32]) -> GameRng {
+		let mut state = [0; 4];
+
+		for (s, chunk) in state.iter_mut().zip(seed.chunks_exact(8)) {
+			*s = u64::from_le_bytes(chunk.try_into().unwrap());
+		}
+
+		// The generator never gets out of an all-zero state
+		if state == [0; 4] {
+			state[0] = 1;
+		}
+
+		GameRng { state }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::doom::{
+		game::{demo::world_checksum, new_game, options::GameOptions},
+		testing::{self, TELEPORTER_MAP},
+	};
+
+	#[test]
+	fn reference_output() {
+		let mut seed = [0; 32];
+
+		for (i, chunk) in seed.chunks_exact_mut(8).enumerate() {
+			chunk[0] = i as u8 + 1;
+		}
+
+		// From the reference implementation at https://prng.di.unimi.it/xoshiro256plusplus.c
+		let expected: [u64; 10] = [
+			41943041,
+			58720359,
+			3588806011781223,
+			3591011842654386,
+			9228616714210784205,
+			9973669472204895162,
+			14011001112246962877,
+			12406186145184390807,
+			15849039046786891736,
+			10450023813501588000,
+		];
+
+		let mut rng = GameRng::from_seed(seed);
+
+		for value in expected {
+			assert_eq!(rng.next_u64(), value);
+		}
+	}
+
+	/// Plays a game on the teleporter map for ten seconds, and returns the checksum of the world
+	/// at the end.
+	fn play_game(seed: u64) -> u64 {
+		let (mut world, mut resources) = testing::init();
+		let mut update_systems = testing::update_systems(&mut resources);
+		let options = GameOptions {
+			players: 1,
+			seed: Some(seed),
+			..GameOptions::default()
+		};
+		new_game(TELEPORTER_MAP, options, &mut world, &mut resources);
+		testing::walk_forward(&mut resources);
+
+		for _ in 0..350 {
+			update_systems.execute(&mut world, &mut resources);
+		}
+
+		world_checksum(&world, &resources)
+	}
+
+	#[test]
+	fn same_seed_plays_the_same() {
+		assert_eq!(play_game(1), play_game(1));
+		assert_ne!(play_game(1), play_game(2));
+	}
+}
@@ -1,4 +1,4 @@
-use legion::{systems::Runnable, SystemBuilder};
+use legion::{Resources, World};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -8,14 +8,17 @@ pub struct GameTime(pub Duration);
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct DeltaTime(pub Duration);
 
-pub fn increment_game_time() -> impl Runnable {
-	SystemBuilder::new("increment_game_time")
-		.read_resource::<DeltaTime>()
-		.write_resource::<GameTime>()
-		.build(move |_command_buffer, _world, resources, _query| {
-			let (delta_time, game_time) = resources;
-			game_time.0 += delta_time.0;
-		})
+/// Advances the game time by one tick. Nothing happens when no game is running, for example
+/// while a client waits for the server to send the map.
+pub fn increment_game_time(_world: &mut World, resources: &mut Resources) {
+	let delta_time = match resources.get::<DeltaTime>() {
+		Some(x) => x.0,
+		None => return,
+	};
+
+	if let Some(mut game_time) = resources.get_mut::<GameTime>() {
+		game_time.0 += delta_time;
+	}
 }
 
 /// A timer that elapses at the specified time
@@ -47,6 +50,11 @@ impl Timer {
 		game_time.0 >= self.target_time
 	}
 
+	/// Returns the time left until the timer elapses, or zero if it already has.
+	pub fn remaining(&self, game_time: GameTime) -> Duration {
+		self.target_time.saturating_sub(game_time.0)
+	}
+
 	pub fn restart(&mut self, game_time: GameTime) {
 		self.target_time = game_time.0 + self.wait_time;
 	}
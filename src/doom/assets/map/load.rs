@@ -1278,6 +1278,8 @@ pub fn process_map(asset_storage: &mut AssetStorage, iwadinfo: &IWADInfo) {
 			switches: get_switches(asset_storage),
 			exit: map_info.exit.map(|s| s.to_owned()),
 			secret_exit: map_info.secret_exit.map(|s| s.to_owned()),
+			par_time: map_info.par_time,
+			boss_specials: map_info.boss_specials.to_vec(),
 
 			linedefs,
 			nodes,
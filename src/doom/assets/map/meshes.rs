@@ -324,7 +324,7 @@ pub fn make_meshes(
 			// Floor
 			let iter = segs.iter().map(|seg| &seg.line.point).rev();
 
-			match &sector.textures[SectorSlot::Floor as usize] {
+			match &sector_dynamic.textures[SectorSlot::Floor as usize] {
 				TextureType::None => (),
 				TextureType::Sky => push_sky_flat(
 					&mut sky_mesh.0,
@@ -357,7 +357,7 @@ pub fn make_meshes(
 			// Ceiling
 			let iter = segs.iter().map(|seg| &seg.line.point);
 
-			match &sector.textures[SectorSlot::Ceiling as usize] {
+			match &sector_dynamic.textures[SectorSlot::Ceiling as usize] {
 				TextureType::None => (),
 				TextureType::Sky => push_sky_flat(
 					&mut sky_mesh.0,
@@ -1,6 +1,8 @@
 pub mod load;
 pub mod meshes;
 pub mod textures;
+#[cfg(test)]
+pub mod write;
 
 use crate::{
 	common::{
@@ -12,7 +14,7 @@ use crate::{
 			image::Image,
 			map::{load::LinedefFlags, textures::TextureType},
 		},
-		game::{map::MapDynamic, physics::SolidBits, trace::CollisionPlane},
+		game::{boss::BossSpecial, map::MapDynamic, physics::SolidBits, trace::CollisionPlane},
 	},
 };
 use bitflags::bitflags;
@@ -30,6 +32,8 @@ pub struct Map {
 	pub switches: FnvHashMap<AssetHandle<Image>, AssetHandle<Image>>,
 	pub exit: Option<String>,
 	pub secret_exit: Option<String>,
+	pub par_time: Option<Duration>,
+	pub boss_specials: Vec<BossSpecial>,
 
 	pub linedefs: Vec<Linedef>,
 	pub nodes: Vec<Node>,
@@ -338,6 +342,24 @@ impl Map {
 			.unwrap_or(32768.0)
 	}
 
+	pub fn lowest_neighbour_light(&self, map_dynamic: &MapDynamic, sector_index: usize) -> f32 {
+		self.sectors[sector_index]
+			.neighbours
+			.iter()
+			.map(|index| map_dynamic.sectors[*index].light_level)
+			.min_by(|x, y| x.partial_cmp(y).unwrap())
+			.unwrap_or(map_dynamic.sectors[sector_index].light_level)
+	}
+
+	pub fn highest_neighbour_light(&self, map_dynamic: &MapDynamic, sector_index: usize) -> f32 {
+		self.sectors[sector_index]
+			.neighbours
+			.iter()
+			.map(|index| map_dynamic.sectors[*index].light_level)
+			.max_by(|x, y| x.partial_cmp(y).unwrap())
+			.unwrap_or(0.0)
+	}
+
 	/*pub fn highest_neighbour_ceiling(&self, map_dynamic: &MapDynamic, sector_index: usize) -> f32 {
 		self.sectors[sector_index]
 			.neighbours
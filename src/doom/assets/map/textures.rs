@@ -158,7 +158,7 @@ pub fn import_textures(
 	))
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TextureType {
 	Normal(AssetHandle<Image>),
 	Sky,
@@ -0,0 +1,887 @@
+//! Writing maps to a WAD. The engine itself doesn't need this, it is meant for tests that make
+//! maps in code.
+
+use crate::{
+	common::geometry::{Side, AABB2},
+	doom::assets::{
+		map::{load::LinedefFlags, NodeChild, ThingFlags},
+		wad::{write_string, WadWriter},
+	},
+};
+use anyhow::{ensure, Context};
+use byteorder::{WriteBytesExt, LE};
+use nalgebra::Vector2;
+use std::borrow::Cow;
+
+/// Distance below which points are considered to lie on a line, when building nodes.
+const EPSILON: f64 = 1.0 / 1024.0;
+
+#[derive(Clone, Debug)]
+pub struct ThingData {
+	pub position: Vector2<i16>,
+
+	/// In degrees.
+	pub angle: u16,
+
+	pub r#type: u16,
+	pub flags: ThingFlags,
+}
+
+#[derive(Clone, Debug)]
+pub struct LinedefData {
+	pub vertices: [u16; 2],
+	pub flags: LinedefFlags,
+	pub special_type: u16,
+	pub sector_tag: u16,
+
+	/// Front and back sidedef.
+	pub sidedefs: [Option<u16>; 2],
+}
+
+#[derive(Clone, Debug)]
+pub struct SidedefData {
+	pub texture_offset: Vector2<i16>,
+
+	/// Texture names, indexed by `SidedefSlot`. `None` is no texture.
+	pub textures: [Option<String>; 3],
+
+	pub sector: u16,
+}
+
+#[derive(Clone, Debug)]
+pub struct SectorData {
+	pub floor_height: i16,
+	pub ceiling_height: i16,
+
+	/// Flat names, indexed by `SectorSlot`.
+	pub textures: [String; 2],
+
+	pub light_level: u16,
+	pub special_type: u16,
+	pub sector_tag: u16,
+}
+
+/// GL nodes, in the version 2 format made by glBSP.
+#[derive(Clone, Debug, Default)]
+pub struct GLNodesData {
+	/// Vertices created by splitting segs, in addition to the map's own vertices.
+	pub vertexes: Vec<Vector2<f32>>,
+
+	pub segs: Vec<GLSegData>,
+	pub subsectors: Vec<GLSubsectorData>,
+
+	/// Nodes in the order they are stored in the WAD, with the root node last.
+	/// `NodeChild::Node` indices refer to this order.
+	pub nodes: Vec<GLNodeData>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub enum GLVertexIndex {
+	/// Index into the map's vertices.
+	Vertex(u16),
+
+	/// Index into the vertices of the GL nodes.
+	GLVertex(u16),
+}
+
+#[derive(Clone, Debug)]
+pub struct GLSegData {
+	pub vertices: [GLVertexIndex; 2],
+
+	/// The linedef that the seg runs along, and which side of it the seg is on.
+	/// `None` for segs that only close off a subsector.
+	pub linedef: Option<(u16, Side)>,
+
+	/// The seg on the other side of the same line, if any.
+	pub partner_seg: Option<u16>,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct GLSubsectorData {
+	pub seg_count: u16,
+	pub first_seg: u16,
+}
+
+#[derive(Clone, Debug)]
+pub struct GLNodeData {
+	pub partition_point: Vector2<i16>,
+	pub partition_dir: Vector2<i16>,
+
+	/// Bounding boxes of the right and left child.
+	pub child_bboxes: [AABB2; 2],
+
+	/// The right and left child.
+	pub child_indices: [NodeChild; 2],
+}
+
+/// Builds the lumps of a map, to be added to a `WadWriter`. This lets maps be made in code.
+///
+/// Only the lumps that Ferret needs are filled in; the standard node lumps are left empty.
+/// GL nodes are always written instead, and are built from the linedefs if none are given.
+#[derive(Clone, Debug, Default)]
+pub struct MapBuilder {
+	pub things: Vec<ThingData>,
+	pub linedefs: Vec<LinedefData>,
+	pub sidedefs: Vec<SidedefData>,
+	pub vertexes: Vec<Vector2<i16>>,
+	pub sectors: Vec<SectorData>,
+
+	/// GL nodes to write. If `None`, they are built when the map is written.
+	pub gl_nodes: Option<GLNodesData>,
+}
+
+impl MapBuilder {
+	pub fn new() -> MapBuilder {
+		MapBuilder::default()
+	}
+
+	pub fn add_thing(&mut self, thing: ThingData) -> u16 {
+		self.things.push(thing);
+		self.things.len() as u16 - 1
+	}
+
+	pub fn add_linedef(&mut self, linedef: LinedefData) -> u16 {
+		self.linedefs.push(linedef);
+		self.linedefs.len() as u16 - 1
+	}
+
+	pub fn add_sidedef(&mut self, sidedef: SidedefData) -> u16 {
+		self.sidedefs.push(sidedef);
+		self.sidedefs.len() as u16 - 1
+	}
+
+	pub fn add_vertex(&mut self, vertex: Vector2<i16>) -> u16 {
+		self.vertexes.push(vertex);
+		self.vertexes.len() as u16 - 1
+	}
+
+	pub fn add_sector(&mut self, sector: SectorData) -> u16 {
+		self.sectors.push(sector);
+		self.sectors.len() as u16 - 1
+	}
+
+	/// Adds the lumps of the map to `wad`, under the map name `name`.
+	pub fn write(&self, name: &str, wad: &mut WadWriter) -> anyhow::Result<()> {
+		self.write_lumps(name, wad)
+			.with_context(|| format!("Couldn't write map \"{}\"", name))
+	}
+
+	fn write_lumps(&self, name: &str, wad: &mut WadWriter) -> anyhow::Result<()> {
+		self.check()?;
+
+		let gl_nodes = match &self.gl_nodes {
+			Some(gl_nodes) => Cow::Borrowed(gl_nodes),
+			None => Cow::Owned(self.build_gl_nodes().context("Couldn't build GL nodes")?),
+		};
+
+		wad.add(name, Vec::new())?;
+		wad.add("things", self.things_lump()?)?;
+		wad.add("linedefs", self.linedefs_lump()?)?;
+		wad.add("sidedefs", self.sidedefs_lump()?)?;
+		wad.add("vertexes", self.vertexes_lump()?)?;
+		wad.add("segs", Vec::new())?;
+		wad.add("ssectors", Vec::new())?;
+		wad.add("nodes", Vec::new())?;
+		wad.add("sectors", self.sectors_lump()?)?;
+		wad.add("reject", Vec::new())?;
+		wad.add("blockmap", Vec::new())?;
+
+		wad.add(&format!("gl_{}", name), Vec::new())?;
+		wad.add("gl_vert", gl_nodes.vert_lump()?)?;
+		wad.add("gl_segs", gl_nodes.segs_lump()?)?;
+		wad.add("gl_ssect", gl_nodes.ssect_lump()?)?;
+		wad.add("gl_nodes", gl_nodes.nodes_lump()?)?;
+
+		Ok(())
+	}
+
+	/// Checks that all indices point to something that exists.
+	fn check(&self) -> anyhow::Result<()> {
+		for (i, linedef) in self.linedefs.iter().enumerate() {
+			for &index in &linedef.vertices {
+				ensure!(
+					(index as usize) < self.vertexes.len(),
+					"Linedef {} has invalid vertex index {}",
+					i,
+					index
+				);
+			}
+
+			for &index in linedef.sidedefs.iter().flatten() {
+				ensure!(
+					(index as usize) < self.sidedefs.len(),
+					"Linedef {} has invalid sidedef index {}",
+					i,
+					index
+				);
+			}
+		}
+
+		for (i, sidedef) in self.sidedefs.iter().enumerate() {
+			ensure!(
+				(sidedef.sector as usize) < self.sectors.len(),
+				"Sidedef {} has invalid sector index {}",
+				i,
+				sidedef.sector
+			);
+		}
+
+		Ok(())
+	}
+
+	/// Builds GL nodes for the map, by splitting it along its linedefs until every part is
+	/// convex. The subsectors are closed off with minisegs along the partition lines.
+	fn build_gl_nodes(&self) -> anyhow::Result<GLNodesData> {
+		let mut segs = Vec::new();
+
+		for (i, linedef) in self.linedefs.iter().enumerate() {
+			let [start, end] = linedef
+				.vertices
+				.map(|index| self.vertexes[index as usize].cast::<f64>());
+
+			if linedef.sidedefs[0].is_some() {
+				segs.push(BuildSeg {
+					start,
+					end,
+					linedef: i as u16,
+					side: Side::Right,
+				});
+			}
+
+			if linedef.sidedefs[1].is_some() {
+				segs.push(BuildSeg {
+					start: end,
+					end: start,
+					linedef: i as u16,
+					side: Side::Left,
+				});
+			}
+		}
+
+		ensure!(!segs.is_empty(), "Map has no sidedefs");
+
+		// Start out with the bounding box of the map, which is then cut into pieces
+		let mut bbox = AABB2::empty();
+
+		for vertex in &self.vertexes {
+			bbox.add_point(vertex.cast());
+		}
+
+		let (min, max) = (bbox.min().cast::<f64>(), bbox.max().cast::<f64>());
+		let region = vec![
+			Vector2::new(min[0], max[1]),
+			max,
+			Vector2::new(max[0], min[1]),
+			min,
+		];
+
+		let first_seg = segs[0];
+		let mut builder = NodeBuilder {
+			map: self,
+			gl_nodes: GLNodesData::default(),
+		};
+		let (root, bbox) = builder.build(segs, region)?;
+
+		// Ferret always starts at a node, so a map that is a single subsector still needs one
+		if let NodeChild::Subsector(_) = root {
+			let (partition_point, partition_dir) = builder.partition(&first_seg);
+			builder.gl_nodes.nodes.push(GLNodeData {
+				partition_point,
+				partition_dir,
+				child_bboxes: [bbox, bbox],
+				child_indices: [root, root],
+			});
+		}
+
+		Ok(builder.gl_nodes)
+	}
+
+	fn things_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.things.len() * 10);
+
+		for thing in &self.things {
+			data.write_i16::<LE>(thing.position[0])?;
+			data.write_i16::<LE>(thing.position[1])?;
+			data.write_u16::<LE>(thing.angle)?;
+			data.write_u16::<LE>(thing.r#type)?;
+			data.write_u16::<LE>(thing.flags.bits())?;
+		}
+
+		Ok(data)
+	}
+
+	fn linedefs_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.linedefs.len() * 14);
+
+		for linedef in &self.linedefs {
+			data.write_u16::<LE>(linedef.vertices[0])?;
+			data.write_u16::<LE>(linedef.vertices[1])?;
+			data.write_u16::<LE>(linedef.flags.bits())?;
+			data.write_u16::<LE>(linedef.special_type)?;
+			data.write_u16::<LE>(linedef.sector_tag)?;
+			data.write_u16::<LE>(linedef.sidedefs[0].unwrap_or(0xFFFF))?;
+			data.write_u16::<LE>(linedef.sidedefs[1].unwrap_or(0xFFFF))?;
+		}
+
+		Ok(data)
+	}
+
+	fn sidedefs_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.sidedefs.len() * 30);
+
+		for sidedef in &self.sidedefs {
+			data.write_i16::<LE>(sidedef.texture_offset[0])?;
+			data.write_i16::<LE>(sidedef.texture_offset[1])?;
+
+			for texture in &sidedef.textures {
+				write_string(&mut data, texture.as_deref().unwrap_or("-"))?;
+			}
+
+			data.write_u16::<LE>(sidedef.sector)?;
+		}
+
+		Ok(data)
+	}
+
+	fn vertexes_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.vertexes.len() * 4);
+
+		for vertex in &self.vertexes {
+			data.write_i16::<LE>(vertex[0])?;
+			data.write_i16::<LE>(vertex[1])?;
+		}
+
+		Ok(data)
+	}
+
+	fn sectors_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.sectors.len() * 26);
+
+		for sector in &self.sectors {
+			data.write_i16::<LE>(sector.floor_height)?;
+			data.write_i16::<LE>(sector.ceiling_height)?;
+			write_string(&mut data, &sector.textures[0])?;
+			write_string(&mut data, &sector.textures[1])?;
+			data.write_u16::<LE>(sector.light_level)?;
+			data.write_u16::<LE>(sector.special_type)?;
+			data.write_u16::<LE>(sector.sector_tag)?;
+		}
+
+		Ok(data)
+	}
+}
+
+/// A piece of a linedef side, while nodes are being built.
+#[derive(Clone, Copy, Debug)]
+struct BuildSeg {
+	start: Vector2<f64>,
+	end: Vector2<f64>,
+	linedef: u16,
+	side: Side,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct BuildLine {
+	point: Vector2<f64>,
+	dir: Vector2<f64>,
+}
+
+impl BuildLine {
+	/// Signed distance from the line to `point`, positive on the right side.
+	fn distance(&self, point: Vector2<f64>) -> f64 {
+		let diff = point - self.point;
+		(self.dir[1] * diff[0] - self.dir[0] * diff[1]) / self.dir.norm()
+	}
+
+	/// Returns whether `seg` lies on the line, running in the same direction.
+	fn has_seg(&self, seg: &BuildSeg) -> bool {
+		self.distance(seg.start).abs() < EPSILON
+			&& self.distance(seg.end).abs() < EPSILON
+			&& (seg.end - seg.start).dot(&self.dir) > 0.0
+	}
+}
+
+struct NodeBuilder<'a> {
+	map: &'a MapBuilder,
+	gl_nodes: GLNodesData,
+}
+
+impl<'a> NodeBuilder<'a> {
+	/// Builds the subtree for `segs`, which lie within the convex polygon `region`.
+	/// Returns the new child and its bounding box.
+	fn build(
+		&mut self,
+		segs: Vec<BuildSeg>,
+		region: Vec<Vector2<f64>>,
+	) -> anyhow::Result<(NodeChild, AABB2)> {
+		let partition = match self.choose_partition(&segs) {
+			Some(seg) => seg,
+			None => return self.build_subsector(&segs, region),
+		};
+		let line = self.line(&partition);
+
+		let (mut right, mut left) = (Vec::new(), Vec::new());
+
+		for seg in segs {
+			let distances = [line.distance(seg.start), line.distance(seg.end)];
+
+			if distances.iter().all(|d| d.abs() < EPSILON) {
+				// Segs on the partition line go to the side they face
+				if (seg.end - seg.start).dot(&line.dir) > 0.0 {
+					right.push(seg);
+				} else {
+					left.push(seg);
+				}
+			} else if distances.iter().all(|&d| d > -EPSILON) {
+				right.push(seg);
+			} else if distances.iter().all(|&d| d < EPSILON) {
+				left.push(seg);
+			} else {
+				let t = distances[0] / (distances[0] - distances[1]);
+				let point = seg.start + (seg.end - seg.start) * t;
+				let first = BuildSeg { end: point, ..seg };
+				let second = BuildSeg {
+					start: point,
+					..seg
+				};
+
+				if distances[0] > 0.0 {
+					right.push(first);
+					left.push(second);
+				} else {
+					left.push(first);
+					right.push(second);
+				}
+			}
+		}
+
+		let inverse = BuildLine {
+			point: line.point,
+			dir: -line.dir,
+		};
+		let right_region = clip_polygon(&region, &line);
+		let left_region = clip_polygon(&region, &inverse);
+
+		let (right_child, right_bbox) = self.build(right, right_region)?;
+		let (left_child, left_bbox) = self.build(left, left_region)?;
+		let (partition_point, partition_dir) = self.partition(&partition);
+
+		self.gl_nodes.nodes.push(GLNodeData {
+			partition_point,
+			partition_dir,
+			child_bboxes: [right_bbox, left_bbox],
+			child_indices: [right_child, left_child],
+		});
+
+		Ok((
+			NodeChild::Node(self.gl_nodes.nodes.len() - 1),
+			right_bbox.union(&left_bbox),
+		))
+	}
+
+	/// Picks the seg whose line splits `segs` best, or `None` if they already form a convex
+	/// subsector.
+	fn choose_partition(&self, segs: &[BuildSeg]) -> Option<BuildSeg> {
+		let mut best: Option<(usize, BuildSeg)> = None;
+
+		for seg in segs {
+			let line = self.line(seg);
+			let (mut right, mut left, mut splits) = (0, 0, 0);
+
+			for other in segs {
+				let distances = [line.distance(other.start), line.distance(other.end)];
+
+				if distances.iter().all(|d| d.abs() < EPSILON) {
+					// Segs on the line go to the side they face
+					if line.has_seg(other) {
+						right += 1;
+					} else {
+						left += 1;
+					}
+				} else if distances.iter().all(|&d| d > -EPSILON) {
+					right += 1;
+				} else if distances.iter().all(|&d| d < EPSILON) {
+					left += 1;
+				} else {
+					splits += 1;
+				}
+			}
+
+			// Everything is in front of this seg already
+			if left == 0 && splits == 0 {
+				continue;
+			}
+
+			let score = splits * 8 + (right as isize - left as isize).unsigned_abs();
+
+			if best.map_or(true, |(best_score, _)| score < best_score) {
+				best = Some((score, *seg));
+			}
+		}
+
+		best.map(|(_, seg)| seg)
+	}
+
+	/// Adds a subsector for `segs`, with segs along every edge of its polygon.
+	fn build_subsector(
+		&mut self,
+		segs: &[BuildSeg],
+		region: Vec<Vector2<f64>>,
+	) -> anyhow::Result<(NodeChild, AABB2)> {
+		let polygon = segs.iter().fold(region, |polygon, seg| {
+			clip_polygon(&polygon, &self.line(seg))
+		});
+		ensure!(
+			polygon.len() >= 3,
+			"Subsector around linedef {} is empty",
+			segs[0].linedef
+		);
+
+		let first_seg = self.gl_nodes.segs.len();
+		let mut seg_count = 0;
+
+		for (i, &point) in polygon.iter().enumerate() {
+			let next = polygon[(i + 1) % polygon.len()];
+			let edge = BuildLine {
+				point,
+				dir: next - point,
+			};
+			let length = edge.dir.norm();
+
+			// The segs along this edge, ordered from `point` to `next`
+			let mut edge_segs: Vec<(f64, f64, &BuildSeg)> = segs
+				.iter()
+				.filter(|seg| edge.has_seg(seg))
+				.map(|seg| {
+					let start = (seg.start - point).dot(&edge.dir) / length;
+					let end = (seg.end - point).dot(&edge.dir) / length;
+					(start, end, seg)
+				})
+				.filter(|&(start, end, _)| start > -EPSILON && end < length + EPSILON)
+				.collect();
+			edge_segs.sort_unstable_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+			// Fill the gaps between the segs with minisegs
+			let (mut position, mut current) = (0.0, point);
+
+			for (start, end, seg) in edge_segs {
+				if start - position > EPSILON {
+					self.add_seg([current, seg.start], None);
+				}
+
+				self.add_seg([seg.start, seg.end], Some((seg.linedef, seg.side)));
+				seg_count += 1;
+				position = end;
+				current = seg.end;
+			}
+
+			if length - position > EPSILON {
+				self.add_seg([current, next], None);
+			}
+		}
+
+		ensure!(
+			seg_count == segs.len(),
+			"Segs of linedef {} don't line up with their subsector",
+			segs[0].linedef
+		);
+
+		let mut bbox = AABB2::empty();
+
+		for point in &polygon {
+			bbox.add_point(point.map(f64::floor).cast());
+			bbox.add_point(point.map(f64::ceil).cast());
+		}
+
+		self.gl_nodes.subsectors.push(GLSubsectorData {
+			seg_count: (self.gl_nodes.segs.len() - first_seg) as u16,
+			first_seg: first_seg as u16,
+		});
+
+		Ok((
+			NodeChild::Subsector(self.gl_nodes.subsectors.len() - 1),
+			bbox,
+		))
+	}
+
+	fn add_seg(&mut self, points: [Vector2<f64>; 2], linedef: Option<(u16, Side)>) {
+		let vertices = points.map(|point| self.vertex_index(point));
+		self.gl_nodes.segs.push(GLSegData {
+			vertices,
+			linedef,
+			partner_seg: None,
+		});
+	}
+
+	/// Returns the index of the vertex at `point`, adding a new GL vertex if the map doesn't
+	/// have one there yet.
+	fn vertex_index(&mut self, point: Vector2<f64>) -> GLVertexIndex {
+		if let Some(index) = self
+			.map
+			.vertexes
+			.iter()
+			.position(|vertex| (vertex.cast::<f64>() - point).norm() < EPSILON)
+		{
+			return GLVertexIndex::Vertex(index as u16);
+		}
+
+		let index = match self
+			.gl_nodes
+			.vertexes
+			.iter()
+			.position(|vertex| (vertex.cast::<f64>() - point).norm() < EPSILON)
+		{
+			Some(index) => index,
+			None => {
+				self.gl_nodes.vertexes.push(point.cast());
+				self.gl_nodes.vertexes.len() - 1
+			}
+		};
+
+		GLVertexIndex::GLVertex(index as u16)
+	}
+
+	/// The partition line of a node split along `seg`, which runs along the whole linedef.
+	fn partition(&self, seg: &BuildSeg) -> (Vector2<i16>, Vector2<i16>) {
+		let linedef = &self.map.linedefs[seg.linedef as usize];
+		let [start, end] = linedef
+			.vertices
+			.map(|index| self.map.vertexes[index as usize]);
+
+		match seg.side {
+			Side::Right => (start, end - start),
+			Side::Left => (end, start - end),
+		}
+	}
+
+	fn line(&self, seg: &BuildSeg) -> BuildLine {
+		let (point, dir) = self.partition(seg);
+		BuildLine {
+			point: point.cast(),
+			dir: dir.cast(),
+		}
+	}
+}
+
+/// Cuts off the part of a clockwise convex polygon that is on the left side of `line`.
+fn clip_polygon(polygon: &[Vector2<f64>], line: &BuildLine) -> Vec<Vector2<f64>> {
+	let mut ret: Vec<Vector2<f64>> = Vec::with_capacity(polygon.len() + 1);
+	let mut push = |point: Vector2<f64>| {
+		if ret
+			.last()
+			.map_or(true, |last| (last - point).norm() >= EPSILON)
+		{
+			ret.push(point);
+		}
+	};
+
+	for (i, &point) in polygon.iter().enumerate() {
+		let next = polygon[(i + 1) % polygon.len()];
+		let distances = [line.distance(point), line.distance(next)];
+
+		if distances[0] > -EPSILON {
+			push(point);
+		}
+
+		if (distances[0] > EPSILON && distances[1] < -EPSILON)
+			|| (distances[0] < -EPSILON && distances[1] > EPSILON)
+		{
+			let t = distances[0] / (distances[0] - distances[1]);
+			push(point + (next - point) * t);
+		}
+	}
+
+	while ret.len() > 1 && (ret[0] - ret[ret.len() - 1]).norm() < EPSILON {
+		ret.pop();
+	}
+
+	ret
+}
+
+impl GLNodesData {
+	fn vert_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(4 + self.vertexes.len() * 8);
+		data.extend_from_slice(b"gNd2");
+
+		for vertex in &self.vertexes {
+			data.write_i32::<LE>((vertex[0] * 65536.0).round() as i32)?;
+			data.write_i32::<LE>((vertex[1] * 65536.0).round() as i32)?;
+		}
+
+		Ok(data)
+	}
+
+	fn segs_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.segs.len() * 10);
+
+		for seg in &self.segs {
+			for vertex in &seg.vertices {
+				data.write_u16::<LE>(match *vertex {
+					GLVertexIndex::Vertex(index) => index,
+					GLVertexIndex::GLVertex(index) => index | 0x8000,
+				})?;
+			}
+
+			let (linedef, side) = seg.linedef.unwrap_or((0xFFFF, Side::Right));
+			data.write_u16::<LE>(linedef)?;
+			data.write_u16::<LE>(side as u16)?;
+			data.write_u16::<LE>(seg.partner_seg.unwrap_or(0xFFFF))?;
+		}
+
+		Ok(data)
+	}
+
+	fn ssect_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.subsectors.len() * 4);
+
+		for subsector in &self.subsectors {
+			data.write_u16::<LE>(subsector.seg_count)?;
+			data.write_u16::<LE>(subsector.first_seg)?;
+		}
+
+		Ok(data)
+	}
+
+	fn nodes_lump(&self) -> anyhow::Result<Vec<u8>> {
+		let mut data = Vec::with_capacity(self.nodes.len() * 28);
+
+		for node in &self.nodes {
+			data.write_i16::<LE>(node.partition_point[0])?;
+			data.write_i16::<LE>(node.partition_point[1])?;
+			data.write_i16::<LE>(node.partition_dir[0])?;
+			data.write_i16::<LE>(node.partition_dir[1])?;
+
+			for bbox in &node.child_bboxes {
+				data.write_i16::<LE>(bbox[1].max as i16)?;
+				data.write_i16::<LE>(bbox[1].min as i16)?;
+				data.write_i16::<LE>(bbox[0].min as i16)?;
+				data.write_i16::<LE>(bbox[0].max as i16)?;
+			}
+
+			for child in &node.child_indices {
+				data.write_u16::<LE>(match *child {
+					NodeChild::Node(index) => index as u16,
+					NodeChild::Subsector(index) => index as u16 | 0x8000,
+				})?;
+			}
+		}
+
+		Ok(data)
+	}
+}
+
+mod tests {
+	use super::*;
+	use crate::{
+		common::assets::AssetStorage,
+		doom::{
+			assets::{map::Map, process_assets},
+			testing::{self, DOOR_MAP, LIFT_MAP, TELEPORTER_MAP},
+		},
+	};
+	use legion::{systems::ResourceSet, Read, Write};
+
+	#[test]
+	fn example_maps_load() {
+		let (_, mut resources) = testing::init();
+
+		for (name, builder) in [
+			(DOOR_MAP, testing::door_map()),
+			(LIFT_MAP, testing::lift_map()),
+			(TELEPORTER_MAP, testing::teleporter_map()),
+		] {
+			let handle = <Write<AssetStorage>>::fetch_mut(&mut resources)
+				.load::<Map>(&format!("{}.map", name));
+			process_assets(&mut resources);
+
+			let asset_storage = <Read<AssetStorage>>::fetch(&resources);
+			let map = asset_storage.get(&handle).unwrap();
+			assert_eq!(map.linedefs.len(), builder.linedefs.len());
+			assert_eq!(map.sectors.len(), builder.sectors.len());
+
+			for sector in &map.sectors {
+				assert!(!sector.subsectors.is_empty());
+			}
+
+			// The rooms and the passage between them
+			for (point, sector_index) in [
+				(Vector2::new(64.0, 128.0), 0),
+				(Vector2::new(272.0, 128.0), 1),
+				(Vector2::new(416.0, 128.0), 2),
+			] {
+				assert_eq!(map.find_subsector(point).sector_index, sector_index);
+			}
+		}
+	}
+
+	#[test]
+	fn built_subsectors_are_closed() {
+		let map = testing::door_map();
+		let gl_nodes = map.build_gl_nodes().unwrap();
+		let vertex = |index: GLVertexIndex| match index {
+			GLVertexIndex::Vertex(index) => map.vertexes[index as usize].cast::<f32>(),
+			GLVertexIndex::GLVertex(index) => gl_nodes.vertexes[index as usize],
+		};
+
+		assert_eq!(gl_nodes.subsectors.len(), gl_nodes.nodes.len() + 1);
+
+		for subsector in &gl_nodes.subsectors {
+			let first_seg = subsector.first_seg as usize;
+			let segs = &gl_nodes.segs[first_seg..first_seg + subsector.seg_count as usize];
+			assert!(segs.len() >= 3);
+
+			for (i, seg) in segs.iter().enumerate() {
+				let next = &segs[(i + 1) % segs.len()];
+				assert_eq!(vertex(seg.vertices[1]), vertex(next.vertices[0]));
+			}
+		}
+
+		// Every side of every linedef ends up in a subsector
+		for (i, linedef) in map.linedefs.iter().enumerate() {
+			for side in [Side::Right, Side::Left] {
+				let count = gl_nodes
+					.segs
+					.iter()
+					.filter(|seg| seg.linedef == Some((i as u16, side)))
+					.count();
+				assert_eq!(count > 0, linedef.sidedefs[side as usize].is_some());
+			}
+		}
+	}
+
+	#[test]
+	fn single_subsector_map() {
+		let mut map = MapBuilder::new();
+		let sector = map.add_sector(SectorData {
+			floor_height: 0,
+			ceiling_height: 128,
+			textures: ["testflat".into(), "testflat".into()],
+			light_level: 160,
+			special_type: 0,
+			sector_tag: 0,
+		});
+		let vertices =
+			[(0, 0), (0, 64), (64, 64), (64, 0)].map(|(x, y)| map.add_vertex(Vector2::new(x, y)));
+
+		for (i, &start) in vertices.iter().enumerate() {
+			let sidedef = map.add_sidedef(SidedefData {
+				texture_offset: Vector2::zeros(),
+				textures: [None, None, Some("testwall".into())],
+				sector,
+			});
+			map.add_linedef(LinedefData {
+				vertices: [start, vertices[(i + 1) % vertices.len()]],
+				flags: LinedefFlags::BLOCKING,
+				special_type: 0,
+				sector_tag: 0,
+				sidedefs: [Some(sidedef), None],
+			});
+		}
+
+		let gl_nodes = map.build_gl_nodes().unwrap();
+		assert_eq!(gl_nodes.subsectors.len(), 1);
+		assert_eq!(gl_nodes.segs.len(), 4);
+		assert_eq!(gl_nodes.nodes.len(), 1);
+		assert!(gl_nodes.vertexes.is_empty());
+	}
+}
@@ -34,7 +34,7 @@ use crate::{
 	},
 };
 use anyhow::bail;
-use legion::{systems::ResourceSet, Read, Resources, Write};
+use legion::{systems::ResourceSet, Read, Resources};
 use relative_path::RelativePath;
 
 pub fn register_assets(resources: &mut Resources) {
@@ -87,9 +87,17 @@ pub fn import(
 }
 
 pub fn process_assets(resources: &mut Resources) {
-	let (iwad_info, render_context, mut asset_storage) =
-		<(Read<IWADInfo>, Read<RenderContext>, Write<AssetStorage>)>::fetch_mut(resources);
-	process_hexfonts(&mut asset_storage, &render_context);
-	process_images(&mut asset_storage, &render_context);
+	let iwad_info = <Read<IWADInfo>>::fetch(resources);
+	let mut asset_storage = resources.get_mut::<AssetStorage>().unwrap();
+
+	// Without video, there is nothing to turn images into
+	if let Some(render_context) = resources.get::<RenderContext>() {
+		process_hexfonts(&mut asset_storage, &render_context);
+		process_images(&mut asset_storage, &render_context);
+	} else {
+		asset_storage.discard_unprocessed::<HexFont>();
+		asset_storage.discard_unprocessed::<Image>();
+	}
+
 	process_map(&mut asset_storage, &iwad_info);
 }
@@ -1,5 +1,5 @@
 use crate::common::assets::DataSource;
-use anyhow::{bail, ensure};
+use anyhow::{bail, ensure, Context};
 use arrayvec::ArrayString;
 use byteorder::{ReadBytesExt, LE};
 use relative_path::RelativePath;
@@ -13,6 +13,11 @@ use std::{
 	vec::Vec,
 };
 
+#[cfg(test)]
+use byteorder::WriteBytesExt;
+#[cfg(test)]
+use std::io::{BufWriter, Write};
+
 struct Lump {
 	path: PathBuf,
 	name: String,
@@ -155,6 +160,79 @@ impl DataSource for WadLoader {
 	}
 }
 
+/// Builds a PWAD out of lumps, which can be added with `WadLoader::add` once saved.
+/// Only used by tests, to make WADs for them to load.
+#[cfg(test)]
+#[derive(Clone, Debug, Default)]
+pub struct WadWriter {
+	lumps: Vec<(ArrayString<8>, Vec<u8>)>,
+}
+
+#[cfg(test)]
+impl WadWriter {
+	pub fn new() -> WadWriter {
+		WadWriter { lumps: Vec::new() }
+	}
+
+	/// Adds a lump to the end of the WAD. Lumps are looked up by name, and later lumps override
+	/// earlier ones, so the order matters.
+	pub fn add(&mut self, name: &str, data: Vec<u8>) -> anyhow::Result<()> {
+		self.lumps.push((lump_name(name)?, data));
+		Ok(())
+	}
+
+	pub fn write<W: Write>(&self, writer: &mut W) -> anyhow::Result<()> {
+		let header_size = 12;
+		let data_size: usize = self.lumps.iter().map(|(_, data)| data.len()).sum();
+		let dir_offset = u32::try_from(header_size + data_size).context("WAD is too large")?;
+
+		writer.write_all(b"PWAD")?;
+		writer.write_u32::<LE>(self.lumps.len() as u32)?;
+		writer.write_u32::<LE>(dir_offset)?;
+
+		for (_, data) in &self.lumps {
+			writer.write_all(data)?;
+		}
+
+		// Lump directory
+		let mut offset = header_size as u32;
+
+		for (name, data) in &self.lumps {
+			writer.write_u32::<LE>(offset)?;
+			writer.write_u32::<LE>(data.len() as u32)?;
+			write_string(writer, name)?;
+			offset += data.len() as u32;
+		}
+
+		Ok(())
+	}
+
+	pub fn save<P: AsRef<Path>>(&self, path: P) -> anyhow::Result<()> {
+		let path = path.as_ref();
+		let file = File::create(path)
+			.with_context(|| format!("Couldn't open \"{}\" for writing", path.display()))?;
+		let mut writer = BufWriter::new(file);
+		self.write(&mut writer)
+			.and_then(|_| Ok(writer.flush()?))
+			.with_context(|| format!("Couldn't write \"{}\"", path.display()))
+	}
+}
+
+/// Checks that `name` can be used as the name of a lump, and converts it to upper case.
+#[cfg(test)]
+fn lump_name(name: &str) -> anyhow::Result<ArrayString<8>> {
+	ensure!(
+		name.is_ascii() && !name.contains('\0'),
+		"Lump name \"{}\" contains invalid characters",
+		name
+	);
+	let mut string = ArrayString::from(name)
+		.ok()
+		.with_context(|| format!("Lump name \"{}\" is longer than 8 characters", name))?;
+	string.make_ascii_uppercase();
+	Ok(string)
+}
+
 pub fn read_string<R: Read>(reader: &mut R) -> anyhow::Result<ArrayString<8>> {
 	let mut buf = [0u8; 8];
 	reader.read_exact(&mut buf)?;
@@ -163,3 +241,13 @@ pub fn read_string<R: Read>(reader: &mut R) -> anyhow::Result<ArrayString<8>> {
 	string.make_ascii_lowercase();
 	Ok(string)
 }
+
+/// Writes a string in the 8-byte form that `read_string` reads, padded with zeroes.
+#[cfg(test)]
+pub fn write_string<W: Write>(writer: &mut W, string: &str) -> anyhow::Result<()> {
+	let string = lump_name(string)?;
+	let mut buf = [0u8; 8];
+	buf[..string.len()].copy_from_slice(string.as_bytes());
+	writer.write_all(&buf)?;
+	Ok(())
+}
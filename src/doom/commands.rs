@@ -4,13 +4,23 @@ use crate::{
 		input::{bind_axis, bind_button},
 	},
 	doom::{
-		game::{change_map, cheats::give_all, load_game, new_game, save_game},
+		game::{
+			change_map,
+			cheats::give_all,
+			demo::{play_demo, record_demo, stop_demo, timedemo},
+			load_game,
+			net::{connect, disconnect, host, DEFAULT_PORT},
+			new_game,
+			options::GameOptions,
+			save_game,
+		},
 		take_screenshot,
 	},
 	ShouldQuit,
 };
 use clap::{App, AppSettings, Arg, ArgMatches};
 use legion::{Resources, World};
+use std::path::Path;
 
 pub fn commands() -> Vec<(
 	App<'static, 'static>,
@@ -78,6 +88,22 @@ pub fn commands() -> Vec<(
 				change_map(matches.value_of("MAP").unwrap(), world, resources);
 			},
 		),
+		(
+			App::new("connect")
+				.about("Connect to a server and join its game")
+				.arg(Arg::with_name("ADDRESS").help(
+					"Address of the server, with an optional port\nLeave empty to connect to this machine",
+				)),
+			|matches, world, resources| {
+				connect(matches.value_of("ADDRESS"), world, resources);
+			},
+		),
+		(
+			App::new("disconnect").about("Leave the server, or stop hosting the game"),
+			|_matches, world, resources| {
+				disconnect(world, resources);
+			},
+		),
 		(
 			App::new("exec").about("Execute commands from a file").arg(
 				Arg::with_name("FILE")
@@ -89,6 +115,23 @@ pub fn commands() -> Vec<(
 				execute_file(matches.value_of("FILE").unwrap(), resources);
 			},
 		),
+		(
+			App::new("host")
+				.about("Let other players connect to the current game")
+				.arg(Arg::with_name("PORT").help("Port to listen on")),
+			|matches, _world, resources| {
+				let port = match matches.value_of("PORT").map(str::parse) {
+					Some(Ok(port)) => port,
+					Some(Err(_)) => {
+						log::error!("Invalid port number.");
+						return;
+					}
+					None => DEFAULT_PORT,
+				};
+
+				host(port, resources);
+			},
+		),
 		(
 			App::new("idfa").about("[Cheat] Give all weapons, ammo and armor"),
 			|_matches, world, resources| {
@@ -113,14 +156,35 @@ pub fn commands() -> Vec<(
 			},
 		),
 		(
-			App::new("new").about("Start a new game").arg(
-				Arg::with_name("MAP")
-					.help("Map to start the new game on")
-					.empty_values(false)
-					.required(true),
-			),
+			App::new("new")
+				.about("Start a new game")
+				.arg(
+					Arg::with_name("MAP")
+						.help("Map to start the new game on")
+						.empty_values(false)
+						.required(true),
+				)
+				.args(&GameOptions::args()),
 			|matches, world, resources| {
-				new_game(matches.value_of("MAP").unwrap(), world, resources);
+				new_game(
+					matches.value_of("MAP").unwrap(),
+					GameOptions::from_arg_matches(matches),
+					world,
+					resources,
+				);
+			},
+		),
+		(
+			App::new("playdemo")
+				.about("Play back a recorded demo")
+				.arg(
+					Arg::with_name("FILE")
+						.help("Demo file to play")
+						.empty_values(false)
+						.required(true),
+				),
+			|matches, world, resources| {
+				play_demo(Path::new(matches.value_of("FILE").unwrap()), world, resources);
 			},
 		),
 		(
@@ -129,6 +193,32 @@ pub fn commands() -> Vec<(
 				resources.insert(ShouldQuit);
 			},
 		),
+		(
+			App::new("record")
+				.about("Start a new game and record it to a demo file")
+				.arg(
+					Arg::with_name("FILE")
+						.help("File to record the demo to")
+						.empty_values(false)
+						.required(true),
+				)
+				.arg(
+					Arg::with_name("MAP")
+						.help("Map to start the new game on")
+						.empty_values(false)
+						.required(true),
+				)
+				.args(&GameOptions::args()),
+			|matches, world, resources| {
+				record_demo(
+					Path::new(matches.value_of("FILE").unwrap()),
+					matches.value_of("MAP").unwrap(),
+					GameOptions::from_arg_matches(matches),
+					world,
+					resources,
+				);
+			},
+		),
 		(
 			App::new("save").about("Save the current game").arg(
 				Arg::with_name("NAME")
@@ -146,5 +236,223 @@ pub fn commands() -> Vec<(
 				take_screenshot(resources);
 			},
 		),
+		(
+			App::new("stopdemo").about("Stop recording or playing back a demo"),
+			|_matches, _world, resources| {
+				stop_demo(resources);
+			},
+		),
+		(
+			App::new("timedemo")
+				.about("Play back a demo as fast as possible, and report how long it took")
+				.arg(
+					Arg::with_name("FILE")
+						.help("Demo file to play")
+						.empty_values(false)
+						.required(true),
+				)
+				.arg(
+					Arg::with_name("json")
+						.help("File to also write the results to, as JSON")
+						.long("json")
+						.value_name("FILE"),
+				),
+			|matches, world, resources| {
+				timedemo(
+					Path::new(matches.value_of("FILE").unwrap()),
+					matches.value_of("json").map(Path::new),
+					world,
+					resources,
+				);
+			},
+		),
 	]
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		common::console::execute_commands,
+		doom::{
+			game::{
+				client::Clients,
+				demo::{finish_timedemo, world_checksum, DemoPlayer, DemoRecorder, Timedemo},
+				options::Skill,
+			},
+			testing::{self, DOOR_MAP},
+		},
+	};
+	use legion::{systems::ResourceSet, Read};
+	use std::{
+		env::temp_dir,
+		fs::{remove_file, File},
+		process,
+		time::{Duration, Instant},
+	};
+
+	/// Runs a line of text through the console, like it was typed in.
+	fn execute(command: &str, world: &mut World, resources: &mut Resources) {
+		let (sender, receiver) = crossbeam_channel::unbounded();
+		sender.send(command.to_owned()).unwrap();
+		let mut run = execute_commands(receiver, commands());
+		run(world, resources);
+	}
+
+	#[test]
+	fn new_with_options() {
+		let (mut world, mut resources) = testing::init();
+		execute(
+			&format!("new {} --skill 4 --fast --players 2", DOOR_MAP),
+			&mut world,
+			&mut resources,
+		);
+
+		let game_options = <Read<GameOptions>>::fetch(&resources);
+		assert_eq!(game_options.skill, Skill::Hard);
+		assert!(game_options.fast_monsters);
+		assert!(!game_options.respawn_monsters);
+		assert!(!game_options.no_monsters);
+		assert_eq!(game_options.players, 2);
+
+		// Both players are spawned
+		let clients = <Read<Clients>>::fetch(&resources);
+		assert_eq!(clients.clients.len(), 2);
+
+		for (i, client) in clients.clients.iter().enumerate() {
+			assert_eq!(client.player_num, i + 1);
+			assert!(client.entity.is_some());
+		}
+	}
+
+	#[test]
+	fn new_deathmatch() {
+		let (mut world, mut resources) = testing::init();
+		execute(
+			&format!("new {} --deathmatch --fraglimit 10", DOOR_MAP),
+			&mut world,
+			&mut resources,
+		);
+		assert!(resources.contains::<Clients>());
+
+		let deathmatch = <Read<GameOptions>>::fetch(&resources)
+			.deathmatch
+			.expect("Game is not a deathmatch");
+		assert!(!deathmatch.alt_death);
+		assert_eq!(deathmatch.frag_limit, Some(10));
+		assert_eq!(deathmatch.time_limit, None);
+	}
+
+	#[test]
+	fn new_with_seed() {
+		let (mut world, mut resources) = testing::init();
+		execute(
+			&format!("new {} --seed 1234", DOOR_MAP),
+			&mut world,
+			&mut resources,
+		);
+		assert_eq!(<Read<GameOptions>>::fetch(&resources).seed, Some(1234));
+
+		// The game is the same as one started with that seed directly
+		let (mut expected_world, mut expected_resources) = testing::init();
+		let options = GameOptions {
+			players: 1,
+			seed: Some(1234),
+			..GameOptions::default()
+		};
+		new_game(
+			DOOR_MAP,
+			options,
+			&mut expected_world,
+			&mut expected_resources,
+		);
+		assert_eq!(
+			world_checksum(&world, &resources),
+			world_checksum(&expected_world, &expected_resources)
+		);
+	}
+
+	#[test]
+	fn record_with_options() {
+		let path = temp_dir().join(format!("ferret-test-{}-record.demo", process::id()));
+		let (mut world, mut resources) = testing::init();
+		let mut update_systems = testing::update_systems(&mut resources);
+		execute(
+			&format!("record {:?} {} --skill 4 --seed 5", path, DOOR_MAP),
+			&mut world,
+			&mut resources,
+		);
+		assert!(resources.contains::<DemoRecorder>());
+
+		for _ in 0..35 {
+			update_systems.execute(&mut world, &mut resources);
+		}
+
+		execute("stopdemo", &mut world, &mut resources);
+		assert!(!resources.contains::<DemoRecorder>());
+
+		// Playing the demo back starts a game with the same options
+		let (mut world, mut resources) = testing::init();
+		execute(&format!("playdemo {:?}", path), &mut world, &mut resources);
+		assert!(resources.contains::<DemoPlayer>());
+
+		let game_options = <Read<GameOptions>>::fetch(&resources);
+		assert_eq!(game_options.skill, Skill::Hard);
+		assert_eq!(game_options.seed, Some(5));
+
+		remove_file(&path).ok();
+	}
+
+	#[test]
+	fn timedemo_json() {
+		let demo_path = temp_dir().join(format!("ferret-test-{}-timedemo.demo", process::id()));
+		let json_path = demo_path.with_extension("json");
+		let (mut world, mut resources) = testing::init();
+		let mut update_systems = testing::update_systems(&mut resources);
+		execute(
+			&format!("record {:?} {}", demo_path, DOOR_MAP),
+			&mut world,
+			&mut resources,
+		);
+
+		for _ in 0..35 {
+			update_systems.execute(&mut world, &mut resources);
+		}
+
+		execute("stopdemo", &mut world, &mut resources);
+
+		// Play it back like the main loop does
+		let (mut world, mut resources) = testing::init();
+		let mut update_systems = testing::update_systems(&mut resources);
+		execute(
+			&format!("timedemo {:?} --json {:?}", demo_path, json_path),
+			&mut world,
+			&mut resources,
+		);
+		assert!(resources.contains::<Timedemo>());
+
+		loop {
+			let start = Instant::now();
+			update_systems.execute(&mut world, &mut resources);
+
+			if !resources.contains::<DemoPlayer>() {
+				break;
+			}
+
+			let mut timedemo = resources.get_mut::<Timedemo>().unwrap();
+			timedemo.add_frame(start, Duration::ZERO, Some(start.elapsed()), Duration::ZERO);
+		}
+
+		finish_timedemo(&mut resources);
+		assert!(!resources.contains::<Timedemo>());
+
+		let report: serde_json::Value =
+			serde_json::from_reader(File::open(&json_path).expect("JSON file was not written"))
+				.unwrap();
+		assert_eq!(report["tics"], 35);
+		assert_eq!(report["frames"], 35);
+
+		remove_file(&demo_path).ok();
+		remove_file(&json_path).ok();
+	}
+}
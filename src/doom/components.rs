@@ -2,13 +2,21 @@ use crate::{
 	common::spawn::SpawnMergerHandlerSet,
 	doom::{
 		assets::template::{EntityTemplateRef, EntityTemplateRefDef},
-		draw::{sprite::SpriteRender, wsprite::WeaponSpriteRender},
+		draw::{
+			sprite::{SpriteFuzz, SpriteRender},
+			wsprite::WeaponSpriteRender,
+		},
 		game::{
-			map::{LinedefRef, LinedefRefDef, MapDynamic, SectorRef, SectorRefDef, SpawnPoint},
+			map::{
+				DeathmatchSpawnPoint, LinedefRef, LinedefRefDef, MapDynamic, SectorRef,
+				SectorRefDef, SpawnPoint,
+			},
+			stats::{CountItem, CountKill},
 			RandomTransformDef, Transform, TransformDef,
 		},
 		ui::{
-			hud::{AmmoStat, ArmsStat, HealthStat},
+			hud::{AmmoStat, ArmorStat, ArmsPanel, ArmsStat, FragsStat, HealthStat},
+			intermission::{IntermissionCentered, IntermissionMapName, IntermissionStat},
 			UiGameView, UiImage, UiText, UiTransform,
 		},
 	},
@@ -21,13 +29,34 @@ pub fn register_components(resources: &mut Resources) {
 
 	handler_set.register_clone::<AmmoStat>();
 
+	handler_set.register_clone::<ArmorStat>();
+
+	handler_set.register_clone::<ArmsPanel>();
+
 	handler_set.register_clone::<ArmsStat>();
 
+	registry.register::<CountItem>("CountItem".into());
+	handler_set.register_clone::<CountItem>();
+
+	registry.register::<CountKill>("CountKill".into());
+	handler_set.register_clone::<CountKill>();
+
+	registry.register::<DeathmatchSpawnPoint>("DeathmatchSpawnPoint".into());
+	handler_set.register_clone::<DeathmatchSpawnPoint>();
+
 	registry.register::<EntityTemplateRef>("EntityTemplateRef".into());
 	handler_set.register_spawn::<EntityTemplateRefDef, EntityTemplateRef>();
 
+	handler_set.register_clone::<FragsStat>();
+
 	handler_set.register_clone::<HealthStat>();
 
+	handler_set.register_clone::<IntermissionCentered>();
+
+	handler_set.register_clone::<IntermissionMapName>();
+
+	handler_set.register_clone::<IntermissionStat>();
+
 	registry.register::<LinedefRef>("LinedefRef".into());
 	handler_set.register_clone::<LinedefRef>();
 	handler_set.register_spawn::<LinedefRefDef, LinedefRef>();
@@ -42,6 +71,9 @@ pub fn register_components(resources: &mut Resources) {
 	registry.register::<SpawnPoint>("SpawnPoint".into());
 	handler_set.register_clone::<SpawnPoint>();
 
+	registry.register::<SpriteFuzz>("SpriteFuzz".into());
+	handler_set.register_clone::<SpriteFuzz>();
+
 	registry.register::<SpriteRender>("SpriteRender".into());
 	handler_set.register_clone::<SpriteRender>();
 
@@ -1,6 +1,9 @@
-use crate::doom::iwad::{IWADInfo, MapInfo};
+use crate::doom::{
+	game::boss::{BossDeathAction, BossSpecial},
+	iwad::{IWADInfo, MapInfo},
+};
 use once_cell::sync::Lazy;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Duration};
 
 pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 	vec![
@@ -28,6 +31,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "runnin.music",
 						exit: Some("map02.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -38,6 +43,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stalks.music",
 						exit: Some("map03.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -48,6 +55,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "countd.music",
 						exit: Some("map04.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -58,6 +67,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "betwee.music",
 						exit: Some("map05.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -68,6 +79,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "doom.music",
 						exit: Some("map06.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -78,6 +91,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "the_da.music",
 						exit: Some("map07.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -88,6 +103,17 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn.music",
 						exit: Some("map08.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[
+							BossSpecial {
+								entity: "fatso.entity",
+								action: BossDeathAction::LowerFloor(666),
+							},
+							BossSpecial {
+								entity: "baby.entity",
+								action: BossDeathAction::RaiseFloorToTexture(667),
+							},
+						],
 					},
 				),
 				(
@@ -98,6 +124,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtblu.music",
 						exit: Some("map09.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -108,6 +136,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "in_cit.music",
 						exit: Some("map10.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(270)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -118,6 +148,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "dead.music",
 						exit: Some("map11.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -128,6 +160,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stlks2.music",
 						exit: Some("map12.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -138,6 +172,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "theda2.music",
 						exit: Some("map13.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -148,6 +184,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "doom2.music",
 						exit: Some("map14.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -158,6 +196,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtbl2.music",
 						exit: Some("map15.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -168,6 +208,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "runni2.music",
 						exit: Some("map16.map"),
 						secret_exit: Some("map31.map"),
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -178,6 +220,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "dead2.music",
 						exit: Some("map17.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -188,6 +232,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stlks3.music",
 						exit: Some("map18.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(420)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -198,6 +244,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "romero.music",
 						exit: Some("map19.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -208,6 +256,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn2.music",
 						exit: Some("map20.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -218,6 +268,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "messag.music",
 						exit: Some("map21.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -228,6 +280,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "count2.music",
 						exit: Some("map22.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(240)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -238,6 +292,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtbl3.music",
 						exit: Some("map23.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -248,6 +304,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ampie.music",
 						exit: Some("map24.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -258,6 +316,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "theda3.music",
 						exit: Some("map25.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -268,6 +328,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "adrian.music",
 						exit: Some("map26.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -278,6 +340,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "messg2.music",
 						exit: Some("map27.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(300)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -288,6 +352,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "romer2.music",
 						exit: Some("map28.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(330)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -298,6 +364,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "tense.music",
 						exit: Some("map29.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(420)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -308,6 +376,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn3.music",
 						exit: Some("map30.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(300)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -318,6 +388,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "openin.music",
 						exit: None,
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -328,6 +400,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "evil.music",
 						exit: Some("map16.map"),
 						secret_exit: Some("map32.map"),
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -338,6 +412,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ultima.music",
 						exit: Some("map16.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 			]),
@@ -366,6 +442,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "runnin.music",
 						exit: Some("map02.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -376,6 +454,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stalks.music",
 						exit: Some("map03.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -386,6 +466,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "countd.music",
 						exit: Some("map04.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -396,6 +478,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "betwee.music",
 						exit: Some("map05.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -406,6 +490,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "doom.music",
 						exit: Some("map06.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -416,6 +502,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "the_da.music",
 						exit: Some("map07.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -426,6 +514,17 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn.music",
 						exit: Some("map08.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[
+							BossSpecial {
+								entity: "fatso.entity",
+								action: BossDeathAction::LowerFloor(666),
+							},
+							BossSpecial {
+								entity: "baby.entity",
+								action: BossDeathAction::RaiseFloorToTexture(667),
+							},
+						],
 					},
 				),
 				(
@@ -436,6 +535,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtblu.music",
 						exit: Some("map09.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -446,6 +547,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "in_cit.music",
 						exit: Some("map10.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(270)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -456,6 +559,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "dead.music",
 						exit: Some("map11.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -466,6 +571,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stlks2.music",
 						exit: Some("map12.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -476,6 +583,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "theda2.music",
 						exit: Some("map13.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -486,6 +595,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "doom2.music",
 						exit: Some("map14.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -496,6 +607,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtbl2.music",
 						exit: Some("map15.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -506,6 +619,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "runni2.music",
 						exit: Some("map16.map"),
 						secret_exit: Some("map31.map"),
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -516,6 +631,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "dead2.music",
 						exit: Some("map17.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -526,6 +643,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stlks3.music",
 						exit: Some("map18.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(420)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -536,6 +655,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "romero.music",
 						exit: Some("map19.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -546,6 +667,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn2.music",
 						exit: Some("map20.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -556,6 +679,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "messag.music",
 						exit: Some("map21.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -566,6 +691,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "count2.music",
 						exit: Some("map22.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(240)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -576,6 +703,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtbl3.music",
 						exit: Some("map23.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -586,6 +715,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ampie.music",
 						exit: Some("map24.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -596,6 +727,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "theda3.music",
 						exit: Some("map25.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -606,6 +739,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "adrian.music",
 						exit: Some("map26.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -616,6 +751,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "messg2.music",
 						exit: Some("map27.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(300)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -626,6 +763,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "romer2.music",
 						exit: Some("map28.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(330)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -636,6 +775,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "tense.music",
 						exit: Some("map29.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(420)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -646,6 +787,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn3.music",
 						exit: Some("map30.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(300)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -656,6 +799,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "openin.music",
 						exit: None,
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -666,6 +811,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "evil.music",
 						exit: Some("map16.map"),
 						secret_exit: Some("map32.map"),
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -676,6 +823,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ultima.music",
 						exit: Some("map16.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 			]),
@@ -704,6 +853,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "runnin.music",
 						exit: Some("map02.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -714,6 +865,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stalks.music",
 						exit: Some("map03.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -724,6 +877,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "countd.music",
 						exit: Some("map04.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -734,6 +889,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "betwee.music",
 						exit: Some("map05.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -744,6 +901,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "doom.music",
 						exit: Some("map06.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -754,6 +913,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "the_da.music",
 						exit: Some("map07.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -764,6 +925,17 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn.music",
 						exit: Some("map08.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[
+							BossSpecial {
+								entity: "fatso.entity",
+								action: BossDeathAction::LowerFloor(666),
+							},
+							BossSpecial {
+								entity: "baby.entity",
+								action: BossDeathAction::RaiseFloorToTexture(667),
+							},
+						],
 					},
 				),
 				(
@@ -774,6 +946,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtblu.music",
 						exit: Some("map09.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -784,6 +958,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "in_cit.music",
 						exit: Some("map10.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(270)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -794,6 +970,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "dead.music",
 						exit: Some("map11.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -804,6 +982,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stlks2.music",
 						exit: Some("map12.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -814,6 +994,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "theda2.music",
 						exit: Some("map13.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -824,6 +1006,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "doom2.music",
 						exit: Some("map14.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -834,6 +1018,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtbl2.music",
 						exit: Some("map15.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -844,6 +1030,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "runni2.music",
 						exit: Some("map16.map"),
 						secret_exit: Some("map31.map"),
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -854,6 +1042,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "dead2.music",
 						exit: Some("map17.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -864,6 +1054,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "stlks3.music",
 						exit: Some("map18.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(420)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -874,6 +1066,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "romero.music",
 						exit: Some("map19.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -884,6 +1078,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn2.music",
 						exit: Some("map20.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(210)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -894,6 +1090,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "messag.music",
 						exit: Some("map21.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -904,6 +1102,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "count2.music",
 						exit: Some("map22.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(240)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -914,6 +1114,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ddtbl3.music",
 						exit: Some("map23.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -924,6 +1126,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ampie.music",
 						exit: Some("map24.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -934,6 +1138,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "theda3.music",
 						exit: Some("map25.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -944,6 +1150,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "adrian.music",
 						exit: Some("map26.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -954,6 +1162,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "messg2.music",
 						exit: Some("map27.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(300)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -964,6 +1174,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "romer2.music",
 						exit: Some("map28.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(330)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -974,6 +1186,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "tense.music",
 						exit: Some("map29.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(420)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -984,6 +1198,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "shawn3.music",
 						exit: Some("map30.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(300)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -994,6 +1210,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "openin.music",
 						exit: None,
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1004,6 +1222,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "evil.music",
 						exit: Some("map16.map"),
 						secret_exit: Some("map32.map"),
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1014,6 +1234,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "ultima.music",
 						exit: Some("map16.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 			]),
@@ -1041,6 +1263,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m1.music",
 						exit: Some("e1m2.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1051,6 +1275,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m2.music",
 						exit: Some("e1m3.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(75)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1061,6 +1287,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m3.music",
 						exit: Some("e1m4.map"),
 						secret_exit: Some("e1m9.map"),
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1071,6 +1299,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m4.music",
 						exit: Some("e1m5.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1081,6 +1311,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m5.music",
 						exit: Some("e1m6.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(165)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1091,6 +1323,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m6.music",
 						exit: Some("e1m7.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1101,6 +1335,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m7.music",
 						exit: Some("e1m8.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1111,6 +1347,11 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m8.music",
 						exit: None,
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[BossSpecial {
+							entity: "bruiser.entity",
+							action: BossDeathAction::LowerFloor(666),
+						}],
 					},
 				),
 				(
@@ -1121,6 +1362,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m9.music",
 						exit: Some("e1m4.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(165)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1131,6 +1374,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m1.music",
 						exit: Some("e2m2.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1141,6 +1386,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m2.music",
 						exit: Some("e2m3.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1151,6 +1398,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m3.music",
 						exit: Some("e2m4.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1161,6 +1410,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m4.music",
 						exit: Some("e2m5.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1171,6 +1422,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m5.music",
 						exit: Some("e2m6.map"),
 						secret_exit: Some("e2m9.map"),
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1181,6 +1434,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m6.music",
 						exit: Some("e2m7.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(360)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1191,6 +1446,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m7.music",
 						exit: Some("e2m8.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(240)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1201,6 +1458,11 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m8.music",
 						exit: None,
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[BossSpecial {
+							entity: "cyborg.entity",
+							action: BossDeathAction::ExitMap,
+						}],
 					},
 				),
 				(
@@ -1211,6 +1473,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m9.music",
 						exit: Some("e2m6.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(170)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1221,6 +1485,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m1.music",
 						exit: Some("e3m2.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1231,6 +1497,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m2.music",
 						exit: Some("e3m3.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(45)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1241,6 +1509,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m3.music",
 						exit: Some("e3m4.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1251,6 +1521,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m4.music",
 						exit: Some("e3m5.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(150)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1261,6 +1533,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m5.music",
 						exit: Some("e3m6.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1271,6 +1545,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m6.music",
 						exit: Some("e3m7.map"),
 						secret_exit: Some("e3m9.map"),
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1281,6 +1557,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m7.music",
 						exit: Some("e3m8.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(165)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1291,6 +1569,11 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "em.music",
 						exit: None,
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[BossSpecial {
+							entity: "spider.entity",
+							action: BossDeathAction::ExitMap,
+						}],
 					},
 				),
 				(
@@ -1301,6 +1584,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m9.music",
 						exit: Some("e3m7.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(135)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1311,6 +1596,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m4.music",
 						exit: Some("e4m2.map"),
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1321,6 +1608,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m2.music",
 						exit: Some("e4m3.map"),
 						secret_exit: Some("e4m9.map"),
+						par_time: None,
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1331,6 +1620,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e3m3.music",
 						exit: Some("e4m4.map"),
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1341,6 +1632,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m5.music",
 						exit: Some("e4m5.map"),
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1351,6 +1644,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m7.music",
 						exit: Some("e4m6.map"),
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1361,6 +1656,11 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m4.music",
 						exit: Some("e4m7.map"),
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[BossSpecial {
+							entity: "cyborg.entity",
+							action: BossDeathAction::OpenDoorFast(666),
+						}],
 					},
 				),
 				(
@@ -1371,6 +1671,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m6.music",
 						exit: Some("e4m8.map"),
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1381,6 +1683,11 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e2m5.music",
 						exit: None,
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[BossSpecial {
+							entity: "spider.entity",
+							action: BossDeathAction::LowerFloor(666),
+						}],
 					},
 				),
 				(
@@ -1391,6 +1698,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m9.music",
 						exit: Some("e4m3.map"),
 						secret_exit: None,
+						par_time: None,
+						boss_specials: &[],
 					},
 				),
 			]),
@@ -1416,6 +1725,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m1.music",
 						exit: Some("e1m2.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1426,6 +1737,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m2.music",
 						exit: Some("e1m3.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(75)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1436,6 +1749,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m3.music",
 						exit: Some("e1m4.map"),
 						secret_exit: Some("e1m9.map"),
+						par_time: Some(Duration::from_secs(120)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1446,6 +1761,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m4.music",
 						exit: Some("e1m5.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(90)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1456,6 +1773,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m5.music",
 						exit: Some("e1m6.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(165)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1466,6 +1785,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m6.music",
 						exit: Some("e1m7.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1476,6 +1797,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m7.music",
 						exit: Some("e1m8.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(180)),
+						boss_specials: &[],
 					},
 				),
 				(
@@ -1486,6 +1809,11 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m8.music",
 						exit: None,
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(30)),
+						boss_specials: &[BossSpecial {
+							entity: "bruiser.entity",
+							action: BossDeathAction::LowerFloor(666),
+						}],
 					},
 				),
 				(
@@ -1496,6 +1824,8 @@ pub static IWADINFO: Lazy<Vec<IWADInfo>> = Lazy::new(|| {
 						music: "e1m9.music",
 						exit: Some("e1m4.map"),
 						secret_exit: None,
+						par_time: Some(Duration::from_secs(165)),
+						boss_specials: &[],
 					},
 				),
 			]),
@@ -5,13 +5,27 @@ use crate::{
 		data::{FRAME_RATE, FRAME_TIME},
 		game::{
 			client::{Usable, UseEventDef},
+			item::KeyColor,
 			map::{
 				anim::TextureScroll,
+				ceiling::{
+					CeilingLinedefTouch, CeilingParams, CeilingStopLinedefTouch, CeilingSwitchUse,
+					CeilingTargetHeight,
+				},
 				door::{DoorLinedefTouch, DoorParams, DoorState, DoorSwitchUse, DoorUse},
 				exit::{ExitMapDef, ExitSwitchUse},
-				floor::{FloorLinedefTouch, FloorParams, FloorSwitchUse, FloorTargetHeight},
-				plat::{PlatLinedefTouch, PlatParams, PlatSwitchUse, PlatTargetHeight},
+				floor::{
+					FloorChangeType, FloorLinedefTouch, FloorParams, FloorSwitchUse,
+					FloorTargetHeight,
+				},
+				light::{LightLinedefTouch, LightParams, LightSwitchUse},
+				plat::{
+					PlatChangeType, PlatLinedefTouch, PlatParams, PlatSwitchUse, PlatTargetHeight,
+				},
+				sector_move::CrushParams,
+				stairs::{StairsLinedefTouch, StairsParams, StairsSwitchUse},
 				switch::SwitchParams,
+				teleport::{TeleportLinedefTouch, TeleportParams},
 				LinedefRefDef,
 			},
 			physics::{TouchEventDef, Touchable},
@@ -71,7 +85,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 
 						open_sound: Some(asset_storage.load("dsdoropn.sound")),
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
-					}
+					},
+					key: None,
 				},
 			));
 			world
@@ -80,7 +95,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, slow
-	// TODO blue key
 	linedefs.insert("linedef26.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -107,6 +121,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
 					},
 					retrigger: true,
+					key: Some(KeyColor::Blue),
 				},
 			));
 			world
@@ -115,7 +130,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, slow
-	// TODO red key
 	linedefs.insert("linedef28.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -142,6 +156,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
 					},
 					retrigger: true,
+					key: Some(KeyColor::Red),
 				},
 			));
 			world
@@ -150,7 +165,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, slow
-	// TODO yellow key
 	linedefs.insert("linedef27.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -177,6 +191,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
 					},
 					retrigger: true,
+					key: Some(KeyColor::Yellow),
 				},
 			));
 			world
@@ -210,6 +225,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsbdcls.sound")),
 					},
 					retrigger: true,
+					key: None,
 				},
 			));
 			world
@@ -248,6 +264,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
 					},
 					retrigger: false,
+					key: None,
 				},
 			));
 			world
@@ -256,7 +273,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, slow
-	// TODO blue key
 	linedefs.insert("linedef32.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -283,6 +299,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
 					},
 					retrigger: false,
+					key: Some(KeyColor::Blue),
 				},
 			));
 			world
@@ -291,7 +308,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, slow
-	// TODO red key
 	linedefs.insert("linedef33.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -318,6 +334,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
 					},
 					retrigger: false,
+					key: Some(KeyColor::Red),
 				},
 			));
 			world
@@ -326,7 +343,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, slow
-	// TODO yellow key
 	linedefs.insert("linedef34.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -353,6 +369,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsdorcls.sound")),
 					},
 					retrigger: false,
+					key: Some(KeyColor::Yellow),
 				},
 			));
 			world
@@ -387,6 +404,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						close_sound: Some(asset_storage.load("dsbdcls.sound")),
 					},
 					retrigger: false,
+					key: None,
 				},
 			));
 			world
@@ -428,6 +446,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: None,
 				},
 			));
 			world
@@ -465,6 +484,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: None,
 				},
 			));
 			world
@@ -502,6 +522,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: None,
 				},
 			));
 			world
@@ -539,6 +560,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: None,
 				},
 			));
 			world
@@ -580,6 +602,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: None,
 				},
 			));
 			world
@@ -617,6 +640,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: None,
 				},
 			));
 			world
@@ -625,7 +649,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, fast
-	// TODO blue key
 	linedefs.insert("linedef99.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -655,6 +678,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: Some(KeyColor::Blue),
 				},
 			));
 			world
@@ -663,7 +687,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, fast
-	// TODO red key
 	linedefs.insert("linedef134.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -693,6 +716,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: Some(KeyColor::Red),
 				},
 			));
 			world
@@ -701,7 +725,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, fast
-	// TODO yellow key
 	linedefs.insert("linedef136.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -731,6 +754,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: Some(KeyColor::Yellow),
 				},
 			));
 			world
@@ -768,6 +792,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: None,
 				},
 			));
 			world
@@ -805,6 +830,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: None,
 				},
 			));
 			world
@@ -813,7 +839,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, fast
-	// TODO blue key
 	linedefs.insert("linedef133.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -843,6 +868,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: Some(KeyColor::Blue),
 				},
 			));
 			world
@@ -851,7 +877,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, fast
-	// TODO red key
 	linedefs.insert("linedef135.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -881,6 +906,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: Some(KeyColor::Red),
 				},
 			));
 			world
@@ -889,7 +915,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, fast
-	// TODO yellow key
 	linedefs.insert("linedef137.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -919,6 +944,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: Some(KeyColor::Yellow),
 				},
 			));
 			world
@@ -960,6 +986,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: None,
 				},
 			));
 			world
@@ -997,6 +1024,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: Some(35 * FRAME_TIME),
 					},
+					key: None,
 				},
 			));
 			world
@@ -1034,6 +1062,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: None,
 				},
 			));
 			world
@@ -1071,6 +1100,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
+					key: None,
 				},
 			));
 			world
@@ -1597,6 +1627,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1636,6 +1668,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1671,6 +1705,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1710,6 +1746,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1745,6 +1783,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1780,6 +1820,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1815,6 +1857,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1854,6 +1898,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1889,6 +1935,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1902,7 +1950,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, slow, offset -8
-	// TODO crush
 	linedefs.insert("linedef65.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -1925,6 +1972,12 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -1938,7 +1991,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, slow, offset -8
-	// TODO crush
 	linedefs.insert("linedef55.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -1961,6 +2013,12 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2000,6 +2058,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2035,6 +2095,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2070,6 +2132,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2105,6 +2169,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2144,6 +2210,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2176,6 +2244,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2186,7 +2256,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, slow, offset 24
-	// TODO change type
 	linedefs.insert("linedef93.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -2209,6 +2278,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: Some(FloorChangeType::Trigger),
 					},
 					retrigger: true,
 				},
@@ -2219,7 +2290,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, slow, offset 24
-	// TODO change type
 	linedefs.insert("linedef59.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -2242,6 +2312,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: Some(FloorChangeType::Trigger),
 					},
 					retrigger: false,
 				},
@@ -2278,6 +2350,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2310,6 +2384,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2320,7 +2396,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, slow, offset 0
-	// TODO type change
 	linedefs.insert("linedef84.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -2343,6 +2418,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: Some(FloorChangeType::Neighbour),
 					},
 					retrigger: true,
 				},
@@ -2353,7 +2430,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, slow, offset 0
-	// TODO type change
 	linedefs.insert("linedef37.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -2376,6 +2452,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: Some(FloorChangeType::Neighbour),
 					},
 					retrigger: false,
 				},
@@ -2412,6 +2490,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2444,6 +2524,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2476,6 +2558,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2508,6 +2592,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2544,6 +2630,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2576,6 +2664,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2586,7 +2676,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// Retrigger, slow, offset -8
-	// TODO crush
 	linedefs.insert("linedef94.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -2609,6 +2698,12 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2619,7 +2714,6 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	// No retrigger, slow, offset -8
-	// TODO crush
 	linedefs.insert("linedef56.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
@@ -2642,6 +2736,12 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2678,6 +2778,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2710,6 +2812,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2742,6 +2846,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -2774,6 +2880,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						move_sound: Some(asset_storage.load("dsstnmov.sound")),
 						move_sound_time: 8 * FRAME_TIME,
 						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: None,
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -2817,6 +2925,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2859,6 +2969,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2901,6 +3013,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2943,6 +3057,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					switch_params: SwitchParams {
 						sound: Some(asset_storage.load("dsswtchn.sound")),
@@ -2989,6 +3105,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -3028,6 +3146,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					retrigger: true,
 				},
@@ -3067,6 +3187,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -3106,6 +3228,8 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 						low_height_offset: 0.0,
 						high_height_base: PlatTargetHeight::Current,
 						high_height_offset: 0.0,
+
+						change_type: None,
 					},
 					retrigger: false,
 				},
@@ -3116,16 +3240,16 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 	});
 
 	/*
-		Exit triggers
+		Switch plats, raise and change
 	*/
 
-	linedefs.insert("linedef11.entity", |asset_storage| EntityTemplate {
+	// Retrigger, slow, offset 24
+	linedefs.insert("linedef66.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
-				ExitMapDef { secret: false },
 				Usable,
 			));
 			world
@@ -3134,10 +3258,27 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 			let mut world = World::default();
 			world.push((
 				UseEventDef,
-				ExitSwitchUse {
+				PlatSwitchUse {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::Current,
+						high_height_offset: 24.0,
+
+						change_type: Some(PlatChangeType::Texture),
+					},
 					switch_params: SwitchParams {
-						sound: Some(asset_storage.load("dsswtchx.sound")),
-						retrigger_time: None,
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: Some(35 * FRAME_TIME),
 					},
 				},
 			));
@@ -3146,13 +3287,13 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef51.entity", |asset_storage| EntityTemplate {
+	// No retrigger, slow, offset 24
+	linedefs.insert("linedef15.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
-				ExitMapDef { secret: true },
 				Usable,
 			));
 			world
@@ -3161,9 +3302,26 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 			let mut world = World::default();
 			world.push((
 				UseEventDef,
-				ExitSwitchUse {
+				PlatSwitchUse {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::Current,
+						high_height_offset: 24.0,
+
+						change_type: Some(PlatChangeType::Texture),
+					},
 					switch_params: SwitchParams {
-						sound: Some(asset_storage.load("dsswtchx.sound")),
+						sound: Some(asset_storage.load("dsswtchn.sound")),
 						retrigger_time: None,
 					},
 				},
@@ -3173,282 +3331,1010 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	/*
-		Other
-	*/
-
-	linedefs.insert("linedef6.entity", |asset_storage| EntityTemplate {
+	// Retrigger, slow, offset 32
+	linedefs.insert("linedef67.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Usable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef7.entity", |asset_storage| EntityTemplate {
-		world: {
+		r#use: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				UseEventDef,
+				PlatSwitchUse {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::Current,
+						high_height_offset: 32.0,
+
+						change_type: Some(PlatChangeType::Texture),
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: Some(35 * FRAME_TIME),
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef8.entity", |asset_storage| EntityTemplate {
+	// No retrigger, slow, offset 32
+	linedefs.insert("linedef14.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Usable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef9.entity", |asset_storage| EntityTemplate {
-		world: {
+		r#use: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				UseEventDef,
+				PlatSwitchUse {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::Current,
+						high_height_offset: 32.0,
+
+						change_type: Some(PlatChangeType::Texture),
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: None,
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef12.entity", |asset_storage| EntityTemplate {
+	// Retrigger, slow, lowest neighbour floor above
+	linedefs.insert("linedef68.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Usable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef13.entity", |asset_storage| EntityTemplate {
-		world: {
+		r#use: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				UseEventDef,
+				PlatSwitchUse {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::LowestNeighbourFloorAbove,
+						high_height_offset: 0.0,
+
+						change_type: Some(PlatChangeType::TextureClearSpecial),
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: Some(35 * FRAME_TIME),
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef14.entity", |asset_storage| EntityTemplate {
+	// No retrigger, slow, lowest neighbour floor above
+	linedefs.insert("linedef20.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Usable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef15.entity", |asset_storage| EntityTemplate {
-		world: {
+		r#use: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				UseEventDef,
+				PlatSwitchUse {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::LowestNeighbourFloorAbove,
+						high_height_offset: 0.0,
+
+						change_type: Some(PlatChangeType::TextureClearSpecial),
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: None,
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef17.entity", |asset_storage| EntityTemplate {
+	/*
+		Linedef touch plats, raise and change
+	*/
+
+	// Retrigger, slow, lowest neighbour floor above
+	linedefs.insert("linedef95.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef20.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				PlatLinedefTouch {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::LowestNeighbourFloorAbove,
+						high_height_offset: 0.0,
+
+						change_type: Some(PlatChangeType::TextureClearSpecial),
+					},
+					retrigger: true,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
+	// No retrigger, slow, lowest neighbour floor above
 	linedefs.insert("linedef22.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef24.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				PlatLinedefTouch {
+					params: PlatParams {
+						speed: 0.5 * FRAME_RATE,
+						wait_time: Duration::default(),
+						can_reverse: false,
+
+						start_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+
+						low_height_base: PlatTargetHeight::Current,
+						low_height_offset: 0.0,
+						high_height_base: PlatTargetHeight::LowestNeighbourFloorAbove,
+						high_height_offset: 0.0,
+
+						change_type: Some(PlatChangeType::TextureClearSpecial),
+					},
+					retrigger: false,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef25.entity", |asset_storage| EntityTemplate {
+	/*
+		Switch stairs
+	*/
+
+	// No retrigger, slow, step 8
+	linedefs.insert("linedef7.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Usable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef30.entity", |asset_storage| EntityTemplate {
-		world: {
+		r#use: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				UseEventDef,
+				StairsSwitchUse {
+					params: StairsParams {
+						speed: 0.25 * FRAME_RATE,
+						step_height: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: None,
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef35.entity", |asset_storage| EntityTemplate {
+	// No retrigger, fast, step 16
+	linedefs.insert("linedef127.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Usable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef39.entity", |asset_storage| EntityTemplate {
+		r#use: {
+			let mut world = World::default();
+			world.push((
+				UseEventDef,
+				StairsSwitchUse {
+					params: StairsParams {
+						speed: 4.0 * FRAME_RATE,
+						step_height: 16.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: None,
+					},
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	/*
+		Linedef touch stairs
+	*/
+
+	// No retrigger, slow, step 8
+	linedefs.insert("linedef8.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				StairsLinedefTouch {
+					params: StairsParams {
+						speed: 0.25 * FRAME_RATE,
+						step_height: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+					},
+					retrigger: false,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, fast, step 16
+	linedefs.insert("linedef100.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				StairsLinedefTouch {
+					params: StairsParams {
+						speed: 4.0 * FRAME_RATE,
+						step_height: 16.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+					},
+					retrigger: false,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	/*
+		Switch ceilings
+	*/
+
+	// Retrigger, slow, lower to floor
+	linedefs.insert("linedef43.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Usable,
+			));
+			world
+		},
+		r#use: {
+			let mut world = World::default();
+			world.push((
+				UseEventDef,
+				CeilingSwitchUse {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 1.0 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 0.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: None,
+						perpetual: false,
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: Some(35 * FRAME_TIME),
+					},
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, slow, lower to floor
+	linedefs.insert("linedef41.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Usable,
+			));
+			world
+		},
+		r#use: {
+			let mut world = World::default();
+			world.push((
+				UseEventDef,
+				CeilingSwitchUse {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 1.0 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 0.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: None,
+						perpetual: false,
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: None,
+					},
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, slow, crush and raise
+	linedefs.insert("linedef49.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Usable,
+			));
+			world
+		},
+		r#use: {
+			let mut world = World::default();
+			world.push((
+				UseEventDef,
+				CeilingSwitchUse {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 0.125 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: true,
+					},
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: None,
+					},
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	/*
+		Linedef touch ceilings
+	*/
+
+	// Retrigger, slow, lower and crush
+	linedefs.insert("linedef72.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingLinedefTouch {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 0.125 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: false,
+					},
+					retrigger: true,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, slow, lower and crush
+	linedefs.insert("linedef44.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingLinedefTouch {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 0.125 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: false,
+					},
+					retrigger: false,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// Retrigger, slow, crush and raise
+	linedefs.insert("linedef73.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingLinedefTouch {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 0.125 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: true,
+					},
+					retrigger: true,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, slow, crush and raise
+	linedefs.insert("linedef25.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingLinedefTouch {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 0.125 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: true,
+					},
+					retrigger: false,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// Retrigger, fast, crush and raise
+	linedefs.insert("linedef77.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingLinedefTouch {
+					params: CeilingParams {
+						speed: 2.0 * FRAME_RATE,
+						crush_speed: 2.0 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: true,
+					},
+					retrigger: true,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, fast, crush and raise
+	linedefs.insert("linedef6.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingLinedefTouch {
+					params: CeilingParams {
+						speed: 2.0 * FRAME_RATE,
+						crush_speed: 2.0 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: Some(asset_storage.load("dsstnmov.sound")),
+						move_sound_time: 8 * FRAME_TIME,
+						finish_sound: None,
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: true,
+					},
+					retrigger: false,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, slow, silent crush and raise
+	linedefs.insert("linedef141.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingLinedefTouch {
+					params: CeilingParams {
+						speed: 1.0 * FRAME_RATE,
+						crush_speed: 0.125 * FRAME_RATE,
+						target_height_base: CeilingTargetHeight::Floor,
+						target_height_offset: 8.0,
+						move_sound: None,
+						move_sound_time: Duration::default(),
+						finish_sound: Some(asset_storage.load("dspstop.sound")),
+						crush: Some(CrushParams {
+							damage: 10,
+							damage_time: 4 * FRAME_TIME,
+							gibs_sprite: asset_storage.load("pol5.sprite"),
+						}),
+						perpetual: true,
+					},
+					retrigger: false,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// Retrigger, stop crusher
+	linedefs.insert("linedef74.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingStopLinedefTouch {
+					retrigger: true,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// No retrigger, stop crusher
+	linedefs.insert("linedef57.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				CeilingStopLinedefTouch {
+					retrigger: false,
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	/*
+		Switch lights
+	*/
+
+	// Retrigger, light level 35
+	linedefs.insert("linedef139.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Usable,
+			));
+			world
+		},
+		r#use: {
+			let mut world = World::default();
+			world.push((
+				UseEventDef,
+				LightSwitchUse {
+					params: LightParams::Set(35.0 / 255.0),
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: Some(35 * FRAME_TIME),
+					},
+				},
+			));
+			world
+		},
+		.. EntityTemplate::default()
+	});
+
+	// Retrigger, light level 255
+	linedefs.insert("linedef138.entity", |asset_storage| EntityTemplate {
+		world: {
+			let mut world = World::default();
+			world.push((
+				EntityTemplateRefDef,
+				LinedefRefDef,
+				Usable,
+			));
+			world
+		},
+		r#use: {
+			let mut world = World::default();
+			world.push((
+				UseEventDef,
+				LightSwitchUse {
+					params: LightParams::Set(255.0 / 255.0),
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchn.sound")),
+						retrigger_time: Some(35 * FRAME_TIME),
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef40.entity", |asset_storage| EntityTemplate {
+	/*
+		Linedef touch lights
+	*/
+
+	// Retrigger, light level 35
+	linedefs.insert("linedef79.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef41.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::Set(35.0 / 255.0),
+					retrigger: true,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef43.entity", |asset_storage| EntityTemplate {
+	// No retrigger, light level 35
+	linedefs.insert("linedef35.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef44.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::Set(35.0 / 255.0),
+					retrigger: false,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef46.entity", |asset_storage| EntityTemplate {
+	// Retrigger, light level 255
+	linedefs.insert("linedef81.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef47.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::Set(255.0 / 255.0),
+					retrigger: true,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef48.entity", |asset_storage| EntityTemplate {
+	// No retrigger, light level 255
+	linedefs.insert("linedef13.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
-				TextureScroll {
-					speed: Vector2::new(35.0, 0.0),
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::Set(255.0 / 255.0),
+					retrigger: false,
 				},
 			));
 			world
@@ -3456,187 +4342,288 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef49.entity", |asset_storage| EntityTemplate {
+	// Retrigger, highest neighbour light level
+	linedefs.insert("linedef80.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef52.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::HighestNeighbour,
+					retrigger: true,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef53.entity", |asset_storage| EntityTemplate {
+	// No retrigger, highest neighbour light level
+	linedefs.insert("linedef12.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef54.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::HighestNeighbour,
+					retrigger: false,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef57.entity", |asset_storage| EntityTemplate {
+	// No retrigger, lowest neighbour light level
+	linedefs.insert("linedef104.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef66.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::LowestNeighbour,
+					retrigger: false,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef67.entity", |asset_storage| EntityTemplate {
+	// No retrigger, start strobing
+	linedefs.insert("linedef17.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef68.entity", |asset_storage| EntityTemplate {
-		world: {
+		touch: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				TouchEventDef,
+				LightLinedefTouch {
+					params: LightParams::Strobe {
+						on_time: 5 * FRAME_TIME,
+						off_time: 35 * FRAME_TIME,
+					},
+					retrigger: false,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef72.entity", |asset_storage| EntityTemplate {
+	/*
+		Exit triggers
+	*/
+
+	linedefs.insert("linedef11.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				ExitMapDef { secret: false },
+				Usable,
 			));
 			world
 		},
-		.. EntityTemplate::default()
-	});
-
-	linedefs.insert("linedef73.entity", |asset_storage| EntityTemplate {
-		world: {
+		r#use: {
 			let mut world = World::default();
 			world.push((
-				EntityTemplateRefDef,
-				LinedefRefDef,
+				UseEventDef,
+				ExitSwitchUse {
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchx.sound")),
+						retrigger_time: None,
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef74.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef51.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				ExitMapDef { secret: true },
+				Usable,
+			));
+			world
+		},
+		r#use: {
+			let mut world = World::default();
+			world.push((
+				UseEventDef,
+				ExitSwitchUse {
+					switch_params: SwitchParams {
+						sound: Some(asset_storage.load("dsswtchx.sound")),
+						retrigger_time: None,
+					},
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef77.entity", |asset_storage| EntityTemplate {
+	/*
+		Teleporters
+	*/
+
+	// Retrigger
+	linedefs.insert("linedef97.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				TeleportLinedefTouch {
+					params: TeleportParams {
+						fog: Some(asset_storage.load("tfog.entity")),
+						monsters_only: false,
+					},
+					retrigger: true,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef79.entity", |asset_storage| EntityTemplate {
+	// No retrigger
+	linedefs.insert("linedef39.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				TeleportLinedefTouch {
+					params: TeleportParams {
+						fog: Some(asset_storage.load("tfog.entity")),
+						monsters_only: false,
+					},
+					retrigger: false,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef80.entity", |asset_storage| EntityTemplate {
+	// Retrigger, monsters only
+	linedefs.insert("linedef126.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				TeleportLinedefTouch {
+					params: TeleportParams {
+						fog: Some(asset_storage.load("tfog.entity")),
+						monsters_only: true,
+					},
+					retrigger: true,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef81.entity", |asset_storage| EntityTemplate {
+	// No retrigger, monsters only
+	linedefs.insert("linedef125.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				Touchable,
+			));
+			world
+		},
+		touch: {
+			let mut world = World::default();
+			world.push((
+				TouchEventDef,
+				TeleportLinedefTouch {
+					params: TeleportParams {
+						fog: Some(asset_storage.load("tfog.entity")),
+						monsters_only: true,
+					},
+					retrigger: false,
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef87.entity", |asset_storage| EntityTemplate {
+	/*
+		Other
+	*/
+
+	linedefs.insert("linedef9.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3648,7 +4635,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef89.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef24.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3660,7 +4647,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef95.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef30.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3672,7 +4659,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef96.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef40.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3684,7 +4671,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef97.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef46.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3696,7 +4683,9 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef100.entity", |asset_storage| EntityTemplate {
+	// TODO raise to nearest floor and change texture when shot, like linedef 22.
+	// Linedefs can't be activated by gunfire yet.
+	linedefs.insert("linedef47.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3708,19 +4697,22 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef104.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef48.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
 				EntityTemplateRefDef,
 				LinedefRefDef,
+				TextureScroll {
+					speed: Vector2::new(35.0, 0.0),
+				},
 			));
 			world
 		},
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef124.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef52.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3732,7 +4724,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef125.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef53.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3744,7 +4736,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef126.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef54.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3756,7 +4748,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef127.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef87.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3768,7 +4760,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef138.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef89.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3780,7 +4772,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef139.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef96.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -3792,7 +4784,7 @@ pub static LINEDEFS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityT
 		.. EntityTemplate::default()
 	});
 
-	linedefs.insert("linedef141.entity", |asset_storage| EntityTemplate {
+	linedefs.insert("linedef124.entity", |asset_storage| EntityTemplate {
 		world: {
 			let mut world = World::default();
 			world.push((
@@ -1,28 +1,48 @@
 #![allow(unused_variables)]
 use crate::{
-	common::{assets::AssetStorage, geometry::Angle},
+	common::{
+		assets::AssetStorage,
+		geometry::{Angle, Interval},
+	},
 	doom::{
 		assets::template::{EntityTemplate, EntityTemplateRefDef},
 		data::{FRAME_RATE, FRAME_TIME},
-		draw::{sprite::SpriteRender, wsprite::WeaponSpriteRender},
+		draw::{
+			sprite::{SpriteFuzz, SpriteRender},
+			wsprite::WeaponSpriteRender,
+		},
 		game::{
+			ai::{
+				ChargeTouch, MeleeAttack, MonsterBlastTarget, MonsterCharge, MonsterChase,
+				MonsterDef, MonsterFaceTarget, MonsterLook, MonsterRaiseCorpses, MonsterReFire,
+				SpawnOnTarget,
+			},
+			boss::{BossDeath, BossDeathAction, BrainSpit, BrainSpitter, BrainTarget},
 			camera::{Camera, MovementBob},
 			client::{PlayerTouch, User},
 			combat::{
-				weapon::{AmmoState, WeaponStateDef},
-				HealthDef, OwnerDef, ProjectileTouch, RadiusAttack, SprayAttack,
+				weapon::{AmmoState, LineAttack, WeaponStateDef},
+				Armor, ArmorType, FastProjectileSpeed, HealthDef, NoRetaliation, OwnerDef,
+				ProjectileTouch, RadiusAttack, SpawnProjectile, SprayAttack,
+			},
+			deathmatch::Frags,
+			item::{
+				AmmoPickup, ArmorPickup, KeyColor, KeyInventory, KeyPickup, KeyType, PowerupPickup,
+				PowerupType, Powerups, WeaponPickup,
 			},
-			map::SpawnPoint,
+			map::{teleport::TeleportDest, DeathmatchSpawnPoint, SpawnPoint},
 			physics::{
 				BoxCollider, CollisionResponse, DamageParticle, Physics, PhysicsDef,
 				SetBlocksTypes, SetSolidType, SolidBits, SolidType, TouchEventDef, Touchable,
 			},
 			state::{
 				entity::{
-					EntityStateEventDef, NextState, NextStateRandomTimeDef, RemoveEntity, StateDef,
+					EntityStateEventDef, FastState, NextState, NextStateRandomTimeDef,
+					RemoveEntity, StateDef,
 				},
 				StateName,
 			},
+			stats::{CountItem, CountKill},
 			RandomTransformDef, SetEntitySprite, TransformDef,
 		},
 		sound::StartSoundEventDef,
@@ -107,6 +127,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					DeathmatchSpawnPoint,
 					EntityTemplateRefDef,
 					TransformDef {
 						spawn_on_ceiling: false,
@@ -122,6 +143,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					Armor::default(),
 					BoxCollider {
 						height: 56.0,
 						radius: 16.0,
@@ -140,10 +162,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						extra_light: 0.0,
 					},
 					EntityTemplateRefDef,
+					Frags::default(),
 					HealthDef {
 						max: 100,
 						pain_chance: 0.99609375,
 					},
+					KeyInventory::default(),
 					MovementBob {
 						max: 16.0,
 						amplitude: 0.0,
@@ -154,6 +178,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					Powerups::default(),
 					SpriteRender {
 						sprite: asset_storage.load("play.sprite"),
 						frame: 0,
@@ -650,13 +675,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 20,
 						pain_chance: 0.78125,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsposact.sound")),
+						see_sound: Some(asset_storage.load("dsposit1.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 100.0,
 						speed: 0.0,
@@ -682,6 +717,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -698,6 +734,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -719,6 +756,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -735,6 +773,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -751,6 +790,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -767,6 +807,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -783,6 +824,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -799,6 +841,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -815,6 +858,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -831,6 +875,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -892,6 +937,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -908,6 +954,18 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 1,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -918,6 +976,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dspistol.sound"),
+							},));
 							world
 						},
 						{
@@ -1258,13 +1319,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 30,
 						pain_chance: 0.6640625,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsposact.sound")),
+						see_sound: Some(asset_storage.load("dsposit1.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 100.0,
 						speed: 0.0,
@@ -1290,6 +1361,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -1306,6 +1378,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -1327,6 +1400,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -1343,6 +1417,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -1359,6 +1434,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -1375,6 +1451,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -1391,6 +1468,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -1407,6 +1485,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -1423,6 +1502,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -1439,6 +1519,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -1500,6 +1581,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -1516,6 +1598,18 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 3,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -1526,6 +1620,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsshotgn.sound"),
+							},));
 							world
 						},
 						{
@@ -1882,13 +1979,24 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 700,
 						pain_chance: 0.0390625,
 					},
+					MonsterDef {
+						speed: 15.0,
+						missile_range: Interval::new(f32::NEG_INFINITY, 14.0 * 64.0),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsvilact.sound")),
+						see_sound: Some(asset_storage.load("dsvilsit.sound")),
+					},
+					NoRetaliation,
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 500.0,
 						speed: 0.0,
@@ -1906,7 +2014,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 				world
 			},
 			states: {
-				let mut states = HashMap::with_capacity(37);
+				let mut states = HashMap::with_capacity(40);
 				states.insert(
 					StateName::from("spawn").unwrap(),
 					vec![
@@ -1914,6 +2022,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -1930,6 +2039,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -1951,6 +2061,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -1967,6 +2081,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -1983,6 +2101,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -1999,6 +2121,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -2015,6 +2141,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -2031,6 +2161,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -2047,6 +2181,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -2063,6 +2201,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 8),
@@ -2079,6 +2221,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 9),
@@ -2095,6 +2241,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 10),
@@ -2111,6 +2261,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 11),
@@ -2127,6 +2281,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
+								MonsterRaiseCorpses {
+									sound: asset_storage.load("dsslop.sound"),
+								},
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -2181,6 +2339,59 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						},
 					],
 				);
+				states.insert(
+					StateName::from("heal").unwrap(),
+					vec![
+						{
+							let mut world = World::default();
+							world.push((
+								EntityStateEventDef,
+								NextState {
+									time: 10 * FRAME_TIME,
+									state: (StateName::from("heal").unwrap(), 1),
+								},
+								SetEntitySprite(SpriteRender {
+									sprite: asset_storage.load("vile.sprite"),
+									frame: 26,
+									full_bright: true,
+								}),
+							));
+							world
+						},
+						{
+							let mut world = World::default();
+							world.push((
+								EntityStateEventDef,
+								NextState {
+									time: 10 * FRAME_TIME,
+									state: (StateName::from("heal").unwrap(), 2),
+								},
+								SetEntitySprite(SpriteRender {
+									sprite: asset_storage.load("vile.sprite"),
+									frame: 27,
+									full_bright: true,
+								}),
+							));
+							world
+						},
+						{
+							let mut world = World::default();
+							world.push((
+								EntityStateEventDef,
+								NextState {
+									time: 10 * FRAME_TIME,
+									state: (StateName::from("see").unwrap(), 0),
+								},
+								SetEntitySprite(SpriteRender {
+									sprite: asset_storage.load("vile.sprite"),
+									frame: 28,
+									full_bright: true,
+								}),
+							));
+							world
+						},
+					],
+				);
 				states.insert(
 					StateName::from("missile").unwrap(),
 					vec![
@@ -2198,12 +2409,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsvilatk.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -2220,6 +2435,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -2229,6 +2445,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 7,
 									full_bright: true,
 								}),
+								SpawnOnTarget {
+									handle: asset_storage.load("fire.entity"),
+								},
 							));
 							world
 						},
@@ -2236,6 +2455,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 4),
@@ -2252,6 +2472,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 5),
@@ -2268,6 +2489,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 6),
@@ -2284,6 +2506,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 7),
@@ -2300,6 +2523,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 8),
@@ -2316,6 +2540,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterBlastTarget {
+									damage: 20,
+									explosion: RadiusAttack {
+										damage: 70,
+										radius: 70.0,
+									},
+									sound: Some(asset_storage.load("dsbarexp.sound")),
+									thrust: 1000.0 * FRAME_RATE,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 9),
@@ -2582,6 +2816,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsflamst.sound"),
+							},));
 							world
 						},
 						{
@@ -2646,6 +2883,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsflame.sound"),
+							},));
 							world
 						},
 						{
@@ -2870,6 +3110,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsflame.sound"),
+							},));
 							world
 						},
 						{
@@ -3072,13 +3315,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 300,
 						pain_chance: 0.390625,
 					},
+					MonsterDef {
+						speed: 10.0,
+						missile_range: Interval::new(196.0, f32::INFINITY),
+						missile_distance_scale: 0.5,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsskeact.sound")),
+						see_sound: Some(asset_storage.load("dsskesit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 500.0,
 						speed: 0.0,
@@ -3104,6 +3357,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -3120,6 +3374,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -3141,6 +3396,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -3157,6 +3413,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -3173,6 +3430,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -3189,6 +3447,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -3205,6 +3464,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -3221,6 +3481,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -3237,6 +3498,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -3253,6 +3515,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 8),
@@ -3269,6 +3532,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 9),
@@ -3285,6 +3549,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 10),
@@ -3301,6 +3566,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 11),
@@ -3317,6 +3583,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -3378,6 +3645,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 0 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 1),
@@ -3394,6 +3662,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 2),
@@ -3404,12 +3673,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsskeswg.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 3),
@@ -3426,6 +3699,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=10).into(),
+									damage_multiplier: 6,
+									sound: Some(asset_storage.load("dsskepch.sound")),
+									projectile: None,
+								},
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -3447,6 +3726,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 0 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -3463,6 +3743,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -3479,6 +3760,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -3488,6 +3770,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 10,
 									full_bright: false,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("tracer.entity"),
+									angle: Angle(0),
+								},
 							));
 							world
 						},
@@ -3495,6 +3781,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -4025,13 +4312,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 600,
 						pain_chance: 0.3125,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsposact.sound")),
+						see_sound: Some(asset_storage.load("dsmansit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 1000.0,
 						speed: 0.0,
@@ -4057,6 +4354,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 15 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -4073,6 +4371,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 15 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -4094,6 +4393,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -4110,6 +4410,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -4126,6 +4427,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -4142,6 +4444,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -4158,6 +4461,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -4174,6 +4478,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -4190,6 +4495,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -4206,6 +4512,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 8),
@@ -4222,6 +4529,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 9),
@@ -4238,6 +4546,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 10),
@@ -4254,6 +4563,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 11),
@@ -4270,6 +4580,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -4331,6 +4642,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 20 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -4341,12 +4653,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsmanatk.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -4356,6 +4672,17 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 7,
 									full_bright: true,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("fatshot.entity"),
+									angle: Angle(0),
+								},
+							));
+							world.push((
+								EntityStateEventDef,
+								SpawnProjectile {
+									handle: asset_storage.load("fatshot.entity"),
+									angle: Angle::from_degrees(11.25),
+								},
 							));
 							world
 						},
@@ -4363,6 +4690,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -4379,6 +4707,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 4),
@@ -4395,6 +4724,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 5),
@@ -4404,6 +4734,17 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 7,
 									full_bright: true,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("fatshot.entity"),
+									angle: Angle(0),
+								},
+							));
+							world.push((
+								EntityStateEventDef,
+								SpawnProjectile {
+									handle: asset_storage.load("fatshot.entity"),
+									angle: Angle::from_degrees(-22.5),
+								},
 							));
 							world
 						},
@@ -4411,6 +4752,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 6),
@@ -4427,6 +4769,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 7),
@@ -4443,6 +4786,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 8),
@@ -4452,6 +4796,17 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 7,
 									full_bright: true,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("fatshot.entity"),
+									angle: Angle::from_degrees(-5.625),
+								},
+							));
+							world.push((
+								EntityStateEventDef,
+								SpawnProjectile {
+									handle: asset_storage.load("fatshot.entity"),
+									angle: Angle::from_degrees(5.625),
+								},
 							));
 							world
 						},
@@ -4459,6 +4814,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 9),
@@ -4475,6 +4831,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -4643,6 +5000,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BossDeath { action: None },
 								EntityStateEventDef,
 								SetEntitySprite(SpriteRender {
 									sprite: asset_storage.load("fatt.sprite"),
@@ -4973,13 +5331,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 70,
 						pain_chance: 0.6640625,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsposact.sound")),
+						see_sound: Some(asset_storage.load("dsposit1.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 100.0,
 						speed: 0.0,
@@ -5005,6 +5373,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -5021,6 +5390,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -5042,6 +5412,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -5058,6 +5429,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -5074,6 +5446,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -5090,6 +5463,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -5106,6 +5480,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -5122,6 +5497,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -5138,6 +5514,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -5154,6 +5531,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -5215,6 +5593,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -5231,6 +5610,18 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 1,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -5241,12 +5632,27 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsshotgn.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 1,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -5257,12 +5663,19 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsshotgn.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
+								MonsterReFire {
+									chance: 40.0 / 256.0,
+								},
 								NextState {
 									time: 1 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -5629,13 +6042,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 60,
 						pain_chance: 0.78125,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsbgact.sound")),
+						see_sound: Some(asset_storage.load("dsbgsit1.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 100.0,
 						speed: 0.0,
@@ -5661,6 +6084,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -5677,6 +6101,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -5698,6 +6123,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -5714,6 +6140,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -5730,6 +6157,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -5746,6 +6174,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -5762,6 +6191,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -5778,6 +6208,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -5794,6 +6225,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -5810,6 +6242,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -5871,6 +6304,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 1),
@@ -5887,6 +6321,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 2),
@@ -5903,6 +6338,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=8).into(),
+									damage_multiplier: 3,
+									sound: Some(asset_storage.load("dsclaw.sound")),
+									projectile: Some(asset_storage.load("troopshot.entity")),
+								},
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -5924,6 +6365,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -5940,6 +6382,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -5956,6 +6399,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=8).into(),
+									damage_multiplier: 3,
+									sound: Some(asset_storage.load("dsclaw.sound")),
+									projectile: Some(asset_storage.load("troopshot.entity")),
+								},
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -6290,13 +6739,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 150,
 						pain_chance: 0.703125,
 					},
+					MonsterDef {
+						speed: 10.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: Some(asset_storage.load("dssgtatk.sound")),
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dssgtsit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 400.0,
 						speed: 0.0,
@@ -6322,6 +6781,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -6338,6 +6798,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -6359,6 +6820,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -6375,6 +6838,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -6391,6 +6856,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -6407,6 +6874,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -6423,6 +6892,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -6439,6 +6910,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -6455,6 +6928,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -6471,6 +6946,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -6492,6 +6969,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("pain").unwrap(), 1),
@@ -6508,6 +6986,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -6532,6 +7011,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 1),
@@ -6548,6 +7029,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 2),
@@ -6564,6 +7047,13 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MeleeAttack {
+									damage_range: (1..=10).into(),
+									damage_multiplier: 4,
+									sound: None,
+									projectile: None,
+								},
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -6797,17 +7287,28 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 150,
 						pain_chance: 0.703125,
 					},
+					MonsterDef {
+						speed: 10.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: Some(asset_storage.load("dssgtatk.sound")),
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dssgtsit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 400.0,
 						speed: 0.0,
 					},
+					SpriteFuzz,
 					SpriteRender {
 						sprite: asset_storage.load("sarg.sprite"),
 						frame: 0,
@@ -6829,6 +7330,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -6845,6 +7347,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -6866,6 +7369,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -6882,6 +7387,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -6898,6 +7405,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -6914,6 +7423,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -6930,6 +7441,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -6946,6 +7459,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -6962,6 +7477,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -6978,6 +7495,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterChase,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -6999,6 +7518,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("pain").unwrap(), 1),
@@ -7015,6 +7535,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
 								NextState {
 									time: 2 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -7039,6 +7560,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 1),
@@ -7055,6 +7578,8 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 2),
@@ -7071,6 +7596,13 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								FastState,
+								MeleeAttack {
+									damage_range: (1..=10).into(),
+									damage_multiplier: 4,
+									sound: None,
+									projectile: None,
+								},
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -7304,13 +7836,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 400,
 						pain_chance: 0.5,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dscacsit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: false,
 						mass: 400.0,
 						speed: 0.0,
@@ -7335,6 +7877,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						let mut world = World::default();
 						world.push((
 							EntityStateEventDef,
+							MonsterLook,
 							NextState {
 								time: 10 * FRAME_TIME,
 								state: (StateName::from("spawn").unwrap(), 0),
@@ -7354,6 +7897,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						let mut world = World::default();
 						world.push((
 							EntityStateEventDef,
+							MonsterChase,
 							NextState {
 								time: 3 * FRAME_TIME,
 								state: (StateName::from("see").unwrap(), 0),
@@ -7430,6 +7974,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -7446,6 +7991,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -7462,6 +8008,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=6).into(),
+									damage_multiplier: 10,
+									sound: None,
+									projectile: Some(asset_storage.load("headshot.entity")),
+								},
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -7695,13 +8247,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 1000,
 						pain_chance: 0.1953125,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dsbrssit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 1000.0,
 						speed: 0.0,
@@ -7727,6 +8289,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -7743,6 +8306,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -7764,6 +8328,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -7780,6 +8345,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -7796,6 +8362,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -7812,6 +8379,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -7828,6 +8396,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -7844,6 +8413,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -7860,6 +8430,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -7876,6 +8447,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -7937,6 +8509,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 1),
@@ -7953,6 +8526,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 2),
@@ -7969,6 +8543,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=8).into(),
+									damage_multiplier: 10,
+									sound: Some(asset_storage.load("dsclaw.sound")),
+									projectile: Some(asset_storage.load("bruisershot.entity")),
+								},
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -7990,6 +8570,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -8006,6 +8587,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -8022,6 +8604,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=8).into(),
+									damage_multiplier: 10,
+									sound: Some(asset_storage.load("dsclaw.sound")),
+									projectile: Some(asset_storage.load("bruisershot.entity")),
+								},
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -8142,6 +8730,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BossDeath { action: None },
 								EntityStateEventDef,
 								SetEntitySprite(SpriteRender {
 									sprite: asset_storage.load("boss.sprite"),
@@ -8288,6 +8877,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					FastProjectileSpeed(20.0 * FRAME_RATE),
 					OwnerDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -8456,13 +9046,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 500,
 						pain_chance: 0.1953125,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dskntsit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 1000.0,
 						speed: 0.0,
@@ -8488,6 +9088,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -8504,6 +9105,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -8525,6 +9127,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -8541,6 +9144,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -8557,6 +9161,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -8573,6 +9178,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -8589,6 +9195,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -8605,6 +9212,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -8621,6 +9229,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -8637,6 +9246,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -8698,6 +9308,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 1),
@@ -8714,6 +9325,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("melee").unwrap(), 2),
@@ -8730,6 +9342,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=8).into(),
+									damage_multiplier: 10,
+									sound: Some(asset_storage.load("dsclaw.sound")),
+									projectile: Some(asset_storage.load("bruisershot.entity")),
+								},
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -8751,6 +9369,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -8767,6 +9386,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -8783,6 +9403,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MeleeAttack {
+									damage_range: (1..=8).into(),
+									damage_multiplier: 10,
+									sound: Some(asset_storage.load("dsclaw.sound")),
+									projectile: Some(asset_storage.load("bruisershot.entity")),
+								},
 								NextState {
 									time: 8 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -9053,6 +9679,15 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						max: 100,
 						pain_chance: 1.0,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 0.5,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: None,
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: false,
@@ -9065,6 +9700,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						full_bright: true,
 					},
 					StateDef,
+					Touchable,
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
@@ -9080,6 +9716,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -9096,6 +9733,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -9117,6 +9755,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -9133,6 +9772,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -9194,6 +9834,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -9210,6 +9851,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterCharge {
+									speed: 20.0 * FRAME_RATE,
+								},
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -9220,6 +9864,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dssklatk.sound"),
+							},));
 							world
 						},
 						{
@@ -9368,6 +10015,17 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 				);
 				states
 			},
+			touch: {
+				let mut world = World::default();
+				world.push((
+					TouchEventDef,
+					ChargeTouch {
+						damage_range: (1..=8).into(),
+						damage_multiplier: 3,
+					},
+				));
+				world
+			},
 			..EntityTemplate::default()
 		});
 
@@ -9383,13 +10041,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 3000,
 						pain_chance: 0.15625,
 					},
+					MonsterDef {
+						speed: 12.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 0.5,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dsspisit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 1000.0,
 						speed: 0.0,
@@ -9415,6 +10083,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -9431,6 +10100,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -9452,6 +10122,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -9462,12 +10133,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsmetal.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -9484,6 +10159,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -9500,6 +10176,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -9516,6 +10193,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -9526,12 +10204,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsmetal.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -9548,6 +10230,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -9564,6 +10247,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 8),
@@ -9580,6 +10264,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 9),
@@ -9590,12 +10275,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsmetal.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 10),
@@ -9612,6 +10301,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 11),
@@ -9628,6 +10318,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -9689,6 +10380,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 20 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -9705,6 +10397,18 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 3,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -9715,12 +10419,27 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsshotgn.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 3,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -9731,12 +10450,19 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsshotgn.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
+								MonsterReFire {
+									chance: 10.0 / 256.0,
+								},
 								NextState {
 									time: 1 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -9921,6 +10647,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BossDeath { action: None },
 								EntityStateEventDef,
 								SetEntitySprite(SpriteRender {
 									sprite: asset_storage.load("spid.sprite"),
@@ -9949,13 +10676,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 500,
 						pain_chance: 0.5,
 					},
+					MonsterDef {
+						speed: 12.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsbspact.sound")),
+						see_sound: Some(asset_storage.load("dsbspsit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 600.0,
 						speed: 0.0,
@@ -9981,6 +10718,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -9997,6 +10735,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -10034,6 +10773,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -10044,12 +10784,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsbspwlk.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -10066,6 +10810,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -10082,6 +10827,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -10098,6 +10844,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -10114,6 +10861,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -10130,6 +10878,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 8),
@@ -10146,6 +10895,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 9),
@@ -10162,6 +10912,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 10),
@@ -10178,6 +10929,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 11),
@@ -10194,6 +10946,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 12),
@@ -10210,6 +10963,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -10271,6 +11025,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 20 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -10287,6 +11042,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -10296,6 +11052,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 6,
 									full_bright: true,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("arachplaz.entity"),
+									angle: Angle(0),
+								},
 							));
 							world
 						},
@@ -10319,6 +11079,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
+								MonsterReFire {
+									chance: 10.0 / 256.0,
+								},
 								NextState {
 									time: 1 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -10439,6 +11203,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BossDeath { action: None },
 								EntityStateEventDef,
 								SetEntitySprite(SpriteRender {
 									sprite: asset_storage.load("bspi.sprite"),
@@ -10584,13 +11349,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 4000,
 						pain_chance: 0.078125,
 					},
+					MonsterDef {
+						speed: 16.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 0.5,
+						missile_distance_max: 160.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dscybsit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 1000.0,
 						speed: 0.0,
@@ -10616,6 +11391,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -10632,6 +11408,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -10653,6 +11430,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -10663,12 +11441,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dshoof.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -10685,6 +11467,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -10701,6 +11484,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -10717,6 +11501,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -10733,6 +11518,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -10749,6 +11535,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -10759,12 +11546,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: false,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsmetal.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -10808,6 +11599,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -10824,6 +11616,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 12 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -10833,6 +11626,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 5,
 									full_bright: false,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("rocket.entity"),
+									angle: Angle(0),
+								},
 							));
 							world
 						},
@@ -10840,6 +11637,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 12 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -10856,6 +11654,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 12 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 4),
@@ -10865,6 +11664,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 5,
 									full_bright: false,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("rocket.entity"),
+									angle: Angle(0),
+								},
 							));
 							world
 						},
@@ -10872,6 +11675,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 12 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 5),
@@ -10888,6 +11692,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 12 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -10897,6 +11702,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									frame: 5,
 									full_bright: false,
 								}),
+								SpawnProjectile {
+									handle: asset_storage.load("rocket.entity"),
+									angle: Angle(0),
+								},
 							));
 							world
 						},
@@ -11056,6 +11865,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BossDeath { action: None },
 								EntityStateEventDef,
 								SetEntitySprite(SpriteRender {
 									sprite: asset_storage.load("cybr.sprite"),
@@ -11084,13 +11894,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 400,
 						pain_chance: 0.5,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsdmact.sound")),
+						see_sound: Some(asset_storage.load("dspesit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: false,
 						mass: 400.0,
 						speed: 0.0,
@@ -11115,6 +11935,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						let mut world = World::default();
 						world.push((
 							EntityStateEventDef,
+							MonsterLook,
 							NextState {
 								time: 10 * FRAME_TIME,
 								state: (StateName::from("spawn").unwrap(), 0),
@@ -11135,6 +11956,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -11151,6 +11973,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -11167,6 +11990,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -11183,6 +12007,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -11199,6 +12024,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -11215,6 +12041,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -11276,6 +12103,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -11292,6 +12120,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -11308,6 +12137,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 5 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -11565,13 +12395,23 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 50,
 						pain_chance: 0.6640625,
 					},
+					MonsterDef {
+						speed: 8.0,
+						missile_range: Interval::full(),
+						missile_distance_scale: 1.0,
+						missile_distance_max: 200.0,
+						attack_sound: None,
+						active_sound: Some(asset_storage.load("dsposact.sound")),
+						see_sound: Some(asset_storage.load("dssssit.sound")),
+					},
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::StepSlide,
 						gravity: true,
 						mass: 100.0,
 						speed: 0.0,
@@ -11597,6 +12437,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 1),
@@ -11613,6 +12454,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterLook,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("spawn").unwrap(), 0),
@@ -11634,6 +12476,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 1),
@@ -11650,6 +12493,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 2),
@@ -11666,6 +12510,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 3),
@@ -11682,6 +12527,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 4),
@@ -11698,6 +12544,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 5),
@@ -11714,6 +12561,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 6),
@@ -11730,6 +12578,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 7),
@@ -11746,6 +12595,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterChase,
 								NextState {
 									time: 3 * FRAME_TIME,
 									state: (StateName::from("see").unwrap(), 0),
@@ -11807,6 +12657,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -11823,6 +12674,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 10 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 2),
@@ -11839,6 +12691,18 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 1,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 3),
@@ -11849,12 +12713,16 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsshotgn.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
 								NextState {
 									time: 6 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 4),
@@ -11871,6 +12739,18 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								LineAttack {
+									count: 1,
+									damage_range: (1..=5).into(),
+									damage_multiplier: 3,
+									distance: 2048.0,
+									spread: Vector2::new(Angle::from_units(1.0 / 16.0), Angle(0)),
+									accurate_until_refire: false,
+									sparks: true,
+									hit_sound: None,
+									miss_sound: None,
+								},
+								MonsterFaceTarget,
 								NextState {
 									time: 4 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 5),
@@ -11881,12 +12761,19 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dsshotgn.sound"),
+							},));
 							world
 						},
 						{
 							let mut world = World::default();
 							world.push((
 								EntityStateEventDef,
+								MonsterFaceTarget,
+								MonsterReFire {
+									chance: 40.0 / 256.0,
+								},
 								NextState {
 									time: 1 * FRAME_TIME,
 									state: (StateName::from("missile").unwrap(), 1),
@@ -12237,6 +13124,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::all(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountKill,
 					EntityTemplateRefDef,
 					HealthDef {
 						max: 100,
@@ -12486,6 +13374,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BossDeath {
+									action: Some(BossDeathAction::OpenDoor(666)),
+								},
 								EntityStateEventDef,
 								NextState {
 									time: 6 * FRAME_TIME,
@@ -12649,6 +13540,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BossDeath {
+									action: Some(BossDeathAction::ExitMap),
+								},
 								EntityStateEventDef,
 								SetEntitySprite(SpriteRender {
 									sprite: asset_storage.load("bbrn.sprite"),
@@ -12677,7 +13571,17 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					BrainSpitter::default(),
 					EntityTemplateRefDef,
+					MonsterDef {
+						speed: 0.0,
+						missile_range: Interval::new(f32::NEG_INFINITY, f32::INFINITY),
+						missile_distance_scale: 1.0,
+						missile_distance_max: f32::INFINITY,
+						attack_sound: None,
+						active_sound: None,
+						see_sound: Some(asset_storage.load("dsbossit.sound")),
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: true,
@@ -12704,6 +13608,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						let mut world = World::default();
 						world.push((
 							EntityStateEventDef,
+							MonsterLook,
 							NextState {
 								time: 10 * FRAME_TIME,
 								state: (StateName::from("spawn").unwrap(), 0),
@@ -12739,6 +13644,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						{
 							let mut world = World::default();
 							world.push((
+								BrainSpit {
+									handle: asset_storage.load("spawnshot.entity"),
+								},
 								EntityStateEventDef,
 								NextState {
 									time: 150 * FRAME_TIME,
@@ -12771,6 +13679,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					BrainTarget,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -12802,7 +13711,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					EntityTemplateRefDef,
 					OwnerDef,
 					PhysicsDef {
-						collision_response: CollisionResponse::Stop,
+						collision_response: CollisionResponse::NoClip,
 						gravity: false,
 						mass: 100.0,
 						speed: 10.0 * FRAME_RATE,
@@ -13291,6 +14200,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					FastProjectileSpeed(20.0 * FRAME_RATE),
 					OwnerDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -13460,6 +14370,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					FastProjectileSpeed(20.0 * FRAME_RATE),
 					OwnerDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -14872,6 +15783,9 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 									full_bright: true,
 								}),
 							));
+							world.push((StartSoundEventDef {
+								handle: asset_storage.load("dstelept.sound"),
+							},));
 							world
 						},
 						{
@@ -15241,6 +16155,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					TeleportDest,
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
@@ -15367,6 +16282,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					ArmorPickup {
+						armor_type: ArmorType::Green,
+						amount: 100,
+						bonus: false,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -15442,6 +16363,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					ArmorPickup {
+						armor_type: ArmorType::Blue,
+						amount: 200,
+						bonus: false,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -15524,6 +16451,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -15656,6 +16584,12 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					ArmorPickup {
+						armor_type: ArmorType::Green,
+						amount: 1,
+						bonus: true,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -15663,6 +16597,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -15803,6 +16738,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					KeyPickup {
+						color: KeyColor::Blue,
+						key_type: KeyType::Card,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: true,
@@ -15878,6 +16818,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					KeyPickup {
+						color: KeyColor::Red,
+						key_type: KeyType::Card,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: true,
@@ -15953,6 +16898,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					KeyPickup {
+						color: KeyColor::Yellow,
+						key_type: KeyType::Card,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: true,
@@ -16028,6 +16978,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					KeyPickup {
+						color: KeyColor::Yellow,
+						key_type: KeyType::Skull,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: true,
@@ -16103,6 +17058,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					KeyPickup {
+						color: KeyColor::Red,
+						key_type: KeyType::Skull,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: true,
@@ -16178,6 +17138,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						damage_particle: DamageParticle::Blood,
 					},
 					EntityTemplateRefDef,
+					KeyPickup {
+						color: KeyColor::Blue,
+						key_type: KeyType::Skull,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
 						gravity: true,
@@ -16358,6 +17323,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -16497,6 +17463,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -16504,6 +17471,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					PowerupPickup {
+						powerup: PowerupType::Invulnerability,
+						sound: asset_storage.load("dsgetpow.sound"),
+					},
 					SpriteRender {
 						sprite: asset_storage.load("pinv.sprite"),
 						frame: 0,
@@ -16604,6 +17575,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -16611,6 +17583,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					PowerupPickup {
+						powerup: PowerupType::Berserk,
+						sound: asset_storage.load("dsgetpow.sound"),
+					},
 					SpriteRender {
 						sprite: asset_storage.load("pstr.sprite"),
 						frame: 0,
@@ -16657,6 +17633,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -16664,6 +17641,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					PowerupPickup {
+						powerup: PowerupType::Invisibility,
+						sound: asset_storage.load("dsgetpow.sound"),
+					},
 					SpriteRender {
 						sprite: asset_storage.load("pins.sprite"),
 						frame: 0,
@@ -16771,6 +17752,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					PowerupPickup {
+						powerup: PowerupType::RadiationSuit,
+						sound: asset_storage.load("dsgetpow.sound"),
+					},
 					SpriteRender {
 						sprite: asset_storage.load("suit.sprite"),
 						frame: 0,
@@ -16817,6 +17802,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -16824,6 +17810,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					PowerupPickup {
+						powerup: PowerupType::ComputerMap,
+						sound: asset_storage.load("dsgetpow.sound"),
+					},
 					SpriteRender {
 						sprite: asset_storage.load("pmap.sprite"),
 						frame: 0,
@@ -16956,6 +17946,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -16963,6 +17954,10 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						mass: 100.0,
 						speed: 0.0,
 					},
+					PowerupPickup {
+						powerup: PowerupType::LightAmp,
+						sound: asset_storage.load("dsgetpow.sound"),
+					},
 					SpriteRender {
 						sprite: asset_storage.load("pvis.sprite"),
 						frame: 0,
@@ -17031,6 +18026,7 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 						blocks_types: SolidBits::empty(),
 						damage_particle: DamageParticle::Blood,
 					},
+					CountItem,
 					EntityTemplateRefDef,
 					PhysicsDef {
 						collision_response: CollisionResponse::Stop,
@@ -17131,6 +18127,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("bullets.ammo"),
+						count: 10,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17184,6 +18185,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("bullets.ammo"),
+						count: 50,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17237,6 +18243,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("rockets.ammo"),
+						count: 1,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17290,6 +18301,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("rockets.ammo"),
+						count: 5,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17343,6 +18359,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("cells.ammo"),
+						count: 20,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17396,6 +18417,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("cells.ammo"),
+						count: 100,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17449,6 +18475,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("shells.ammo"),
+						count: 4,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17502,6 +18533,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 			world: {
 				let mut world = World::default();
 				world.push((
+					AmmoPickup {
+						ammo: asset_storage.load("shells.ammo"),
+						count: 20,
+						sound: asset_storage.load("dsitemup.sound"),
+					},
 					BoxCollider {
 						height: 16.0,
 						radius: 20.0,
@@ -17631,6 +18667,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
+					WeaponPickup {
+						weapon: asset_storage.load("bfg.weapon"),
+						ammo_count: 40,
+						sound: asset_storage.load("dswpnup.sound"),
+					},
 				));
 				world
 			},
@@ -17684,6 +18725,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
+					WeaponPickup {
+						weapon: asset_storage.load("chaingun.weapon"),
+						ammo_count: 20,
+						sound: asset_storage.load("dswpnup.sound"),
+					},
 				));
 				world
 			},
@@ -17737,6 +18783,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
+					WeaponPickup {
+						weapon: asset_storage.load("chainsaw.weapon"),
+						ammo_count: 0,
+						sound: asset_storage.load("dswpnup.sound"),
+					},
 				));
 				world
 			},
@@ -17790,6 +18841,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
+					WeaponPickup {
+						weapon: asset_storage.load("missile.weapon"),
+						ammo_count: 2,
+						sound: asset_storage.load("dswpnup.sound"),
+					},
 				));
 				world
 			},
@@ -17843,6 +18899,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
+					WeaponPickup {
+						weapon: asset_storage.load("plasma.weapon"),
+						ammo_count: 40,
+						sound: asset_storage.load("dswpnup.sound"),
+					},
 				));
 				world
 			},
@@ -17896,6 +18957,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
+					WeaponPickup {
+						weapon: asset_storage.load("shotgun.weapon"),
+						ammo_count: 8,
+						sound: asset_storage.load("dswpnup.sound"),
+					},
 				));
 				world
 			},
@@ -17949,6 +19015,11 @@ pub static MOBJS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemp
 					TransformDef {
 						spawn_on_ceiling: false,
 					},
+					WeaponPickup {
+						weapon: asset_storage.load("supershotgun.weapon"),
+						ammo_count: 8,
+						sound: asset_storage.load("dswpnup.sound"),
+					},
 				));
 				world
 			},
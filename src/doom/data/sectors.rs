@@ -6,6 +6,8 @@ use crate::{
 		game::{
 			map::{
 				anim::{LightFlashDef, LightFlashType, LightGlow},
+				exit::ExitMapDef,
+				sector_special::{SectorDamageDef, SectorSecret},
 				SectorRefDef,
 			},
 			TransformDef,
@@ -112,6 +114,11 @@ pub static SECTORS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTe
 					off_time: 15 * FRAME_TIME,
 					on_time: 5 * FRAME_TIME,
 				},
+				SectorDamageDef {
+					damage: 20,
+					damage_time: 32 * FRAME_TIME,
+					suit_leak_chance: 5.0 / 256.0,
+				},
 				TransformDef {
 					spawn_on_ceiling: false,
 				},
@@ -128,6 +135,11 @@ pub static SECTORS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTe
 			world.push((
 				EntityTemplateRefDef,
 				SectorRefDef,
+				SectorDamageDef {
+					damage: 10,
+					damage_time: 32 * FRAME_TIME,
+					suit_leak_chance: 0.0,
+				},
 				TransformDef {
 					spawn_on_ceiling: false,
 				},
@@ -144,6 +156,11 @@ pub static SECTORS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTe
 			world.push((
 				EntityTemplateRefDef,
 				SectorRefDef,
+				SectorDamageDef {
+					damage: 5,
+					damage_time: 32 * FRAME_TIME,
+					suit_leak_chance: 0.0,
+				},
 				TransformDef {
 					spawn_on_ceiling: false,
 				},
@@ -180,6 +197,7 @@ pub static SECTORS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTe
 			world.push((
 				EntityTemplateRefDef,
 				SectorRefDef,
+				SectorSecret,
 				TransformDef {
 					spawn_on_ceiling: false,
 				},
@@ -212,6 +230,12 @@ pub static SECTORS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTe
 			world.push((
 				EntityTemplateRefDef,
 				SectorRefDef,
+				ExitMapDef { secret: false },
+				SectorDamageDef {
+					damage: 20,
+					damage_time: 32 * FRAME_TIME,
+					suit_leak_chance: 1.0,
+				},
 				TransformDef {
 					spawn_on_ceiling: false,
 				},
@@ -286,6 +310,11 @@ pub static SECTORS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTe
 			world.push((
 				EntityTemplateRefDef,
 				SectorRefDef,
+				SectorDamageDef {
+					damage: 20,
+					damage_time: 32 * FRAME_TIME,
+					suit_leak_chance: 5.0 / 256.0,
+				},
 				TransformDef {
 					spawn_on_ceiling: false,
 				},
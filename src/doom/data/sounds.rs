@@ -25,6 +25,26 @@ pub static SOUNDS: &[SoundData] = &[
 		sounds: &["dsspidth.rawsound"],
 		global: true,
 	},
+	SoundData {
+		sounds: &[
+			"dsposit1.rawsound",
+			"dsposit2.rawsound",
+			"dsposit3.rawsound",
+		],
+		global: false,
+	},
+	SoundData {
+		sounds: &["dsbgsit1.rawsound", "dsbgsit2.rawsound"],
+		global: false,
+	},
+	SoundData {
+		sounds: &["dscybsit.rawsound"],
+		global: true,
+	},
+	SoundData {
+		sounds: &["dsspisit.rawsound"],
+		global: true,
+	},
 	SoundData {
 		sounds: &["dsbosdth.rawsound"],
 		global: true,
@@ -3,7 +3,8 @@ use crate::{
 	doom::{
 		assets::template::EntityTemplate,
 		ui::{
-			hud::{AmmoStat, ArmsStat, HealthStat},
+			hud::{AmmoStat, ArmorStat, ArmsPanel, ArmsStat, FragsStat, HealthStat},
+			intermission::{IntermissionCentered, IntermissionMapName, IntermissionStat},
 			UiAlignment, UiGameView, UiImage, UiText, UiTransform,
 		},
 	},
@@ -109,6 +110,7 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					UiImage {
 						image: asset_storage.load("starms.patch"),
 					},
+					ArmsPanel,
 				));
 
 				// Weapon 2
@@ -123,6 +125,7 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					UiImage {
 						image: asset_storage.load("stysnum0.patch"),
 					},
+					ArmsPanel,
 					ArmsStat {
 						weapons: vec!["pistol".into()],
 						images: [
@@ -144,6 +147,7 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					UiImage {
 						image: asset_storage.load("stysnum0.patch"),
 					},
+					ArmsPanel,
 					ArmsStat {
 						weapons: vec!["shotgun".into(), "supershotgun".into()],
 						images: [
@@ -165,6 +169,7 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					UiImage {
 						image: asset_storage.load("stysnum0.patch"),
 					},
+					ArmsPanel,
 					ArmsStat {
 						weapons: vec!["chaingun".into()],
 						images: [
@@ -186,6 +191,7 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					UiImage {
 						image: asset_storage.load("stysnum0.patch"),
 					},
+					ArmsPanel,
 					ArmsStat {
 						weapons: vec!["missile".into()],
 						images: [
@@ -207,6 +213,7 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					UiImage {
 						image: asset_storage.load("stysnum0.patch"),
 					},
+					ArmsPanel,
 					ArmsStat {
 						weapons: vec!["plasma".into()],
 						images: [
@@ -228,6 +235,7 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					UiImage {
 						image: asset_storage.load("stgnum7.patch"),
 					},
+					ArmsPanel,
 					ArmsStat {
 						weapons: vec!["bfg".into()],
 						images: [
@@ -251,6 +259,22 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 					},
 				));
 
+				// Frags
+				world.push((
+					UiTransform {
+						position: Vector2::new(110.0, 171.0),
+						depth: 10.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Far],
+						size: Vector2::new(28.0, 20.0),
+						stretch: [false; 2],
+					},
+					UiText {
+						text: String::with_capacity(3),
+						font: asset_storage.load("sttnum.font"),
+					},
+					FragsStat,
+				));
+
 				// Armor
 				world.push((
 					UiTransform {
@@ -261,9 +285,10 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 						stretch: [false; 2],
 					},
 					UiText {
-						text: "  0%".into(),
+						text: String::with_capacity(4),
 						font: asset_storage.load("sttnum.font"),
 					},
+					ArmorStat,
 				));
 
 				// Blue key
@@ -464,5 +489,221 @@ pub static UI: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> EntityTemplat
 			..EntityTemplate::default()
 		});
 
+		ui.insert("intermission.entity", |asset_storage| EntityTemplate {
+			name: Some("intermission"),
+			world: {
+				let mut world = World::default();
+
+				// Tiled background
+				world.push((
+					UiTransform {
+						position: Vector2::new(0.0, 0.0),
+						depth: 20.0,
+						alignment: [UiAlignment::Near, UiAlignment::Near],
+						size: Vector2::new(320.0, 200.0),
+						stretch: [true, true],
+					},
+					UiImage {
+						image: asset_storage.load("floor7_2.flat"),
+					},
+				));
+
+				// Main image
+				world.push((
+					UiTransform {
+						position: Vector2::new(0.0, 0.0),
+						depth: 21.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					UiImage {
+						image: asset_storage.load("interpic.patch"),
+					},
+				));
+
+				// Map name
+				world.push((
+					UiTransform {
+						position: Vector2::new(160.0, 2.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					IntermissionCentered,
+					IntermissionMapName,
+				));
+
+				// Finished
+				world.push((
+					UiTransform {
+						position: Vector2::new(160.0, 20.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					UiImage {
+						image: asset_storage.load("wif.patch"),
+					},
+					IntermissionCentered,
+				));
+
+				// Kills
+				world.push((
+					UiTransform {
+						position: Vector2::new(50.0, 50.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					UiImage {
+						image: asset_storage.load("wiostk.patch"),
+					},
+				));
+
+				// Kills percentage
+				world.push((
+					UiTransform {
+						position: Vector2::new(220.0, 50.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::new(64.0, 14.0),
+						stretch: [false; 2],
+					},
+					UiText {
+						text: String::with_capacity(5),
+						font: asset_storage.load("winum.font"),
+					},
+					IntermissionStat::Kills,
+				));
+
+				// Items
+				world.push((
+					UiTransform {
+						position: Vector2::new(50.0, 68.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					UiImage {
+						image: asset_storage.load("wiosti.patch"),
+					},
+				));
+
+				// Items percentage
+				world.push((
+					UiTransform {
+						position: Vector2::new(220.0, 68.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::new(64.0, 14.0),
+						stretch: [false; 2],
+					},
+					UiText {
+						text: String::with_capacity(5),
+						font: asset_storage.load("winum.font"),
+					},
+					IntermissionStat::Items,
+				));
+
+				// Secrets
+				world.push((
+					UiTransform {
+						position: Vector2::new(50.0, 86.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					UiImage {
+						image: asset_storage.load("wiscrt2.patch"),
+					},
+				));
+
+				// Secrets percentage
+				world.push((
+					UiTransform {
+						position: Vector2::new(220.0, 86.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::new(64.0, 14.0),
+						stretch: [false; 2],
+					},
+					UiText {
+						text: String::with_capacity(5),
+						font: asset_storage.load("winum.font"),
+					},
+					IntermissionStat::Secrets,
+				));
+
+				// Time
+				world.push((
+					UiTransform {
+						position: Vector2::new(16.0, 168.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					UiImage {
+						image: asset_storage.load("witime.patch"),
+					},
+				));
+
+				// Time taken
+				world.push((
+					UiTransform {
+						position: Vector2::new(80.0, 168.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::new(64.0, 14.0),
+						stretch: [false; 2],
+					},
+					UiText {
+						text: String::with_capacity(5),
+						font: asset_storage.load("winum.font"),
+					},
+					IntermissionStat::Time,
+				));
+
+				// Par
+				world.push((
+					UiTransform {
+						position: Vector2::new(176.0, 168.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::zeros(),
+						stretch: [false; 2],
+					},
+					UiImage {
+						image: asset_storage.load("wipar.patch"),
+					},
+				));
+
+				// Par time
+				world.push((
+					UiTransform {
+						position: Vector2::new(240.0, 168.0),
+						depth: 22.0,
+						alignment: [UiAlignment::Middle, UiAlignment::Middle],
+						size: Vector2::new(64.0, 14.0),
+						stretch: [false; 2],
+					},
+					UiText {
+						text: String::with_capacity(5),
+						font: asset_storage.load("winum.font"),
+					},
+					IntermissionStat::ParTime,
+				));
+
+				world
+			},
+			..EntityTemplate::default()
+		});
+
 		ui
 	});
@@ -8,9 +8,9 @@ use crate::{
 		game::{
 			combat::{
 				weapon::{
-					ChangeAmmoCount, LineAttack, NextWeaponState, SetWeaponSprite, SetWeaponState,
-					WeaponPosition, WeaponReFire, WeaponReady, WeaponSpriteSlot,
-					WeaponStateEventDef, WeaponStateEventDefSlot,
+					BerserkMultiplier, ChangeAmmoCount, LineAttack, NextWeaponState,
+					SetWeaponSprite, SetWeaponState, WeaponPosition, WeaponReFire, WeaponReady,
+					WeaponSpriteSlot, WeaponStateEventDef, WeaponStateEventDefSlot,
 				},
 				ExtraLight, SpawnProjectile,
 			},
@@ -110,6 +110,7 @@ pub static WEAPONS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> WeaponTe
 				{
 					let mut world = World::default();
 					world.push((
+						BerserkMultiplier(10),
 						EntityStateEventDef,
 						LineAttack {
 							count: 1,
@@ -1028,7 +1029,10 @@ pub static WEAPONS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> WeaponTe
 					let mut world = World::default();
 					world.push((
 						EntityStateEventDef,
-						SpawnProjectile(asset_storage.load("rocket.entity")),
+						SpawnProjectile {
+							handle: asset_storage.load("rocket.entity"),
+							angle: Angle(0),
+						},
 					));
 					world.push((
 						WeaponStateEventDef,
@@ -1246,7 +1250,10 @@ pub static WEAPONS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> WeaponTe
 					));
 					world.push((
 						EntityStateEventDef,
-						SpawnProjectile(asset_storage.load("plasma.entity")),
+						SpawnProjectile {
+							handle: asset_storage.load("plasma.entity"),
+							angle: Angle(0),
+						},
 					));
 					world
 				},
@@ -1453,7 +1460,10 @@ pub static WEAPONS: Lazy<HashMap<&'static str, fn(&mut AssetStorage) -> WeaponTe
 					let mut world = World::default();
 					world.push((
 						EntityStateEventDef,
-						SpawnProjectile(asset_storage.load("bfg.entity")),
+						SpawnProjectile {
+							handle: asset_storage.load("bfg.entity"),
+							angle: Angle(0),
+						},
 					));
 					world.push((
 						WeaponStateEventDef,
@@ -6,7 +6,7 @@ use crate::{
 	doom::{
 		assets::map::meshes::{make_meshes, SkyVertex, Vertex},
 		draw::world::{world_frag, world_vert},
-		game::{camera::Camera, client::Client, map::MapDynamic, Transform},
+		game::{camera::Camera, client::Clients, map::MapDynamic, Transform},
 	},
 };
 use anyhow::{anyhow, Context};
@@ -149,11 +149,16 @@ pub fn draw_map(
 		      world: &World,
 		      resources: &Resources|
 		      -> anyhow::Result<()> {
-			let (asset_storage, client) = <(Read<AssetStorage>, Read<Client>)>::fetch(resources);
+			let (asset_storage, clients) = <(Read<AssetStorage>, Read<Clients>)>::fetch(resources);
 
 			// Camera
-			let (camera, &(mut camera_transform)) =
-				queries.0.get(world, client.entity.unwrap()).unwrap();
+			let (camera, &(mut camera_transform)) = match clients
+				.local_entity()
+				.and_then(|entity| queries.0.get(world, entity).ok())
+			{
+				Some(x) => x,
+				None => return Ok(()),
+			};
 			let mut extra_light = 0.0;
 
 			if let Some(camera) = camera {
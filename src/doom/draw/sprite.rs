@@ -7,7 +7,7 @@ use crate::{
 	doom::{
 		assets::sprite::Sprite,
 		draw::world::{world_frag, world_vert},
-		game::{camera::Camera, client::Client, map::MapDynamic, Transform},
+		game::{camera::Camera, client::Clients, map::MapDynamic, Transform},
 	},
 };
 use anyhow::Context;
@@ -44,6 +44,10 @@ pub struct SpriteRender {
 	pub full_bright: bool,
 }
 
+/// Component for entities whose sprite is drawn with the fuzz effect, making them hard to see.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct SpriteFuzz;
+
 pub fn draw_sprites(
 	resources: &mut Resources,
 ) -> anyhow::Result<
@@ -85,6 +89,34 @@ pub fn draw_sprites(
 		})
 		.context("Couldn't create sprite pipeline")?;
 
+	// Create pipeline for fuzz
+	let fuzz_frag = fuzz_frag::load(device.clone()).context("Couldn't load shader")?;
+
+	let fuzz_pipeline = GraphicsPipeline::start()
+		.render_pass(
+			Subpass::from(draw_target.render_pass().clone(), 0)
+				.context("Subpass index out of range")?,
+		)
+		.vertex_shader(
+			vert.entry_point("main")
+				.context("Couldn't find entry point \"main\"")?,
+			(),
+		)
+		.fragment_shader(
+			fuzz_frag
+				.entry_point("main")
+				.context("Couldn't find entry point \"main\"")?,
+			(),
+		)
+		.vertex_input_state(BuffersDefinition::new().vertex::<Vertex>())
+		.input_assembly_state(InputAssemblyState::new().topology(PrimitiveTopology::TriangleList))
+		.viewport_state(ViewportState::viewport_dynamic_scissor_irrelevant())
+		.depth_stencil_state(DepthStencilState::simple_depth_test())
+		.with_auto_layout(device.clone(), |set_descs| {
+			set_descs[1].set_immutable_samplers(0, [sampler.clone()]);
+		})
+		.context("Couldn't create fuzz pipeline")?;
+
 	let vertex_buffer_pool = CpuBufferPool::new(device.clone(), BufferUsage::vertex_buffer());
 	let mut texture_set_pool =
 		SingleLayoutDescSetPool::new(pipeline.layout().descriptor_set_layouts()[1].clone());
@@ -92,7 +124,7 @@ pub fn draw_sprites(
 	let mut queries = (
 		<(Option<&Camera>, &Transform)>::query(),
 		<&MapDynamic>::query(),
-		<(Entity, &SpriteRender, &Transform)>::query(),
+		<(Entity, &SpriteRender, Option<&SpriteFuzz>, &Transform)>::query(),
 	);
 
 	Ok(
@@ -100,13 +132,18 @@ pub fn draw_sprites(
 		      world: &World,
 		      resources: &Resources|
 		      -> anyhow::Result<()> {
-			let (asset_storage, client) = <(Read<AssetStorage>, Read<Client>)>::fetch(resources);
+			let (asset_storage, clients) = <(Read<AssetStorage>, Read<Clients>)>::fetch(resources);
 			let map_dynamic = queries.1.iter(world).next().unwrap();
 			let map = asset_storage.get(&map_dynamic.map).unwrap();
 
 			// Camera
-			let (camera, &(mut camera_transform)) =
-				queries.0.get(world, client.entity.unwrap()).unwrap();
+			let (camera, &(mut camera_transform)) = match clients
+				.local_entity()
+				.and_then(|entity| queries.0.get(world, entity).ok())
+			{
+				Some(x) => x,
+				None => return Ok(()),
+			};
 			let mut extra_light = 0.0;
 
 			if let Some(camera) = camera {
@@ -124,13 +161,15 @@ pub fn draw_sprites(
 					 0.0      ,  0.0, 0.0      , 1.0,
 				);
 
-			// Group draws into batches by texture
+			// Group draws into batches by texture, with separate batches for fuzz
 			let mut batches: FnvHashMap<Arc<dyn ImageViewAbstract>, Vec<Vertex>> =
 				FnvHashMap::default();
+			let mut fuzz_batches: FnvHashMap<Arc<dyn ImageViewAbstract>, Vec<Vertex>> =
+				FnvHashMap::default();
 
-			for (&entity, sprite_render, transform) in queries.2.iter(world) {
+			for (&entity, sprite_render, sprite_fuzz, transform) in queries.2.iter(world) {
 				// Don't draw the player's own sprite
-				if let Some(view_entity) = client.entity {
+				if let Some(view_entity) = clients.local_entity() {
 					if entity == view_entity {
 						continue;
 					}
@@ -207,6 +246,12 @@ pub fn draw_sprites(
 				let vertices = [0, 1, 2, 0, 2, 3].into_iter().map(|i| vertices[i]);
 
 				// Add to batches
+				let batches = if sprite_fuzz.is_some() {
+					&mut fuzz_batches
+				} else {
+					&mut batches
+				};
+
 				match batches.entry(image_view.clone()) {
 					Entry::Occupied(mut entry) => {
 						entry.get_mut().extend(vertices);
@@ -218,28 +263,30 @@ pub fn draw_sprites(
 			}
 
 			// Draw the batches
-			command_buffer.bind_pipeline_graphics(pipeline.clone());
+			for (pipeline, batches) in [(&pipeline, batches), (&fuzz_pipeline, fuzz_batches)] {
+				command_buffer.bind_pipeline_graphics(pipeline.clone());
 
-			for (image_view, vertices) in batches {
-				let descriptor_set = texture_set_pool
-					.next([WriteDescriptorSet::image_view(0, image_view)])
-					.context("Couldn't create descriptor set")?;
-				command_buffer.bind_descriptor_sets(
-					PipelineBindPoint::Graphics,
-					pipeline.layout().clone(),
-					1,
-					descriptor_set,
-				);
+				for (image_view, vertices) in batches {
+					let descriptor_set = texture_set_pool
+						.next([WriteDescriptorSet::image_view(0, image_view)])
+						.context("Couldn't create descriptor set")?;
+					command_buffer.bind_descriptor_sets(
+						PipelineBindPoint::Graphics,
+						pipeline.layout().clone(),
+						1,
+						descriptor_set,
+					);
 
-				let vertex_buffer = vertex_buffer_pool
-					.chunk(vertices)
-					.context("Couldn't create instance buffer")?;
-				let vertex_count = vertex_buffer.len() as u32;
-				command_buffer.bind_vertex_buffers(0, vertex_buffer);
+					let vertex_buffer = vertex_buffer_pool
+						.chunk(vertices)
+						.context("Couldn't create instance buffer")?;
+					let vertex_count = vertex_buffer.len() as u32;
+					command_buffer.bind_vertex_buffers(0, vertex_buffer);
 
-				command_buffer
-					.draw(vertex_count, 1, 0, 0)
-					.context("Couldn't issue draw to command buffer")?;
+					command_buffer
+						.draw(vertex_count, 1, 0, 0)
+						.context("Couldn't issue draw to command buffer")?;
+				}
 			}
 
 			Ok(())
@@ -277,3 +324,10 @@ unsafe impl VertexTrait for Vertex {
 		}
 	}
 }
+
+pub mod fuzz_frag {
+	vulkano_shaders::shader! {
+		ty: "fragment",
+		path: "shaders/fuzz.frag",
+	}
+}
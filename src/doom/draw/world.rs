@@ -1,11 +1,17 @@
 use crate::{
 	common::{
 		geometry::{perspective_matrix, Interval},
+		time::GameTime,
 		video::RenderContext,
 	},
 	doom::{
 		draw::{map::draw_map, sprite::draw_sprites, NON_SQUARE_CORRECTION},
-		game::{camera::Camera, client::Client, Transform},
+		game::{
+			camera::Camera,
+			client::Clients,
+			item::{PowerupType, Powerups},
+			Transform,
+		},
 	},
 };
 use anyhow::Context;
@@ -22,6 +28,11 @@ use vulkano::{
 	shader::ShaderStages,
 };
 
+// Values of fixed_colormap in the world shaders
+const COLORMAP_NONE: u32 = 0;
+const COLORMAP_FULL_BRIGHT: u32 = 1;
+const COLORMAP_INVERSE: u32 = 2;
+
 pub fn draw_world(
 	resources: &mut Resources,
 ) -> anyhow::Result<
@@ -58,7 +69,7 @@ pub fn draw_world(
 		BufferUsage::uniform_buffer(),
 	);
 
-	let mut query = <(Option<&Camera>, &Transform)>::query();
+	let mut query = <(Option<&Camera>, Option<&Powerups>, &Transform)>::query();
 
 	drop(render_context);
 	let mut draw_map = draw_map(resources)?;
@@ -69,7 +80,7 @@ pub fn draw_world(
 		      world: &World,
 		      resources: &Resources|
 		      -> anyhow::Result<()> {
-			let client = <Read<Client>>::fetch(resources);
+			let (clients, game_time) = <(Read<Clients>, Read<GameTime>)>::fetch(resources);
 			let viewport = command_buffer.state().viewport(0).unwrap();
 
 			// Projection matrix
@@ -86,8 +97,13 @@ pub fn draw_world(
 			let proj = perspective_matrix(fov, Interval::new(4.0, 20000.0));
 
 			// View matrix
-			let (camera, &(mut camera_transform)) =
-				query.get(world, client.entity.unwrap()).unwrap();
+			let (camera, powerups, &(mut camera_transform)) = match clients
+				.local_entity()
+				.and_then(|entity| query.get(world, entity).ok())
+			{
+				Some(x) => x,
+				None => return Ok(()),
+			};
 
 			if let Some(camera) = camera {
 				camera_transform.position += camera.base + camera.offset;
@@ -107,11 +123,23 @@ pub fn draw_world(
 				-camera_transform.rotation[2].to_radians() as f32,
 			)) * Matrix4::new_translation(&-camera_transform.position);
 
+			// Powerups that change the lighting
+			let fixed_colormap = match powerups {
+				Some(p) if p.shows_effect(PowerupType::Invulnerability, *game_time) => {
+					COLORMAP_INVERSE
+				}
+				Some(p) if p.shows_effect(PowerupType::LightAmp, *game_time) => {
+					COLORMAP_FULL_BRIGHT
+				}
+				_ => COLORMAP_NONE,
+			};
+
 			// Create matrix uniform buffer
 			let uniform_buffer = matrix_uniform_pool
 				.next(world_vert::ty::Matrices {
 					proj: proj.into(),
 					view: view.into(),
+					fixed_colormap,
 				})
 				.context("Couldn't create buffer")?;
 			let descriptor_set = matrix_set_pool
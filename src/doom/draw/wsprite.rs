@@ -9,7 +9,7 @@ use crate::{
 			sprite::SpriteRender,
 			ui::{ui_frag, ui_vert, Matrices, Vertex, VERTICES},
 		},
-		game::client::Client,
+		game::client::Clients,
 		ui::{UiAlignment, UiParams},
 	},
 };
@@ -98,8 +98,8 @@ pub fn draw_weapon_sprites(
 		      world: &World,
 		      resources: &Resources|
 		      -> anyhow::Result<()> {
-			let (asset_storage, client, ui_params) =
-				<(Read<AssetStorage>, Read<Client>, Read<UiParams>)>::fetch(resources);
+			let (asset_storage, clients, ui_params) =
+				<(Read<AssetStorage>, Read<Clients>, Read<UiParams>)>::fetch(resources);
 
 			command_buffer.bind_pipeline_graphics(pipeline.clone());
 			let viewport = command_buffer.state().viewport(0).unwrap();
@@ -128,7 +128,7 @@ pub fn draw_weapon_sprites(
 				descriptor_set,
 			);
 
-			let client_entity = match client.entity {
+			let client_entity = match clients.local_entity() {
 				Some(e) => e,
 				None => return Ok(()),
 			};
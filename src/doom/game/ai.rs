@@ -0,0 +1,1596 @@
+use crate::{
+	common::{
+		assets::{AssetHandle, AssetStorage},
+		geometry::{angles_to_axes, Angle, Interval, Line2, Line3, AABB2, AABB3},
+		quadtree::Quadtree,
+		random::GameRng,
+		spawn::{ComponentAccessor, SpawnContext, SpawnFrom, SpawnMergerHandlerSet},
+		time::{GameTime, Timer},
+	},
+	doom::{
+		assets::{
+			sound::Sound,
+			template::{EntityTemplate, EntityTemplateRef},
+		},
+		data::{FRAME_RATE, FRAME_TIME},
+		draw::sprite::SpriteFuzz,
+		game::{
+			client::User,
+			combat::{radius_damage, DamageEvent, Health, Owner, RadiusAttack},
+			map::MapDynamic,
+			options::GameOptions,
+			physics::{BoxCollider, Physics, SolidType, TouchEvent, MAX_STEP},
+			spawn::spawn_entity,
+			state::{
+				entity::{next_state_time, EntityStateEvent, FastState, NextState},
+				State, StateAction, StateName,
+			},
+			trace::EntityTracer,
+			Transform,
+		},
+		sound::StartSoundEvent,
+	},
+};
+use legion::{
+	component,
+	systems::{ResourceSet, Runnable},
+	Entity, EntityStore, IntoQuery, Read, Registry, Resources, SystemBuilder, World, Write,
+};
+use nalgebra::{Vector2, Vector3};
+use rand::{distributions::Uniform, Rng};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Distance within which a monster can hit its target with a melee attack,
+/// measured from the monster's midpoint to the edge of the target.
+const MELEE_RANGE: f32 = 44.0;
+
+/// Number of chase steps a monster waits before it first considers firing a missile.
+const REACTION_TIME: u32 = 8;
+
+const FLOAT_SPEED: f32 = 4.0 * FRAME_RATE;
+
+/// Time that a monster must lie dead before it can respawn.
+pub const RESPAWN_TIME: Duration = Duration::from_secs(12);
+
+/// Number of chase steps that a monster keeps its target after retaliating,
+/// before it can be provoked into switching to another.
+pub const BASE_THRESHOLD: u32 = 100;
+
+/// Component for entities that hunt down players.
+///
+/// The movement direction is one of eight directions, counting counterclockwise in steps of
+/// 45 degrees starting from the positive X axis. `None` means the monster is not moving.
+/// While `threshold` is above zero, the monster will not switch targets when hurt.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Monster {
+	pub target: Option<Entity>,
+	pub threshold: u32,
+	pub move_dir: Option<u8>,
+	pub move_count: i32,
+	pub reaction_time: u32,
+	pub just_attacked: bool,
+	pub charging: bool,
+
+	pub speed: f32,
+	pub missile_range: Interval,
+	pub missile_distance_scale: f32,
+	pub missile_distance_max: f32,
+	pub attack_sound: Option<AssetHandle<Sound>>,
+	pub active_sound: Option<AssetHandle<Sound>>,
+	pub see_sound: Option<AssetHandle<Sound>>,
+}
+
+/// Spawns a Monster component without a target.
+///
+/// `speed` is the distance moved each time a chase action runs.
+/// The chance of firing a missile decreases with distance: the distance to the target,
+/// scaled by `missile_distance_scale` and capped at `missile_distance_max`,
+/// is compared against a random number between 0 and 256.
+#[derive(Clone, Debug)]
+pub struct MonsterDef {
+	pub speed: f32,
+	pub missile_range: Interval,
+	pub missile_distance_scale: f32,
+	pub missile_distance_max: f32,
+	pub attack_sound: Option<AssetHandle<Sound>>,
+	pub active_sound: Option<AssetHandle<Sound>>,
+	pub see_sound: Option<AssetHandle<Sound>>,
+}
+
+impl SpawnFrom<MonsterDef> for Monster {
+	fn spawn(component: &MonsterDef, _accessor: ComponentAccessor, _resources: &Resources) -> Self {
+		Monster {
+			target: None,
+			threshold: 0,
+			move_dir: None,
+			move_count: 0,
+			reaction_time: REACTION_TIME,
+			just_attacked: false,
+			charging: false,
+
+			speed: component.speed,
+			missile_range: component.missile_range,
+			missile_distance_scale: component.missile_distance_scale,
+			missile_distance_max: component.missile_distance_max,
+			attack_sound: component.attack_sound.clone(),
+			active_sound: component.active_sound.clone(),
+			see_sound: component.see_sound.clone(),
+		}
+	}
+}
+
+#[inline]
+fn move_dir_angle(move_dir: u8) -> Angle {
+	Angle::from_units(move_dir as f64 / 8.0)
+}
+
+#[inline]
+fn move_dir_vector(move_dir: u8) -> Vector3<f32> {
+	let angle = move_dir_angle(move_dir);
+	Vector3::new(angle.cos() as f32, angle.sin() as f32, 0.0)
+}
+
+#[inline]
+fn angle_to(from: Vector3<f32>, to: Vector3<f32>) -> Angle {
+	let delta = to - from;
+	Angle::from_radians(f64::atan2(delta[1] as f64, delta[0] as f64))
+}
+
+#[inline]
+fn eye_position(transform: &Transform, box_collider: &BoxCollider) -> Vector3<f32> {
+	let mut position = transform.position;
+	position[2] += box_collider.height * 0.75;
+	position
+}
+
+fn is_alive<W: EntityStore>(world: &W, entity: Entity) -> bool {
+	<&Health>::query()
+		.get(world, entity)
+		.map_or(false, |health| health.current > 0)
+}
+
+fn can_see<W: EntityStore>(
+	tracer: &EntityTracer<W>,
+	transform: &Transform,
+	box_collider: &BoxCollider,
+	target: Entity,
+) -> bool {
+	match <(&BoxCollider, &Transform)>::query().get(tracer.world, target) {
+		Ok((target_box_collider, target_transform)) => tracer.is_visible(
+			eye_position(transform, box_collider),
+			target_transform.position,
+			target_box_collider.height,
+		),
+		Err(_) => false,
+	}
+}
+
+fn in_melee_range<W: EntityStore>(
+	tracer: &EntityTracer<W>,
+	transform: &Transform,
+	box_collider: &BoxCollider,
+	target: Entity,
+) -> bool {
+	let (target_box_collider, target_transform) =
+		match <(&BoxCollider, &Transform)>::query().get(tracer.world, target) {
+			Ok(x) => x,
+			Err(_) => return false,
+		};
+
+	let distance = (target_transform.position - transform.position)
+		.fixed_rows::<2>(0)
+		.norm();
+
+	distance < MELEE_RANGE + target_box_collider.radius
+		&& can_see(tracer, transform, box_collider, target)
+}
+
+fn look_for_players<W: EntityStore>(
+	tracer: &EntityTracer<W>,
+	transform: &Transform,
+	box_collider: &BoxCollider,
+	all_around: bool,
+) -> Option<Entity> {
+	for (&entity, health, player_transform) in <(Entity, &Health, &Transform)>::query()
+		.filter(component::<User>())
+		.iter(tracer.world)
+	{
+		if health.current <= 0 {
+			continue;
+		}
+
+		if !can_see(tracer, transform, box_collider, entity) {
+			continue;
+		}
+
+		if !all_around {
+			let angle =
+				angle_to(transform.position, player_transform.position) - transform.rotation[2];
+
+			if angle.to_degrees().abs() > 90.0 {
+				let distance = (player_transform.position - transform.position)
+					.fixed_rows::<2>(0)
+					.norm();
+
+				// Behind the monster's back, but close enough to notice
+				if distance > MELEE_RANGE + 20.0 {
+					continue;
+				}
+			}
+		}
+
+		return Some(entity);
+	}
+
+	None
+}
+
+fn check_missile_range<W: EntityStore>(
+	tracer: &EntityTracer<W>,
+	transform: &Transform,
+	box_collider: &BoxCollider,
+	monster: &Monster,
+	has_melee: bool,
+	target: Entity,
+	game_rng: &mut GameRng,
+) -> bool {
+	if !can_see(tracer, transform, box_collider, target) {
+		return false;
+	}
+
+	if monster.reaction_time > 0 {
+		return false;
+	}
+
+	let target_transform = match <&Transform>::query().get(tracer.world, target) {
+		Ok(x) => x,
+		Err(_) => return false,
+	};
+
+	let mut distance = (target_transform.position - transform.position)
+		.fixed_rows::<2>(0)
+		.norm()
+		- 64.0;
+
+	// No melee attack, so fire more
+	if !has_melee {
+		distance -= 128.0;
+	}
+
+	if !monster.missile_range.contains(distance) {
+		return false;
+	}
+
+	distance = f32::min(
+		distance * monster.missile_distance_scale,
+		monster.missile_distance_max,
+	);
+
+	game_rng.gen_range(0.0..256.0) >= distance
+}
+
+fn try_move<W: EntityStore>(
+	tracer: &EntityTracer<W>,
+	entity: Entity,
+	transform: &Transform,
+	box_collider: &BoxCollider,
+	physics: &Physics,
+	move_dir: u8,
+	distance: f32,
+) -> bool {
+	let bbox = AABB3::from_radius_height(box_collider.radius, box_collider.height);
+	let move_step = move_dir_vector(move_dir) * distance;
+	let trace = tracer.trace(
+		&bbox,
+		box_collider.solid_type,
+		Some(entity),
+		Line3::new(transform.position, move_step),
+	);
+
+	let end_point = match trace.collision {
+		None => trace.move_step.end_point(),
+		Some(collision) => {
+			// See if the monster can step up onto the obstacle
+			let height = match collision.step_z {
+				Some(step_z) => step_z - transform.position[2],
+				None => return false,
+			};
+
+			if height <= 0.0 || height >= MAX_STEP {
+				return false;
+			}
+
+			let mut position = transform.position;
+			position[2] += height;
+			let trace = tracer.trace(
+				&bbox,
+				box_collider.solid_type,
+				Some(entity),
+				Line3::new(position, move_step),
+			);
+
+			if trace.collision.is_some() {
+				return false;
+			}
+
+			trace.move_step.end_point()
+		}
+	};
+
+	if physics.gravity {
+		// Don't walk off ledges that are too high to step back up
+		let trace = tracer.trace(
+			&bbox,
+			box_collider.solid_type,
+			Some(entity),
+			Line3::new(end_point, Vector3::new(0.0, 0.0, -MAX_STEP)),
+		);
+
+		if trace.collision.is_none() {
+			return false;
+		}
+	}
+
+	true
+}
+
+fn new_chase_dir<W: EntityStore>(
+	tracer: &EntityTracer<W>,
+	entity: Entity,
+	transform: &Transform,
+	box_collider: &BoxCollider,
+	physics: &Physics,
+	monster: &mut Monster,
+	target_position: Vector3<f32>,
+	game_rng: &mut GameRng,
+) {
+	let old_dir = monster.move_dir;
+	let turnaround = old_dir.map(|dir| (dir + 4) % 8);
+
+	let try_walk = |move_dir: u8, monster: &mut Monster, game_rng: &mut GameRng| -> bool {
+		if try_move(
+			tracer,
+			entity,
+			transform,
+			box_collider,
+			physics,
+			move_dir,
+			monster.speed,
+		) {
+			monster.move_dir = Some(move_dir);
+			monster.move_count = game_rng.gen_range(0..16);
+			true
+		} else {
+			false
+		}
+	};
+
+	let delta = target_position - transform.position;
+	let mut dirs = [
+		if delta[0] > 10.0 {
+			Some(0)
+		} else if delta[0] < -10.0 {
+			Some(4)
+		} else {
+			None
+		},
+		if delta[1] < -10.0 {
+			Some(6)
+		} else if delta[1] > 10.0 {
+			Some(2)
+		} else {
+			None
+		},
+	];
+
+	// Try the direct route
+	if let [Some(dir_x), Some(dir_y)] = dirs {
+		let diagonal = if dir_y == 2 {
+			if dir_x == 0 {
+				1
+			} else {
+				3
+			}
+		} else if dir_x == 0 {
+			7
+		} else {
+			5
+		};
+
+		if Some(diagonal) != turnaround && try_walk(diagonal, monster, game_rng) {
+			return;
+		}
+	}
+
+	// Try other directions
+	if game_rng.gen_bool(55.0 / 256.0) || delta[1].abs() > delta[0].abs() {
+		dirs.swap(0, 1);
+	}
+
+	for dir in dirs.iter().flatten().copied() {
+		if Some(dir) != turnaround && try_walk(dir, monster, game_rng) {
+			return;
+		}
+	}
+
+	// There is no direct path to the target, so pick another direction
+	if let Some(dir) = old_dir {
+		if try_walk(dir, monster, game_rng) {
+			return;
+		}
+	}
+
+	// Randomly determine the direction of the search
+	let search: [u8; 8] = if game_rng.gen_bool(0.5) {
+		[0, 1, 2, 3, 4, 5, 6, 7]
+	} else {
+		[7, 6, 5, 4, 3, 2, 1, 0]
+	};
+
+	for dir in search {
+		if Some(dir) != turnaround && try_walk(dir, monster, game_rng) {
+			return;
+		}
+	}
+
+	if let Some(dir) = turnaround {
+		if try_walk(dir, monster, game_rng) {
+			return;
+		}
+	}
+
+	// Can not move
+	monster.move_dir = None;
+}
+
+/// Component for monsters that only wake up to a noise if they can also see who made it.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct MonsterAmbush;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MonsterLook;
+
+pub fn monster_look(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<Monster>("Monster".into());
+	handler_set.register_spawn::<MonsterDef, Monster>();
+
+	registry.register::<MonsterAmbush>("MonsterAmbush".into());
+	handler_set.register_clone::<MonsterLook>();
+
+	SystemBuilder::new("monster_look")
+		.read_resource::<AssetStorage>()
+		.read_resource::<Quadtree>()
+		.with_query(<(&EntityStateEvent, &MonsterLook)>::query())
+		.with_query(<&MapDynamic>::query())
+		.with_query(<(
+			&EntityTemplateRef,
+			&BoxCollider,
+			&Transform,
+			Option<&MonsterAmbush>,
+		)>::query())
+		.with_query(<(&mut Monster, &mut State)>::query())
+		.read_component::<BoxCollider>() // used by EntityTracer
+		.read_component::<Health>()
+		.read_component::<Owner>() // used by EntityTracer
+		.read_component::<Transform>() // used by EntityTracer
+		.read_component::<User>()
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, quadtree) = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+			let (world1, mut world) = world.split_for_query(&queries.1);
+
+			let map_dynamic = queries.1.iter(&world1).next().unwrap();
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			for (&event, MonsterLook) in queries.0.iter(&world0) {
+				let (template_ref, &box_collider, &transform, ambush) =
+					match queries.2.get(&world, event.entity) {
+						Ok(x) => x,
+						Err(_) => continue,
+					};
+				let template = asset_storage.get(&template_ref.0).unwrap();
+				let ambush = ambush.is_some();
+
+				let target = {
+					let tracer = EntityTracer {
+						map,
+						map_dynamic,
+						quadtree: &quadtree,
+						world: &world,
+					};
+
+					// Wake up to noises heard in the monster's sector
+					let ssect = map.find_subsector(transform.position.fixed_resize(0.0));
+					let sound_target = map_dynamic.sectors[ssect.sector_index].sound_target;
+					let heard = sound_target.filter(|&target| {
+						let alive = <&Health>::query()
+							.get(&world, target)
+							.map_or(false, |health| health.current > 0);
+						alive && (!ambush || can_see(&tracer, &transform, &box_collider, target))
+					});
+
+					match heard
+						.or_else(|| look_for_players(&tracer, &transform, &box_collider, false))
+					{
+						Some(x) => x,
+						None => continue,
+					}
+				};
+
+				if let Ok((monster, state)) = queries.3.get_mut(&mut world, event.entity) {
+					monster.target = Some(target);
+
+					if let Some(sound) = &monster.see_sound {
+						command_buffer.push((StartSoundEvent {
+							handle: sound.clone(),
+							entity: Some(event.entity),
+						},));
+					}
+
+					if template.states.contains_key("see") {
+						let new = (StateName::from("see").unwrap(), 0);
+						state.action = StateAction::Set(new);
+					}
+				}
+			}
+		})
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MonsterChase;
+
+/// Component for chase states in which the monster looks for corpses to bring back to life,
+/// instead of chasing when it finds one. `sound` is played at the corpse when it is raised.
+#[derive(Clone, Debug)]
+pub struct MonsterRaiseCorpses {
+	pub sound: AssetHandle<Sound>,
+}
+
+pub fn monster_chase(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<MonsterChase>();
+	handler_set.register_clone::<MonsterRaiseCorpses>();
+
+	SystemBuilder::new("monster_chase")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameOptions>()
+		.read_resource::<Quadtree>()
+		.write_resource::<GameRng>()
+		.with_query(<(
+			&EntityStateEvent,
+			&MonsterChase,
+			Option<&NextState>,
+			Option<&FastState>,
+			Option<&MonsterRaiseCorpses>,
+		)>::query())
+		.with_query(<&MapDynamic>::query())
+		.with_query(<(
+			&EntityTemplateRef,
+			&BoxCollider,
+			&Monster,
+			&Physics,
+			&Transform,
+		)>::query())
+		.with_query(<(&mut Monster, &mut Physics, &mut State, &mut Transform)>::query())
+		.read_component::<BoxCollider>() // used by EntityTracer
+		.read_component::<Health>()
+		.read_component::<Owner>() // used by EntityTracer
+		.read_component::<Transform>() // used by EntityTracer
+		.read_component::<User>()
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_options, quadtree, game_rng) = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+			let (world1, mut world) = world.split_for_query(&queries.1);
+
+			let map_dynamic = queries.1.iter(&world1).next().unwrap();
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			for (&event, MonsterChase, next_state, fast_state, raise_corpses) in
+				queries.0.iter(&world0)
+			{
+				let (template_ref, &box_collider, monster, &physics, &transform) =
+					match queries.2.get(&world, event.entity) {
+						Ok(x) => x,
+						Err(_) => continue,
+					};
+
+				// Raise a nearby corpse instead of chasing, if there is one
+				if let Some(raise_corpses) = raise_corpses {
+					let tracer = EntityTracer {
+						map,
+						map_dynamic,
+						quadtree: &quadtree,
+						world: &world,
+					};
+
+					if let Some((corpse, corpse_position)) =
+						find_raisable_corpse(&tracer, asset_storage, &transform, monster)
+					{
+						command_buffer.push((StartSoundEvent {
+							handle: raise_corpses.sound.clone(),
+							entity: Some(corpse),
+						},));
+						command_buffer.exec_mut(move |world, resources| {
+							raise_corpse(corpse, world, resources)
+						});
+
+						if let Ok((_, _, state, transform)) =
+							queries.3.get_mut(&mut world, event.entity)
+						{
+							transform.rotation[2] = angle_to(transform.position, corpse_position);
+							let new = (StateName::from("heal").unwrap(), 0);
+							state.action = StateAction::Set(new);
+						}
+
+						continue;
+					}
+				}
+
+				let template = asset_storage.get(&template_ref.0).unwrap();
+				let mut monster = monster.clone();
+				let mut physics = physics;
+				let mut transform = transform;
+				let mut new_state = None;
+
+				// Move at a speed that covers the step distance before the next chase action
+				let step_time = next_state.map_or(1.0 / FRAME_RATE, |n| {
+					next_state_time(n, fast_state, game_options).as_secs_f32()
+				});
+
+				if monster.reaction_time > 0 {
+					monster.reaction_time -= 1;
+				}
+
+				// Count down the time to keep the current target, unless it has died
+				if monster.threshold > 0 {
+					if monster
+						.target
+						.map_or(false, |target| is_alive(&world, target))
+					{
+						monster.threshold -= 1;
+					} else {
+						monster.threshold = 0;
+					}
+				}
+
+				// Turn towards the movement direction if not there yet
+				if let Some(move_dir) = monster.move_dir {
+					const ANG45: i32 = 0x2000_0000;
+					transform.rotation[1] = Angle(0);
+					transform.rotation[2] = Angle(transform.rotation[2].0 & !(ANG45 - 1));
+					let delta = transform.rotation[2] - move_dir_angle(move_dir);
+
+					if delta.0 > 0 {
+						transform.rotation[2] -= ANG45;
+					} else if delta.0 < 0 {
+						transform.rotation[2] += ANG45;
+					}
+				}
+
+				// Flying monsters only move while chasing
+				if !physics.gravity {
+					physics.velocity = Vector3::zeros();
+				}
+
+				{
+					let tracer = EntityTracer {
+						map,
+						map_dynamic,
+						quadtree: &quadtree,
+						world: &world,
+					};
+
+					let target = match monster.target {
+						Some(target) if is_alive(&world, target) => Some(target),
+						_ => {
+							// Look for a new target
+							monster.target =
+								look_for_players(&tracer, &transform, &box_collider, true);
+
+							if monster.target.is_none() {
+								new_state = Some("spawn");
+							}
+
+							None
+						}
+					};
+
+					if let Some(target) = target {
+						let target_position = <&Transform>::query()
+							.get(&world, target)
+							.map_or(transform.position, |t| t.position);
+
+						if monster.just_attacked {
+							// Do not attack twice in a row
+							monster.just_attacked = false;
+
+							// Fast monsters don't spend a step turning away after an attack
+							if !game_options.fast_monsters {
+								new_chase_dir(
+									&tracer,
+									event.entity,
+									&transform,
+									&box_collider,
+									&physics,
+									&mut monster,
+									target_position,
+									game_rng,
+								);
+							}
+						} else if template.states.contains_key("melee")
+							&& in_melee_range(&tracer, &transform, &box_collider, target)
+						{
+							if let Some(sound) = &monster.attack_sound {
+								command_buffer.push((StartSoundEvent {
+									handle: sound.clone(),
+									entity: Some(event.entity),
+								},));
+							}
+
+							monster.move_dir = None;
+							new_state = Some("melee");
+						} else if template.states.contains_key("missile")
+							&& (monster.move_count <= 0 || game_options.fast_monsters)
+							&& check_missile_range(
+								&tracer,
+								&transform,
+								&box_collider,
+								&monster,
+								template.states.contains_key("melee"),
+								target,
+								game_rng,
+							) {
+							monster.just_attacked = true;
+							monster.move_dir = None;
+							new_state = Some("missile");
+						} else {
+							// Chase towards the target
+							monster.move_count -= 1;
+
+							if monster.move_count < 0
+								|| monster.move_dir.map_or(true, |move_dir| {
+									!try_move(
+										&tracer,
+										event.entity,
+										&transform,
+										&box_collider,
+										&physics,
+										move_dir,
+										monster.speed,
+									)
+								}) {
+								new_chase_dir(
+									&tracer,
+									event.entity,
+									&transform,
+									&box_collider,
+									&physics,
+									&mut monster,
+									target_position,
+									game_rng,
+								);
+							}
+
+							// Make active sound
+							if let Some(sound) = &monster.active_sound {
+								if game_rng.gen_bool(3.0 / 256.0) {
+									command_buffer.push((StartSoundEvent {
+										handle: sound.clone(),
+										entity: Some(event.entity),
+									},));
+								}
+							}
+						}
+
+						if new_state.is_none() {
+							if let Some(move_dir) = monster.move_dir {
+								let velocity =
+									move_dir_vector(move_dir) * monster.speed / step_time;
+								physics.velocity[0] = velocity[0];
+								physics.velocity[1] = velocity[1];
+							}
+
+							// Float up or down towards the target
+							if !physics.gravity {
+								let distance = (target_position - transform.position)
+									.fixed_rows::<2>(0)
+									.norm();
+								let delta = target_position[2] - transform.position[2];
+
+								if delta < 0.0 && distance < -delta * 3.0 {
+									physics.velocity[2] = -FLOAT_SPEED;
+								} else if delta > 0.0 && distance < delta * 3.0 {
+									physics.velocity[2] = FLOAT_SPEED;
+								}
+							}
+						}
+					}
+				}
+
+				if let Ok((monster_mut, physics_mut, state, transform_mut)) =
+					queries.3.get_mut(&mut world, event.entity)
+				{
+					*monster_mut = monster;
+					*physics_mut = physics;
+					*transform_mut = transform;
+
+					if let Some(name) = new_state {
+						let new = (StateName::from(name).unwrap(), 0);
+						state.action = StateAction::Set(new);
+					}
+				}
+			}
+		})
+}
+
+/// Distance around a monster's next position within which it can raise corpses,
+/// in addition to the radius of the corpse.
+const RAISE_RADIUS: f32 = 20.0;
+
+/// Looks for a corpse near where the monster is about to move to, that has a "raise" state
+/// and has room to stand up again.
+fn find_raisable_corpse<W: EntityStore>(
+	tracer: &EntityTracer<W>,
+	asset_storage: &AssetStorage,
+	transform: &Transform,
+	monster: &Monster,
+) -> Option<(Entity, Vector3<f32>)> {
+	let mut position = transform.position;
+
+	if let Some(move_dir) = monster.move_dir {
+		position += move_dir_vector(move_dir) * monster.speed;
+	}
+
+	let mut found = None;
+	let mut query = <(
+		&BoxCollider,
+		&EntityTemplateRef,
+		&Health,
+		&State,
+		&Transform,
+	)>::query();
+
+	tracer.quadtree.traverse_nodes(
+		AABB2::from_radius(RAISE_RADIUS),
+		Line2::new(position.fixed_resize(0.0), Vector2::zeros()),
+		&mut |entities: &[Entity]| {
+			for &entity in entities {
+				if found.is_some() {
+					break;
+				}
+
+				let (box_collider, template_ref, health, state, corpse_transform) =
+					match query.get(tracer.world, entity) {
+						Ok(x) => x,
+						Err(_) => continue,
+					};
+
+				// Only corpses that have finished dying can be raised
+				if health.current > 0 || !matches!(state.action, StateAction::None) {
+					continue;
+				}
+
+				let template = asset_storage.get(&template_ref.0).unwrap();
+
+				if !template.states.contains_key("raise") {
+					continue;
+				}
+
+				let delta = corpse_transform.position - position;
+
+				if delta[0].abs() > box_collider.radius + RAISE_RADIUS
+					|| delta[1].abs() > box_collider.radius + RAISE_RADIUS
+				{
+					continue;
+				}
+
+				// Check that the monster would fit once it is solid again
+				let alive_box_collider = match <&BoxCollider>::query().iter(&template.world).next()
+				{
+					Some(x) => x,
+					None => continue,
+				};
+				let trace = tracer.trace(
+					&AABB3::from_radius_height(
+						alive_box_collider.radius,
+						alive_box_collider.height,
+					),
+					alive_box_collider.solid_type,
+					Some(entity),
+					Line3::new(corpse_transform.position, Vector3::zeros()),
+				);
+
+				if trace.collision.is_none() {
+					found = Some((entity, corpse_transform.position));
+				}
+			}
+
+			Vector2::zeros()
+		},
+	);
+
+	found
+}
+
+/// Brings a corpse back to life, restoring its health and solidity from its template,
+/// and starts its "raise" state.
+fn raise_corpse(entity: Entity, world: &mut World, resources: &mut Resources) {
+	let asset_storage = <Read<AssetStorage>>::fetch(resources);
+	let mut entry = match world.entry(entity) {
+		Some(x) => x,
+		None => return,
+	};
+
+	let template = match entry.get_component::<EntityTemplateRef>() {
+		Ok(template_ref) => asset_storage.get(&template_ref.0).unwrap(),
+		Err(_) => return,
+	};
+
+	if let (Ok(box_collider), Some(alive_box_collider)) = (
+		entry.get_component_mut::<BoxCollider>(),
+		<&BoxCollider>::query().iter(&template.world).next(),
+	) {
+		box_collider.solid_type = alive_box_collider.solid_type;
+		box_collider.blocks_types = alive_box_collider.blocks_types;
+	}
+
+	if let Ok(health) = entry.get_component_mut::<Health>() {
+		health.current = health.max;
+	}
+
+	if let Ok(monster) = entry.get_component_mut::<Monster>() {
+		monster.target = None;
+		monster.threshold = 0;
+	}
+
+	if let Ok(state) = entry.get_component_mut::<State>() {
+		let new = (StateName::from("raise").unwrap(), 0);
+		state.action = StateAction::Set(new);
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MonsterFaceTarget;
+
+pub fn monster_face_target(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<MonsterFaceTarget>();
+
+	SystemBuilder::new("monster_face_target")
+		.write_resource::<GameRng>()
+		.with_query(<(&EntityStateEvent, &MonsterFaceTarget)>::query())
+		.with_query(<(&BoxCollider, &Monster, &Transform)>::query())
+		.with_query(<(&BoxCollider, Option<&SpriteFuzz>, &Transform)>::query())
+		.with_query(<&mut Transform>::query())
+		.build(move |_command_buffer, world, game_rng, queries| {
+			let (world0, mut world) = world.split_for_query(&queries.0);
+
+			for (&event, MonsterFaceTarget) in queries.0.iter(&world0) {
+				let rotation = match queries.1.get(&world, event.entity) {
+					Ok((box_collider, monster, transform)) => {
+						let (target_box_collider, target_fuzz, target_transform) = match monster
+							.target
+							.and_then(|target| queries.2.get(&world, target).ok())
+						{
+							Some(x) => x,
+							None => continue,
+						};
+
+						let mut position = transform.position;
+						position[2] += box_collider.height * 0.5;
+						let mut target_position = target_transform.position;
+						target_position[2] += target_box_collider.height * 0.5;
+
+						let delta = target_position - position;
+						let distance = delta.fixed_rows::<2>(0).norm();
+
+						let mut angle = angle_to(position, target_position);
+
+						// Fuzzy targets are harder to aim at
+						if target_fuzz.is_some() {
+							angle +=
+								(game_rng.gen::<u8>() as i32 - game_rng.gen::<u8>() as i32) << 21;
+						}
+
+						Vector3::new(
+							transform.rotation[0],
+							Angle::from_radians(f64::atan2(-delta[2] as f64, distance as f64)),
+							angle,
+						)
+					}
+					Err(_) => continue,
+				};
+
+				if let Ok(transform) = queries.3.get_mut(&mut world, event.entity) {
+					transform.rotation = rotation;
+				}
+			}
+		})
+}
+
+/// Component for states that spawn an entity on the monster's target, like the arch-vile's fire.
+/// The spawned entity is owned by the monster and follows the target.
+#[derive(Clone, Debug)]
+pub struct SpawnOnTarget {
+	pub handle: AssetHandle<EntityTemplate>,
+}
+
+pub fn spawn_on_target(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<SpawnOnTarget>();
+
+	SystemBuilder::new("spawn_on_target")
+		.with_query(<(&EntityStateEvent, &SpawnOnTarget)>::query())
+		.with_query(<&Monster>::query())
+		.with_query(<&Transform>::query())
+		.build(move |command_buffer, world, _resources, queries| {
+			for (&event, spawn_on_target) in queries.0.iter(world) {
+				let target = match queries.1.get(world, event.entity) {
+					Ok(Monster {
+						target: Some(target),
+						..
+					}) => *target,
+					_ => continue,
+				};
+
+				let &transform = match queries.2.get(world, target) {
+					Ok(x) => x,
+					Err(_) => continue,
+				};
+
+				let handle = spawn_on_target.handle.clone();
+				command_buffer.exec_mut(move |world, resources| {
+					let entity = spawn_entity(world, resources, &handle, transform);
+					let mut entry = world.entry(entity).unwrap();
+					entry.add_component(Owner(event.entity));
+					entry.add_component(FollowTarget(target));
+				});
+			}
+		})
+}
+
+/// Component for entities that stay in front of the given target,
+/// as long as their owner can see it.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct FollowTarget(pub Entity);
+
+/// Distance in front of the target that a following entity is placed at.
+const FOLLOW_DISTANCE: f32 = 24.0;
+
+pub fn follow_target(resources: &mut Resources) -> impl Runnable {
+	let mut registry = <Write<Registry<String>>>::fetch_mut(resources);
+	registry.register::<FollowTarget>("FollowTarget".into());
+
+	SystemBuilder::new("follow_target")
+		.read_resource::<AssetStorage>()
+		.write_resource::<Quadtree>()
+		.with_query(<&MapDynamic>::query())
+		.with_query(<(Entity, &BoxCollider, &FollowTarget, &Owner)>::query())
+		.with_query(<(&BoxCollider, &Transform)>::query())
+		.with_query(<&mut Transform>::query())
+		.read_component::<Owner>() // used by EntityTracer
+		.build(move |_command_buffer, world, resources, queries| {
+			let (asset_storage, quadtree) = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+			let map_dynamic = queries.0.iter(&world0).next().unwrap();
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			let mut moves = Vec::new();
+
+			{
+				let tracer = EntityTracer {
+					map,
+					map_dynamic,
+					quadtree: &quadtree,
+					world: &world,
+				};
+
+				for (&entity, &box_collider, &FollowTarget(target), &Owner(owner)) in
+					queries.1.iter(&world)
+				{
+					let (&owner_box_collider, &owner_transform) = match queries.2.get(&world, owner)
+					{
+						Ok(x) => x,
+						Err(_) => continue,
+					};
+
+					if !can_see(&tracer, &owner_transform, &owner_box_collider, target) {
+						continue;
+					}
+
+					if let Ok((_, target_transform)) = queries.2.get(&world, target) {
+						let angle = target_transform.rotation[2];
+						let direction = Vector3::new(angle.cos() as f32, angle.sin() as f32, 0.0);
+						let position = target_transform.position + direction * FOLLOW_DISTANCE;
+						moves.push((entity, box_collider, position));
+					}
+				}
+			}
+
+			for (entity, box_collider, position) in moves {
+				if let Ok(transform) = queries.3.get_mut(&mut world, entity) {
+					transform.position = position;
+
+					let bbox = AABB3::from_radius_height(box_collider.radius, box_collider.height);
+					quadtree.remove(entity);
+					quadtree.insert(entity, &AABB2::from(bbox.offset(position)));
+				}
+			}
+		})
+}
+
+/// Component for states in which a monster blasts its target if it can see it,
+/// like the arch-vile. The target is damaged and thrown upwards, and the monster's
+/// following fire explodes in front of the target.
+#[derive(Clone, Debug)]
+pub struct MonsterBlastTarget {
+	pub damage: i32,
+	pub explosion: RadiusAttack,
+	pub sound: Option<AssetHandle<Sound>>,
+
+	/// Upwards impulse given to the target, which is divided by its mass.
+	pub thrust: f32,
+}
+
+pub fn monster_blast_target(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<MonsterBlastTarget>();
+
+	SystemBuilder::new("monster_blast_target")
+		.read_resource::<AssetStorage>()
+		.read_resource::<Quadtree>()
+		.with_query(<(&EntityStateEvent, &MonsterBlastTarget)>::query())
+		.with_query(<&MapDynamic>::query())
+		.with_query(<(&BoxCollider, &Monster, &Transform)>::query())
+		.with_query(<(&BoxCollider, &Owner)>::query().filter(component::<FollowTarget>()))
+		.with_query(<(&mut Physics, &Transform)>::query())
+		.read_component::<BoxCollider>() // used by EntityTracer
+		.read_component::<Transform>() // used by EntityTracer
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, quadtree) = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+			let (world1, mut world) = world.split_for_query(&queries.1);
+
+			let map_dynamic = queries.1.iter(&world1).next().unwrap();
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			for (&event, monster_blast_target) in queries.0.iter(&world0) {
+				let (&box_collider, target, &transform) = match queries.2.get(&world, event.entity)
+				{
+					Ok((box_collider, monster, transform)) => match monster.target {
+						Some(target) => (box_collider, target, transform),
+						None => continue,
+					},
+					Err(_) => continue,
+				};
+
+				{
+					let tracer = EntityTracer {
+						map,
+						map_dynamic,
+						quadtree: &quadtree,
+						world: &world,
+					};
+
+					if !can_see(&tracer, &transform, &box_collider, target) {
+						continue;
+					}
+				}
+
+				if let Some(sound) = &monster_blast_target.sound {
+					command_buffer.push((StartSoundEvent {
+						handle: sound.clone(),
+						entity: Some(event.entity),
+					},));
+				}
+
+				command_buffer.push((DamageEvent {
+					entity: target,
+					damage: monster_blast_target.damage,
+					source_entity: event.entity,
+					direction: Vector3::zeros(),
+				},));
+
+				let target_position = match queries.4.get_mut(&mut world, target) {
+					Ok((physics, target_transform)) => {
+						physics.velocity[2] = monster_blast_target.thrust / physics.mass;
+						target_transform.position
+					}
+					Err(_) => continue,
+				};
+
+				// Explode the fire between the monster and the target
+				let fire_height = match queries
+					.3
+					.iter(&world)
+					.find(|(_, &Owner(owner))| owner == event.entity)
+				{
+					Some((box_collider, _)) => box_collider.height,
+					None => continue,
+				};
+
+				let angle = transform.rotation[2];
+				let direction = Vector3::new(angle.cos() as f32, angle.sin() as f32, 0.0);
+				let mut midpoint = target_position - direction * FOLLOW_DISTANCE;
+				midpoint[2] += fire_height * 0.75;
+
+				let tracer = EntityTracer {
+					map,
+					map_dynamic,
+					quadtree: &quadtree,
+					world: &world,
+				};
+
+				radius_damage(
+					command_buffer,
+					&tracer,
+					&monster_blast_target.explosion,
+					event.entity,
+					midpoint,
+				);
+			}
+		})
+}
+
+#[derive(Clone, Debug)]
+pub struct MeleeAttack {
+	pub damage_range: Uniform<i32>,
+	pub damage_multiplier: i32,
+	pub sound: Option<AssetHandle<Sound>>,
+
+	/// Projectile to fire instead, if the target is out of melee range.
+	pub projectile: Option<AssetHandle<EntityTemplate>>,
+}
+
+pub fn melee_attack(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<MeleeAttack>();
+
+	SystemBuilder::new("melee_attack")
+		.read_resource::<AssetStorage>()
+		.read_resource::<Quadtree>()
+		.write_resource::<GameRng>()
+		.with_query(<&MapDynamic>::query())
+		.with_query(<(&EntityStateEvent, &MeleeAttack)>::query())
+		.with_query(<(&BoxCollider, &Monster, &Transform)>::query())
+		.read_component::<BoxCollider>() // used by EntityTracer
+		.read_component::<Owner>() // used by EntityTracer
+		.read_component::<Transform>() // used by EntityTracer
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, quadtree, game_rng) = resources;
+			let map_dynamic = queries.0.iter(world).next().unwrap();
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			for (&event, melee_attack) in queries.1.iter(world) {
+				let (box_collider, monster, &transform) = match queries.2.get(world, event.entity) {
+					Ok(x) => x,
+					Err(_) => continue,
+				};
+
+				let target = match monster.target {
+					Some(x) => x,
+					None => continue,
+				};
+
+				let tracer = EntityTracer {
+					map,
+					map_dynamic,
+					quadtree: &quadtree,
+					world,
+				};
+
+				if in_melee_range(&tracer, &transform, box_collider, target) {
+					// Apply the damage
+					let damage =
+						melee_attack.damage_multiplier * game_rng.sample(melee_attack.damage_range);
+					let direction = <&Transform>::query()
+						.get(world, target)
+						.map_or(Vector3::zeros(), |t| t.position - transform.position);
+
+					command_buffer.push((DamageEvent {
+						entity: target,
+						damage,
+						source_entity: event.entity,
+						direction,
+					},));
+
+					if let Some(sound) = &melee_attack.sound {
+						command_buffer.push((StartSoundEvent {
+							handle: sound.clone(),
+							entity: Some(event.entity),
+						},));
+					}
+				} else if let Some(projectile_handle) = &melee_attack.projectile {
+					let handle = projectile_handle.clone();
+					let mut transform = transform;
+					let direction = angles_to_axes(transform.rotation)[0];
+					transform.position += direction; // Start a little forward from the spawner
+					transform.position[2] += 32.0;
+
+					command_buffer.exec_mut(move |world, resources| {
+						resources.insert(SpawnContext(Owner(event.entity)));
+						spawn_entity(world, resources, &handle, transform);
+						resources.remove::<SpawnContext<Owner>>();
+					});
+				}
+			}
+		})
+}
+
+/// Keeps firing with a certain chance, otherwise only while the target is alive and in view.
+#[derive(Clone, Copy, Debug)]
+pub struct MonsterReFire {
+	pub chance: f64,
+}
+
+pub fn monster_refire(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<MonsterReFire>();
+
+	SystemBuilder::new("monster_refire")
+		.read_resource::<AssetStorage>()
+		.read_resource::<Quadtree>()
+		.write_resource::<GameRng>()
+		.with_query(<(&EntityStateEvent, &MonsterReFire)>::query())
+		.with_query(<&MapDynamic>::query())
+		.with_query(<(&BoxCollider, &Monster, &Transform)>::query())
+		.with_query(<&mut State>::query())
+		.read_component::<BoxCollider>() // used by EntityTracer
+		.read_component::<Health>()
+		.read_component::<Owner>() // used by EntityTracer
+		.read_component::<Transform>() // used by EntityTracer
+		.build(move |_command_buffer, world, resources, queries| {
+			let (asset_storage, quadtree, game_rng) = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+			let (world1, mut world) = world.split_for_query(&queries.1);
+
+			let map_dynamic = queries.1.iter(&world1).next().unwrap();
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			for (&event, monster_refire) in queries.0.iter(&world0) {
+				if game_rng.gen_bool(monster_refire.chance) {
+					continue;
+				}
+
+				let keep_firing = match queries.2.get(&world, event.entity) {
+					Ok((box_collider, monster, transform)) => {
+						let tracer = EntityTracer {
+							map,
+							map_dynamic,
+							quadtree: &quadtree,
+							world: &world,
+						};
+
+						monster.target.map_or(false, |target| {
+							is_alive(&world, target)
+								&& can_see(&tracer, transform, box_collider, target)
+						})
+					}
+					Err(_) => continue,
+				};
+
+				if !keep_firing {
+					if let Ok(state) = queries.3.get_mut(&mut world, event.entity) {
+						let new = (StateName::from("see").unwrap(), 0);
+						state.action = StateAction::Set(new);
+					}
+				}
+			}
+		})
+}
+
+/// Launches the monster towards its target, damaging whatever it hits first.
+#[derive(Clone, Copy, Debug)]
+pub struct MonsterCharge {
+	pub speed: f32,
+}
+
+pub fn monster_charge(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<MonsterCharge>();
+
+	SystemBuilder::new("monster_charge")
+		.with_query(<(&EntityStateEvent, &MonsterCharge)>::query())
+		.with_query(<(&BoxCollider, &Transform)>::query())
+		.with_query(<(&mut Monster, &mut Physics, &Transform)>::query())
+		.build(move |_command_buffer, world, _resources, queries| {
+			let (world0, mut world) = world.split_for_query(&queries.0);
+
+			for (&event, monster_charge) in queries.0.iter(&world0) {
+				let target = match queries.2.get_mut(&mut world, event.entity) {
+					Ok((monster, _, _)) => match monster.target {
+						Some(x) => x,
+						None => continue,
+					},
+					Err(_) => continue,
+				};
+
+				let target_position = match queries.1.get(&world, target) {
+					Ok((box_collider, transform)) => {
+						let mut position = transform.position;
+						position[2] += box_collider.height * 0.5;
+						position
+					}
+					Err(_) => continue,
+				};
+
+				if let Ok((monster, physics, transform)) =
+					queries.2.get_mut(&mut world, event.entity)
+				{
+					let direction = target_position - transform.position;
+
+					if direction != Vector3::zeros() {
+						physics.velocity = direction.normalize() * monster_charge.speed;
+						monster.charging = true;
+					}
+				}
+			}
+		})
+}
+
+#[derive(Clone, Debug)]
+pub struct ChargeTouch {
+	pub damage_range: Uniform<i32>,
+	pub damage_multiplier: i32,
+}
+
+pub fn charge_touch(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<ChargeTouch>();
+
+	SystemBuilder::new("charge_touch")
+		.write_resource::<GameRng>()
+		.with_query(<(&TouchEvent, &ChargeTouch)>::query())
+		.with_query(<(&mut Monster, &mut Physics, &mut State)>::query())
+		.build(move |command_buffer, world, game_rng, queries| {
+			let (world0, mut world) = world.split_for_query(&queries.0);
+
+			for (event, charge_touch) in queries.0.iter(&world0) {
+				let collision = match event.collision {
+					Some(x) => x,
+					None => continue,
+				};
+
+				if let Ok((monster, physics, state)) = queries.1.get_mut(&mut world, event.entity) {
+					if !monster.charging {
+						continue;
+					}
+
+					// Slammed into something, stop
+					monster.charging = false;
+					physics.velocity = Vector3::zeros();
+					let new = (StateName::from("spawn").unwrap(), 0);
+					state.action = StateAction::Set(new);
+
+					// Apply the damage to the other entity
+					let damage =
+						charge_touch.damage_multiplier * game_rng.sample(charge_touch.damage_range);
+
+					command_buffer.push((DamageEvent {
+						entity: event.other,
+						damage,
+						source_entity: event.entity,
+						direction: collision.velocity,
+					},));
+				}
+			}
+		})
+}
+
+/// Component for monsters that come back to life at their spawn point some time after dying.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct MonsterRespawn {
+	pub transform: Transform,
+	pub timer: Timer,
+}
+
+pub fn monster_respawn(resources: &mut Resources) -> impl Runnable {
+	let mut registry = <Write<Registry<String>>>::fetch_mut(resources);
+	registry.register::<MonsterRespawn>("MonsterRespawn".into());
+
+	SystemBuilder::new("monster_respawn")
+		.read_resource::<GameTime>()
+		.write_resource::<GameRng>()
+		.write_resource::<Quadtree>()
+		.with_query(<(
+			Entity,
+			&BoxCollider,
+			&EntityTemplateRef,
+			&Health,
+			&State,
+			&Transform,
+			&mut MonsterRespawn,
+			Option<&MonsterAmbush>,
+		)>::query())
+		.with_query(<(Entity, &BoxCollider, &Transform)>::query().filter(component::<Health>()))
+		.build(move |command_buffer, world, resources, queries| {
+			let (game_time, game_rng, quadtree) = resources;
+			let (mut world0, world) = world.split_for_query(&queries.0);
+
+			for (
+				&entity,
+				box_collider,
+				template_ref,
+				health,
+				state,
+				&transform,
+				monster_respawn,
+				ambush,
+			) in queries.0.iter_mut(&mut world0)
+			{
+				// Only respawn once the death animation has finished
+				if health.current > 0 || !matches!(state.action, StateAction::None) {
+					monster_respawn
+						.timer
+						.restart_with(**game_time, RESPAWN_TIME);
+					continue;
+				}
+
+				if !monster_respawn.timer.is_elapsed(**game_time) {
+					continue;
+				}
+
+				if !game_rng.gen_bool(5.0 / 256.0) {
+					monster_respawn
+						.timer
+						.restart_with(**game_time, 32 * FRAME_TIME);
+					continue;
+				}
+
+				// Don't respawn if something is standing on the spawn point
+				let spawn_transform = monster_respawn.transform;
+				let bbox = AABB2::from(
+					AABB3::from_radius_height(box_collider.radius, box_collider.height)
+						.offset(spawn_transform.position),
+				);
+
+				if queries
+					.1
+					.iter(&world)
+					.any(|(&other, other_box_collider, other_transform)| {
+						let other_bbox = AABB3::from_radius_height(
+							other_box_collider.radius,
+							other_box_collider.height,
+						);
+
+						other != entity
+							&& other_box_collider.blocks_types.blocks(SolidType::MONSTER)
+							&& bbox
+								.overlaps(&AABB2::from(other_bbox.offset(other_transform.position)))
+					}) {
+					monster_respawn
+						.timer
+						.restart_with(**game_time, 32 * FRAME_TIME);
+					continue;
+				}
+
+				// Replace the corpse with a new monster, with fog at both positions
+				let handle = template_ref.0.clone();
+				let ambush = ambush.copied();
+				command_buffer.exec_mut(move |world, resources| {
+					let fog = <Write<AssetStorage>>::fetch_mut(resources).load("tfog.entity");
+					spawn_entity(world, resources, &fog, transform);
+					spawn_entity(world, resources, &fog, spawn_transform);
+
+					let new_entity = spawn_entity(world, resources, &handle, spawn_transform);
+					let game_time = *<Read<GameTime>>::fetch(resources);
+					let mut entry = world.entry(new_entity).unwrap();
+					entry.add_component(MonsterRespawn {
+						transform: spawn_transform,
+						timer: Timer::new(game_time, RESPAWN_TIME),
+					});
+
+					if let Some(ambush) = ambush {
+						entry.add_component(ambush);
+					}
+				});
+
+				quadtree.remove(entity);
+				command_buffer.remove(entity);
+			}
+		})
+}
@@ -0,0 +1,433 @@
+use crate::{
+	common::{
+		assets::{AssetHandle, AssetStorage},
+		geometry::{Angle, AABB2, AABB3},
+		quadtree::Quadtree,
+		random::GameRng,
+		spawn::{SpawnContext, SpawnMergerHandlerSet},
+		time::{GameTime, Timer},
+	},
+	doom::{
+		assets::template::{EntityTemplate, EntityTemplateRef},
+		data::{FRAME_RATE, FRAME_TIME},
+		game::{
+			combat::{DamageEvent, Health, Owner},
+			map::{
+				door::{self, DoorActive, DoorParams, DoorState},
+				floor::{self, FloorActive, FloorParams, FloorTargetHeight},
+				MapDynamic,
+			},
+			options::{GameOptions, Skill},
+			physics::{BoxCollider, Physics, SolidType},
+			spawn::spawn_entity,
+			state::entity::EntityStateEvent,
+			Transform,
+		},
+		sound::StartSoundEvent,
+		ui::intermission::start_intermission,
+	},
+};
+use legion::{
+	component,
+	systems::{CommandBuffer, ResourceSet, Runnable},
+	Entity, EntityStore, IntoQuery, Read, Registry, Resources, SystemBuilder, World, Write,
+};
+use nalgebra::Vector3;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Something that happens when the last monster of a boss type dies.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BossDeathAction {
+	/// Lower the floors with the given tag to their lowest neighbouring floor.
+	LowerFloor(u16),
+	/// Raise the floors with the given tag by the height of their shortest lower texture.
+	RaiseFloorToTexture(u16),
+	/// Open the doors with the given tag.
+	OpenDoor(u16),
+	/// Open the doors with the given tag, quickly.
+	OpenDoorFast(u16),
+	/// Exit the map.
+	ExitMap,
+}
+
+/// A special action of a map, taken when all monsters spawned from `entity` are dead.
+#[derive(Clone, Copy, Debug)]
+pub struct BossSpecial {
+	pub entity: &'static str,
+	pub action: BossDeathAction,
+}
+
+/// Component for states that take the map's boss specials for the monster,
+/// if it is the last of its type to die.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BossDeath {
+	/// Action that is taken on every map, in addition to the map's specials.
+	pub action: Option<BossDeathAction>,
+}
+
+pub fn boss_death(resources: &mut Resources) -> impl Runnable {
+	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<BossDeath>();
+
+	SystemBuilder::new("boss_death")
+		.read_resource::<GameTime>()
+		.write_resource::<AssetStorage>()
+		.with_query(<(&EntityStateEvent, &BossDeath)>::query())
+		.with_query(<(Entity, &EntityTemplateRef, &Health)>::query())
+		.with_query(<&mut MapDynamic>::query())
+		.read_component::<DoorActive>() // used by door::activate_with_tag
+		.read_component::<FloorActive>() // used by floor::activate_with_tag
+		.build(move |command_buffer, world, resources, queries| {
+			let (game_time, asset_storage) = resources;
+			let (mut world2, world) = world.split_for_query(&queries.2);
+
+			for (&event, boss_death) in queries.0.iter(&world) {
+				let handle = match queries.1.get(&world, event.entity) {
+					Ok((_, template_ref, _)) => template_ref.0.clone(),
+					Err(_) => continue,
+				};
+
+				// Only the last one of its type to die triggers the actions
+				if queries
+					.1
+					.iter(&world)
+					.any(|(&entity, template_ref, health)| {
+						entity != event.entity && template_ref.0 == handle && health.current > 0
+					}) {
+					continue;
+				}
+
+				let map_dynamic = match queries.2.iter_mut(&mut world2).next() {
+					Some(x) => x,
+					None => continue,
+				};
+
+				let actions: Vec<BossDeathAction> = {
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let name = asset_storage.name_for(&handle);
+
+					boss_death
+						.action
+						.into_iter()
+						.chain(
+							map.boss_specials
+								.iter()
+								.filter(|special| Some(special.entity) == name)
+								.map(|special| special.action),
+						)
+						.collect()
+				};
+
+				for action in actions {
+					activate(
+						action,
+						command_buffer,
+						**game_time,
+						&world,
+						asset_storage,
+						map_dynamic,
+					);
+				}
+			}
+		})
+}
+
+fn activate<W: EntityStore>(
+	action: BossDeathAction,
+	command_buffer: &mut CommandBuffer,
+	game_time: GameTime,
+	world: &W,
+	asset_storage: &mut AssetStorage,
+	map_dynamic: &mut MapDynamic,
+) {
+	match action {
+		BossDeathAction::LowerFloor(sector_tag)
+		| BossDeathAction::RaiseFloorToTexture(sector_tag) => {
+			let params = FloorParams {
+				speed: 1.0 * FRAME_RATE,
+				target_height_base: match action {
+					BossDeathAction::LowerFloor(_) => FloorTargetHeight::LowestNeighbourFloor,
+					_ => FloorTargetHeight::ShortestLowerTexture,
+				},
+				target_height_offset: 0.0,
+				move_sound: Some(asset_storage.load("dsstnmov.sound")),
+				move_sound_time: 8 * FRAME_TIME,
+				finish_sound: Some(asset_storage.load("dspstop.sound")),
+				crush: None,
+				change_type: None,
+			};
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			floor::activate_with_tag(
+				&params,
+				command_buffer,
+				game_time,
+				sector_tag,
+				None,
+				world,
+				asset_storage,
+				map,
+				map_dynamic,
+			);
+		}
+		BossDeathAction::OpenDoor(sector_tag) | BossDeathAction::OpenDoorFast(sector_tag) => {
+			let params = if matches!(action, BossDeathAction::OpenDoorFast(_)) {
+				DoorParams {
+					start_state: DoorState::Closed,
+					end_state: DoorState::Open,
+					speed: 8.0 * FRAME_RATE,
+					wait_time: Duration::ZERO,
+					can_reverse: false,
+
+					open_sound: Some(asset_storage.load("dsbdopn.sound")),
+					close_sound: Some(asset_storage.load("dsbdcls.sound")),
+				}
+			} else {
+				DoorParams {
+					start_state: DoorState::Closed,
+					end_state: DoorState::Open,
+					speed: 2.0 * FRAME_RATE,
+					wait_time: Duration::ZERO,
+					can_reverse: false,
+
+					open_sound: Some(asset_storage.load("dsdoropn.sound")),
+					close_sound: Some(asset_storage.load("dsdorcls.sound")),
+				}
+			};
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+			door::activate_with_tag(
+				&params,
+				command_buffer,
+				game_time,
+				sector_tag,
+				world,
+				map,
+				map_dynamic,
+			);
+		}
+		BossDeathAction::ExitMap => {
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+			let next_map = match &map.exit {
+				Some(exit) => exit.clone(),
+				None => asset_storage.name_for(&map_dynamic.map).unwrap().to_owned(),
+			};
+
+			command_buffer
+				.exec_mut(move |world, resources| start_intermission(&next_map, world, resources));
+		}
+	}
+}
+
+/// Component for the spots that a boss brain spits its cubes at.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct BrainTarget;
+
+/// Component for entities that spit cubes at brain targets, taking turns between them.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct BrainSpitter {
+	pub next_target: usize,
+
+	/// Toggled on every spit. On easy skill levels, only every other spit goes ahead.
+	pub alternate: bool,
+}
+
+/// Component for states in which a brain spitter spits a cube at its next target.
+#[derive(Clone, Debug)]
+pub struct BrainSpit {
+	pub handle: AssetHandle<EntityTemplate>,
+}
+
+pub fn brain_spit(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<BrainSpitter>("BrainSpitter".into());
+	handler_set.register_clone::<BrainSpitter>();
+
+	registry.register::<BrainTarget>("BrainTarget".into());
+	handler_set.register_clone::<BrainTarget>();
+
+	handler_set.register_clone::<BrainSpit>();
+
+	SystemBuilder::new("brain_spit")
+		.read_resource::<GameOptions>()
+		.with_query(<(&EntityStateEvent, &BrainSpit)>::query())
+		.with_query(<(Entity, &Transform)>::query().filter(component::<BrainTarget>()))
+		.with_query(<(&mut BrainSpitter, &Transform)>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let game_options = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+
+			for (&event, brain_spit) in queries.0.iter(&world0) {
+				let targets: Vec<(Entity, Vector3<f32>)> = queries
+					.1
+					.iter(&world)
+					.map(|(&target, target_transform)| (target, target_transform.position))
+					.collect();
+
+				if targets.is_empty() {
+					continue;
+				}
+
+				let (spitter, &transform) = match queries.2.get_mut(&mut world, event.entity) {
+					Ok(x) => x,
+					Err(_) => continue,
+				};
+
+				spitter.alternate = !spitter.alternate;
+
+				if game_options.skill <= Skill::Easy && !spitter.alternate {
+					continue;
+				}
+
+				let (target, target_position) = targets[spitter.next_target % targets.len()];
+				spitter.next_target = (spitter.next_target + 1) % targets.len();
+
+				// Aim at the target
+				let delta = target_position - transform.position;
+				let distance = delta.norm();
+				let mut spawn_transform = transform;
+				spawn_transform.rotation[1] = Angle::from_radians(f64::atan2(
+					-delta[2] as f64,
+					delta.fixed_rows::<2>(0).norm() as f64,
+				));
+				spawn_transform.rotation[2] =
+					Angle::from_radians(f64::atan2(delta[1] as f64, delta[0] as f64));
+
+				let handle = brain_spit.handle.clone();
+				command_buffer.exec_mut(move |world, resources| {
+					resources.insert(SpawnContext(Owner(event.entity)));
+					let cube = spawn_entity(world, resources, &handle, spawn_transform);
+					resources.remove::<SpawnContext<Owner>>();
+
+					// Arrive at the target when the distance has been covered
+					let game_time = *<Read<GameTime>>::fetch(resources);
+					let mut entry = world.entry(cube).unwrap();
+					let speed = entry
+						.get_component::<Physics>()
+						.map_or(0.0, |physics| physics.velocity.norm());
+					let travel_time = if speed > 0.0 {
+						Duration::from_secs_f32(distance / speed)
+					} else {
+						Duration::ZERO
+					};
+
+					entry.add_component(BrainCube {
+						target,
+						timer: Timer::new(game_time, travel_time),
+					});
+				});
+			}
+		})
+}
+
+/// Component for cubes spat by a brain spitter,
+/// which spawn a monster at their target once they arrive there.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct BrainCube {
+	pub target: Entity,
+	pub timer: Timer,
+}
+
+/// Monsters that brain cubes can spawn, with the upper limit of a random byte to pick each.
+/// Anything above the last limit spawns a baron of hell.
+const BRAIN_MONSTERS: [(u8, &str); 10] = [
+	(50, "troop.entity"),
+	(90, "sergeant.entity"),
+	(120, "shadows.entity"),
+	(130, "pain.entity"),
+	(160, "head.entity"),
+	(162, "vile.entity"),
+	(172, "undead.entity"),
+	(192, "baby.entity"),
+	(222, "fatso.entity"),
+	(246, "knight.entity"),
+];
+
+pub fn brain_cube(resources: &mut Resources) -> impl Runnable {
+	let mut registry = <Write<Registry<String>>>::fetch_mut(resources);
+	registry.register::<BrainCube>("BrainCube".into());
+
+	SystemBuilder::new("brain_cube")
+		.read_resource::<GameTime>()
+		.write_resource::<Quadtree>()
+		.with_query(<(Entity, &BrainCube)>::query())
+		.with_query(<&Transform>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let (game_time, quadtree) = resources;
+
+			for (&entity, brain_cube) in queries.0.iter(world) {
+				if !brain_cube.timer.is_elapsed(**game_time) {
+					continue;
+				}
+
+				if let Ok(&transform) = queries.1.get(world, brain_cube.target) {
+					command_buffer.exec_mut(move |world, resources| {
+						spawn_brain_monster(transform, world, resources)
+					});
+				}
+
+				quadtree.remove(entity);
+				command_buffer.remove(entity);
+			}
+		})
+}
+
+/// Spawns a random monster with fire at `transform`, killing anything that is in the way.
+fn spawn_brain_monster(transform: Transform, world: &mut World, resources: &mut Resources) {
+	let (fire_handle, sound_handle, monster_handle) = {
+		let (mut asset_storage, mut game_rng) =
+			<(Write<AssetStorage>, Write<GameRng>)>::fetch_mut(resources);
+		let roll = game_rng.gen::<u8>();
+		let name = BRAIN_MONSTERS
+			.iter()
+			.find(|(limit, _)| roll < *limit)
+			.map_or("bruiser.entity", |(_, name)| name);
+
+		(
+			asset_storage.load::<EntityTemplate>("spawnfire.entity"),
+			asset_storage.load("dstelept.sound"),
+			asset_storage.load::<EntityTemplate>(name),
+		)
+	};
+
+	let fire = spawn_entity(world, resources, &fire_handle, transform);
+	world.push((StartSoundEvent {
+		handle: sound_handle,
+		entity: Some(fire),
+	},));
+
+	let entity = spawn_entity(world, resources, &monster_handle, transform);
+	let bbox = match <&BoxCollider>::query().get(world, entity) {
+		Ok(box_collider) => AABB2::from(
+			AABB3::from_radius_height(box_collider.radius, box_collider.height)
+				.offset(transform.position),
+		),
+		Err(_) => return,
+	};
+
+	let telefragged: Vec<Entity> = <(Entity, &BoxCollider, &Transform)>::query()
+		.iter(world)
+		.filter(|(&other, other_box_collider, other_transform)| {
+			let other_bbox =
+				AABB3::from_radius_height(other_box_collider.radius, other_box_collider.height);
+
+			other != entity
+				&& other_box_collider.blocks_types.blocks(SolidType::MONSTER)
+				&& bbox.overlaps(&AABB2::from(other_bbox.offset(other_transform.position)))
+		})
+		.map(|(&other, _, _)| other)
+		.collect();
+
+	for other in telefragged {
+		world.push((DamageEvent {
+			entity: other,
+			damage: 10000,
+			source_entity: entity,
+			direction: Vector3::zeros(),
+		},));
+	}
+}
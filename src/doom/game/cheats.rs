@@ -3,8 +3,12 @@ use crate::{
 	doom::{
 		assets::process_assets,
 		game::{
-			client::Client,
-			combat::weapon::{AmmoState, WeaponState},
+			client::Clients,
+			combat::{
+				weapon::{AmmoState, WeaponState},
+				Armor, ArmorType, MAX_ARMOR,
+			},
+			item::{KeyColor, KeyInventory, KeyType},
 		},
 		iwad::IWADInfo,
 	},
@@ -13,12 +17,16 @@ use legion::{systems::ResourceSet, IntoQuery, Read, Resources, World, Write};
 
 pub fn give_all(world: &mut World, resources: &mut Resources, add_keys: bool) {
 	{
-		let (client, iwadinfo, mut asset_storage) =
-			<(Read<Client>, Read<IWADInfo>, Write<AssetStorage>)>::fetch_mut(resources);
-		let mut query = <&mut WeaponState>::query();
+		let (clients, iwadinfo, mut asset_storage) =
+			<(Read<Clients>, Read<IWADInfo>, Write<AssetStorage>)>::fetch_mut(resources);
+		let mut query = <(
+			&mut WeaponState,
+			Option<&mut Armor>,
+			Option<&mut KeyInventory>,
+		)>::query();
 
-		if let Some(weapon_state) = client
-			.entity
+		if let Some((weapon_state, armor, key_inventory)) = clients
+			.local_entity()
 			.and_then(|entity| query.get_mut(world, entity).ok())
 		{
 			weapon_state
@@ -43,7 +51,20 @@ pub fn give_all(world: &mut World, resources: &mut Resources, add_keys: bool) {
 				);
 			}
 
+			if let Some(armor) = armor {
+				armor.current = MAX_ARMOR;
+				armor.armor_type = Some(ArmorType::Blue);
+			}
+
 			if add_keys {
+				if let Some(key_inventory) = key_inventory {
+					for &color in [KeyColor::Blue, KeyColor::Yellow, KeyColor::Red].iter() {
+						for &key_type in [KeyType::Card, KeyType::Skull].iter() {
+							key_inventory.keys.insert((color, key_type));
+						}
+					}
+				}
+
 				log::info!("Very Happy Ammo Added!");
 			} else {
 				log::info!("Ammo (no keys) Added");
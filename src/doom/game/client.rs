@@ -5,7 +5,7 @@ use crate::{
 		input::InputState,
 		quadtree::Quadtree,
 		spawn::{spawn_helper, ComponentAccessor, SpawnContext, SpawnFrom, SpawnMergerHandlerSet},
-		time::DeltaTime,
+		time::{DeltaTime, GameTime, Timer},
 	},
 	doom::{
 		assets::{
@@ -15,9 +15,17 @@ use crate::{
 		data::{FORWARD_ACCEL, FRAME_RATE, STRAFE_ACCEL},
 		game::{
 			camera::Camera,
-			combat::{weapon::WeaponState, Owner},
+			combat::{weapon::WeaponState, Armor, Health, Owner, MAX_ARMOR},
+			deathmatch::{Frags, ItemRespawn, ITEM_RESPAWN_TIME},
+			item::{
+				AmmoPickup, ArmorPickup, KeyInventory, KeyPickup, PowerupPickup, PowerupType,
+				Powerups, WeaponPickup,
+			},
 			map::MapDynamic,
+			options::GameOptions,
 			physics::{BoxCollider, Physics, TouchEvent},
+			spawn::{spawn_entity, spawn_player},
+			stats::{CountItem, LevelStats},
 			trace::EntityTracer,
 			Transform,
 		},
@@ -31,12 +39,63 @@ use legion::{
 };
 use nalgebra::{Vector2, Vector3};
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
 
+/// A player taking part in the game, and the commands it is sending.
 #[derive(Clone, Default, Serialize, Deserialize)]
 pub struct Client {
+	pub player_num: usize,
 	pub entity: Option<Entity>,
 	pub command: UserCommand,
 	pub previous_command: UserCommand,
+
+	/// Commands that have arrived over the network for this player but have not been carried
+	/// out yet. One is taken every tick, and the player stands still while there are none.
+	/// The local player's commands come from the input instead.
+	#[serde(skip)]
+	pub queued_commands: VecDeque<UserCommand>,
+}
+
+impl Client {
+	#[inline]
+	pub fn new(player_num: usize, entity: Entity) -> Client {
+		Client {
+			player_num,
+			entity: Some(entity),
+			..Client::default()
+		}
+	}
+}
+
+/// Resource holding all the players taking part in the game.
+/// Only one of them is played on this machine, the others are controlled over the network.
+#[derive(Clone, Default, Serialize, Deserialize)]
+pub struct Clients {
+	pub clients: Vec<Client>,
+
+	/// Index of the local player, whose commands come from the input and whose view is shown.
+	pub local: usize,
+}
+
+impl Clients {
+	#[inline]
+	pub fn local(&self) -> Option<&Client> {
+		self.clients.get(self.local)
+	}
+
+	/// Returns the entity of the local player, which is the one that is viewed and heard from.
+	#[inline]
+	pub fn local_entity(&self) -> Option<Entity> {
+		self.local().and_then(|client| client.entity)
+	}
+
+	/// Returns the client that controls `entity`, if any.
+	#[inline]
+	pub fn for_entity(&self, entity: Entity) -> Option<&Client> {
+		self.clients
+			.iter()
+			.find(|client| client.entity == Some(entity))
+	}
 }
 
 fn select_weapon<'a>(
@@ -75,82 +134,97 @@ fn select_weapon<'a>(
 	next.or(first)
 }
 
+/// Builds a command from the local input. `weapon_state` is used to decide which weapon
+/// the weapon keys select.
+fn input_command(
+	asset_storage: &AssetStorage,
+	input_state: &InputState,
+	weapon_state: Option<&WeaponState>,
+) -> UserCommand {
+	let weapon: Option<&str> = weapon_state.and_then(|weapon_state| {
+		let weapon_keys = [
+			input_state.bool_value("weapon1"),
+			input_state.bool_value("weapon2"),
+			input_state.bool_value("weapon3"),
+			input_state.bool_value("weapon4"),
+			input_state.bool_value("weapon5"),
+			input_state.bool_value("weapon6"),
+			input_state.bool_value("weapon7"),
+		];
+		let mut iter =
+			weapon_keys
+				.iter()
+				.enumerate()
+				.filter_map(|(i, x)| if *x { Some(i + 1) } else { None });
+
+		// Do not register a button press if more than one weapon key is pressed at a time
+		let weapon_index: Option<usize> = match (iter.next(), iter.next()) {
+			(Some(i), None) => Some(i),
+			_ => None,
+		};
+
+		weapon_index
+			.map(|i| match i {
+				1 => &["chainsaw", "fist"] as &[&str],
+				2 => &["pistol"],
+				3 => &["supershotgun", "shotgun"],
+				4 => &["chaingun"],
+				5 => &["missile"],
+				6 => &["plasma"],
+				7 => &["bfg"],
+				_ => unreachable!(),
+			})
+			.and_then(|possible| select_weapon(possible, asset_storage, weapon_state))
+	});
+
+	let mut command = UserCommand {
+		attack: input_state.bool_value("attack"),
+		weapon: weapon.map(|x| x.to_owned()),
+		r#use: input_state.bool_value("use"),
+		forward: input_state.float_value("forward") as f32,
+		pitch: input_state.float_value("pitch") as f32,
+		strafe: input_state.float_value("strafe") as f32,
+		yaw: input_state.float_value("yaw") as f32,
+	};
+
+	if input_state.bool_value("walk") {
+		command.forward *= 0.5;
+		command.strafe *= 0.6;
+	}
+
+	command
+}
+
 pub fn player_command(_resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("player_command")
 		.read_resource::<AssetStorage>()
 		.read_resource::<InputState>()
-		.write_resource::<Client>()
+		.write_resource::<Clients>()
 		.with_query(<&WeaponState>::query())
 		.build(move |_command_buffer, world, resources, query| {
-			let (asset_storage, input_state, client) = resources;
-
-			let weapon: Option<&str> = client.entity.and_then(|entity| {
-				let weapon_keys = [
-					input_state.bool_value("weapon1"),
-					input_state.bool_value("weapon2"),
-					input_state.bool_value("weapon3"),
-					input_state.bool_value("weapon4"),
-					input_state.bool_value("weapon5"),
-					input_state.bool_value("weapon6"),
-					input_state.bool_value("weapon7"),
-				];
-				let mut iter =
-					weapon_keys
-						.iter()
-						.enumerate()
-						.filter_map(|(i, x)| if *x { Some(i + 1) } else { None });
-
-				// Do not register a button press if more than one weapon key is pressed at a time
-				let weapon_index: Option<usize> = match (iter.next(), iter.next()) {
-					(Some(i), None) => Some(i),
-					_ => None,
+			let (asset_storage, input_state, clients) = resources;
+			let clients: &mut Clients = &mut *clients; // This prevents borrow errors
+			let local = clients.local;
+
+			for (i, client) in clients.clients.iter_mut().enumerate() {
+				let command = if i == local {
+					let weapon_state = client
+						.entity
+						.and_then(|entity| query.get(world, entity).ok());
+					input_command(asset_storage, input_state, weapon_state)
+				} else {
+					client.queued_commands.pop_front().unwrap_or_default()
 				};
 
-				weapon_index
-					.map(|i| match i {
-						1 => &["chainsaw", "fist"] as &[&str],
-						2 => &["pistol"],
-						3 => &["supershotgun", "shotgun"],
-						4 => &["chaingun"],
-						5 => &["missile"],
-						6 => &["plasma"],
-						7 => &["bfg"],
-						_ => unreachable!(),
-					})
-					.and_then(|possible| {
-						let weapon_state = query
-							.get(world, entity)
-							.expect("Client entity does not have WeaponState");
-
-						select_weapon(possible, asset_storage, weapon_state)
-					})
-			});
-
-			let mut command = UserCommand {
-				attack: input_state.bool_value("attack"),
-				weapon: weapon.map(|x| x.to_owned()),
-				r#use: input_state.bool_value("use"),
-				forward: input_state.float_value("forward") as f32,
-				pitch: input_state.float_value("pitch") as f32,
-				strafe: input_state.float_value("strafe") as f32,
-				yaw: input_state.float_value("yaw") as f32,
-			};
-
-			if input_state.bool_value("walk") {
-				command.forward *= 0.5;
-				command.strafe *= 0.6;
+				client.previous_command = std::mem::replace(&mut client.command, command);
 			}
-
-			let client: &mut Client = &mut *client; // This prevents borrow errors
-			std::mem::swap(&mut client.previous_command, &mut client.command);
-			client.command = command;
 		})
 }
 
 pub fn player_move(_resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("player_move")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.read_resource::<DeltaTime>()
 		.read_resource::<Quadtree>()
 		.with_query(<&mut Transform>::query())
@@ -161,76 +235,78 @@ pub fn player_move(_resources: &mut Resources) -> impl Runnable {
 		.read_component::<Owner>() // used by EntityTracer
 		.read_component::<Transform>() // used by EntityTracer
 		.build(move |_command_buffer, world, resources, queries| {
-			let (asset_storage, client, delta_time, quadtree) = resources;
+			let (asset_storage, clients, delta_time, quadtree) = resources;
 
-			let client_entity = match client.entity {
-				Some(e) => e,
-				None => return,
-			};
-
-			// Apply rotation
-			{
-				let transform = queries.0.get_mut(world, client_entity).unwrap();
+			for client in clients.clients.iter() {
+				let client_entity = match client.entity {
+					Some(e) => e,
+					None => continue,
+				};
 
-				transform.rotation[1] += (client.command.pitch * 1e6) as i32;
-				transform.rotation[1].0 =
-					num_traits::clamp(transform.rotation[1].0, -0x4000_0000, 0x4000_0000);
+				// Apply rotation
+				{
+					let transform = queries.0.get_mut(world, client_entity).unwrap();
 
-				transform.rotation[2] -= (client.command.yaw * 1e6) as i32;
-			}
+					transform.rotation[1] += (client.command.pitch * 1e6) as i32;
+					transform.rotation[1].0 =
+						num_traits::clamp(transform.rotation[1].0, -0x4000_0000, 0x4000_0000);
 
-			// Apply acceleration
-			{
-				if client.command.forward == 0.0 && client.command.strafe == 0.0 {
-					return;
+					transform.rotation[2] -= (client.command.yaw * 1e6) as i32;
 				}
 
-				let map_dynamic = queries.1.iter(world).next().unwrap();
-				let map = asset_storage.get(&map_dynamic.map).unwrap();
-
-				let (bbox, ignore, solid_type, position) = {
-					let (box_collider, owner, transform) =
-						queries.2.get(world, client_entity).unwrap();
-					(
-						AABB3::from_radius_height(box_collider.radius, box_collider.height),
-						Some(owner.map_or(client_entity, |&Owner(owner)| owner)),
-						box_collider.solid_type,
-						transform.position,
-					)
-				};
+				// Apply acceleration
+				{
+					if client.command.forward == 0.0 && client.command.strafe == 0.0 {
+						continue;
+					}
 
-				let tracer = EntityTracer {
-					map,
-					map_dynamic,
-					quadtree: &quadtree,
-					world,
-				};
+					let map_dynamic = queries.1.iter(world).next().unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
 
-				let trace = tracer.trace(
-					&bbox,
-					solid_type,
-					ignore,
-					Line3::new(position, Vector3::new(0.0, 0.0, -0.25)),
-				);
+					let (bbox, ignore, solid_type, position) = {
+						let (box_collider, owner, transform) =
+							queries.2.get(world, client_entity).unwrap();
+						(
+							AABB3::from_radius_height(box_collider.radius, box_collider.height),
+							Some(owner.map_or(client_entity, |&Owner(owner)| owner)),
+							box_collider.solid_type,
+							transform.position,
+						)
+					};
+
+					let tracer = EntityTracer {
+						map,
+						map_dynamic,
+						quadtree: &quadtree,
+						world,
+					};
+
+					let trace = tracer.trace(
+						&bbox,
+						solid_type,
+						ignore,
+						Line3::new(position, Vector3::new(0.0, 0.0, -0.25)),
+					);
 
-				if trace.collision.is_none() {
-					// Player is not on ground
-					return;
-				}
+					if trace.collision.is_none() {
+						// Player is not on ground
+						continue;
+					}
 
-				let move_dir = Vector2::new(
-					client.command.forward.max(-1.0).min(1.0) * FORWARD_ACCEL,
-					client.command.strafe.max(-1.0).min(1.0) * STRAFE_ACCEL,
-				);
+					let move_dir = Vector2::new(
+						client.command.forward.max(-1.0).min(1.0) * FORWARD_ACCEL,
+						client.command.strafe.max(-1.0).min(1.0) * STRAFE_ACCEL,
+					);
 
-				let (transform, physics) = queries.3.get_mut(world, client_entity).unwrap();
+					let (transform, physics) = queries.3.get_mut(world, client_entity).unwrap();
 
-				let angles = Vector3::new(0.into(), 0.into(), transform.rotation[2]);
-				let axes = crate::common::geometry::angles_to_axes(angles);
-				let accel =
-					(axes[0] * move_dir[0] + axes[1] * move_dir[1]) * delta_time.0.as_secs_f32();
+					let angles = Vector3::new(0.into(), 0.into(), transform.rotation[2]);
+					let axes = crate::common::geometry::angles_to_axes(angles);
+					let accel = (axes[0] * move_dir[0] + axes[1] * move_dir[1])
+						* delta_time.0.as_secs_f32();
 
-				physics.velocity += accel;
+					physics.velocity += accel;
+				}
 			}
 		})
 }
@@ -275,81 +351,86 @@ pub fn player_use(resources: &mut Resources) -> impl Runnable {
 
 	SystemBuilder::new("player_use")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.with_query(<(&Transform, &User)>::query())
 		.with_query(<&MapDynamic>::query())
 		.with_query(<(&EntityTemplateRef, &Usable)>::query())
 		.build(move |command_buffer, world, resources, queries| {
-			let (asset_storage, client) = resources;
-
-			if let Some(entity) = client.entity {
-				if client.command.r#use && !client.previous_command.r#use {
-					let (transform, user) = queries.0.get(world, entity).unwrap();
-					let map_dynamic = queries.1.iter(world).next().unwrap();
-					let map = asset_storage.get(&map_dynamic.map).unwrap();
-
-					const USERANGE: f32 = 64.0;
-					let yaw = transform.rotation[2].to_radians() as f32;
-					let use_line = Line2::new(
-						Vector2::new(transform.position[0], transform.position[1]),
-						Vector2::new(yaw.cos(), yaw.sin()) * USERANGE,
-					);
-
-					// Find the closest linedef hit
-					// TODO use a trace here
-					let mut pmax = 1.0;
-					let mut closest_linedef = None;
-
-					for (i, linedef) in map.linedefs.iter().enumerate() {
-						if let Some((linedef_p, use_p)) = linedef.line.intersect(&use_line) {
-							if linedef_p >= 0.0 && linedef_p <= 1.0 && use_p >= 0.0 && use_p < pmax
-							{
-								// Always hit a usable linedef
-								if let Ok((_, Usable)) =
-									queries.2.get(world, map_dynamic.linedefs[i].entity)
-								{
-									pmax = use_p;
-									closest_linedef = Some(i);
-								} else if let [Some(_front_sidedef), Some(_back_sidedef)] =
-									&linedef.sidedefs
+			let (asset_storage, clients) = resources;
+
+			for client in clients.clients.iter() {
+				if let Some(entity) = client.entity {
+					if client.command.r#use && !client.previous_command.r#use {
+						let (transform, user) = queries.0.get(world, entity).unwrap();
+						let map_dynamic = queries.1.iter(world).next().unwrap();
+						let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+						const USERANGE: f32 = 64.0;
+						let yaw = transform.rotation[2].to_radians() as f32;
+						let use_line = Line2::new(
+							Vector2::new(transform.position[0], transform.position[1]),
+							Vector2::new(yaw.cos(), yaw.sin()) * USERANGE,
+						);
+
+						// Find the closest linedef hit
+						// TODO use a trace here
+						let mut pmax = 1.0;
+						let mut closest_linedef = None;
+
+						for (i, linedef) in map.linedefs.iter().enumerate() {
+							if let Some((linedef_p, use_p)) = linedef.line.intersect(&use_line) {
+								if linedef_p >= 0.0
+									&& linedef_p <= 1.0 && use_p >= 0.0
+									&& use_p < pmax
 								{
-									// Skip two-sided linedefs
-								} else {
-									pmax = use_p;
-									closest_linedef = Some(i);
+									// Always hit a usable linedef
+									if let Ok((_, Usable)) =
+										queries.2.get(world, map_dynamic.linedefs[i].entity)
+									{
+										pmax = use_p;
+										closest_linedef = Some(i);
+									} else if let [Some(_front_sidedef), Some(_back_sidedef)] =
+										&linedef.sidedefs
+									{
+										// Skip two-sided linedefs
+									} else {
+										pmax = use_p;
+										closest_linedef = Some(i);
+									}
 								}
 							}
 						}
-					}
 
-					// We hit a linedef, use it
-					if let Some(linedef_index) = closest_linedef {
-						let linedef = &map.linedefs[linedef_index];
+						// We hit a linedef, use it
+						if let Some(linedef_index) = closest_linedef {
+							let linedef = &map.linedefs[linedef_index];
 
-						// Used from the back, ignore
-						if (use_line.point - linedef.line.point).dot(&linedef.normal) <= 0.0 {
-							return;
-						}
+							// Used from the back, ignore
+							if (use_line.point - linedef.line.point).dot(&linedef.normal) <= 0.0 {
+								continue;
+							}
+
+							let linedef_entity = map_dynamic.linedefs[linedef_index].entity;
 
-						let linedef_entity = map_dynamic.linedefs[linedef_index].entity;
-
-						if let Ok((template_ref, Usable)) = queries.2.get(world, linedef_entity) {
-							let event = UseEvent {
-								entity: linedef_entity,
-								other: entity,
-							};
-							let handle = template_ref.0.clone();
-							command_buffer.exec_mut(move |world, resources| {
-								resources.insert(SpawnContext(event));
-								let asset_storage = <Read<AssetStorage>>::fetch(resources);
-								let use_world = &asset_storage.get(&handle).unwrap().r#use;
-								spawn_helper(&use_world, world, resources);
-							});
-						} else {
-							command_buffer.push((StartSoundEvent {
-								handle: user.error_sound.clone(),
-								entity: Some(entity),
-							},));
+							if let Ok((template_ref, Usable)) = queries.2.get(world, linedef_entity)
+							{
+								let event = UseEvent {
+									entity: linedef_entity,
+									other: entity,
+								};
+								let handle = template_ref.0.clone();
+								command_buffer.exec_mut(move |world, resources| {
+									resources.insert(SpawnContext(event));
+									let asset_storage = <Read<AssetStorage>>::fetch(resources);
+									let use_world = &asset_storage.get(&handle).unwrap().r#use;
+									spawn_helper(&use_world, world, resources);
+								});
+							} else {
+								command_buffer.push((StartSoundEvent {
+									handle: user.error_sound.clone(),
+									entity: Some(entity),
+								},));
+							}
 						}
 					}
 				}
@@ -360,44 +441,143 @@ pub fn player_use(resources: &mut Resources) -> impl Runnable {
 pub fn player_weapon(_resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("player_weapon")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.with_query(<&mut WeaponState>::query())
 		.build(move |_command_buffer, world, resources, query| {
-			let (asset_storage, client) = resources;
-
-			if let Some(weapon_state) = client
-				.entity
-				.and_then(|entity| query.get_mut(world, entity).ok())
-			{
-				if let Some(switch_to) = client
-					.command
-					.weapon
-					.as_ref()
-					.and_then(|name| {
-						let asset_name = format!("{}.weapon", name);
-						asset_storage.handle_for::<WeaponTemplate>(&asset_name)
-					})
-					.as_ref()
-					.filter(|&handle| *handle != weapon_state.current)
-					.map(Clone::clone)
+			let (asset_storage, clients) = resources;
+
+			for client in clients.clients.iter() {
+				if let Some(weapon_state) = client
+					.entity
+					.and_then(|entity| query.get_mut(world, entity).ok())
 				{
-					weapon_state.switch_to = Some(switch_to);
+					if let Some(switch_to) = client
+						.command
+						.weapon
+						.as_ref()
+						.and_then(|name| {
+							let asset_name = format!("{}.weapon", name);
+							asset_storage.handle_for::<WeaponTemplate>(&asset_name)
+						})
+						.as_ref()
+						.filter(|&handle| *handle != weapon_state.current)
+						.map(Clone::clone)
+					{
+						weapon_state.switch_to = Some(switch_to);
+					}
 				}
 			}
 		})
 }
 
+/// Brings dead players back to life in multiplayer games, when they press the use key.
+/// In deathmatch they come back at a random deathmatch start, otherwise at their own start.
+pub fn player_respawn(_resources: &mut Resources) -> impl Runnable {
+	SystemBuilder::new("player_respawn")
+		.read_resource::<Clients>()
+		.read_resource::<GameOptions>()
+		.with_query(<&Health>::query())
+		.build(move |command_buffer, world, resources, query| {
+			let (clients, game_options) = resources;
+
+			if !game_options.is_multiplayer() {
+				return;
+			}
+
+			for (index, client) in clients.clients.iter().enumerate() {
+				let entity = match client.entity {
+					Some(e) => e,
+					None => continue,
+				};
+
+				if !client.command.r#use || client.previous_command.r#use {
+					continue;
+				}
+
+				if !matches!(query.get(world, entity), Ok(health) if health.current <= 0) {
+					continue;
+				}
+
+				let player_num = client.player_num;
+				command_buffer.exec_mut(move |world, resources| {
+					// The corpse stays behind, but the frags go to the new body
+					let frags = match world.entry(entity) {
+						Some(mut entry) => {
+							let frags = entry.get_component::<Frags>().map_or(Frags(0), |&f| f);
+							entry.remove_component::<Frags>();
+							frags
+						}
+						None => Frags(0),
+					};
+
+					let new_entity = match spawn_player(world, resources, player_num) {
+						Ok(x) => x,
+						Err(err) => {
+							log::error!("{:?}", err);
+							return;
+						}
+					};
+
+					let mut entry = world.entry(new_entity).unwrap();
+					entry.add_component(frags);
+					let transform = *entry.get_component::<Transform>().unwrap();
+					<Write<Clients>>::fetch_mut(resources).clients[index].entity = Some(new_entity);
+
+					let fog = <Write<AssetStorage>>::fetch_mut(resources).load("tfog.entity");
+					spawn_entity(world, resources, &fog, transform);
+				});
+			}
+		})
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct PlayerTouch;
 
 pub fn player_touch(resources: &mut Resources) -> impl Runnable {
-	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
 	handler_set.register_clone::<PlayerTouch>();
 
+	registry.register::<AmmoPickup>("AmmoPickup".into());
+	handler_set.register_clone::<AmmoPickup>();
+
+	registry.register::<ArmorPickup>("ArmorPickup".into());
+	handler_set.register_clone::<ArmorPickup>();
+
+	registry.register::<KeyInventory>("KeyInventory".into());
+	handler_set.register_clone::<KeyInventory>();
+
+	registry.register::<KeyPickup>("KeyPickup".into());
+	handler_set.register_clone::<KeyPickup>();
+
+	registry.register::<PowerupPickup>("PowerupPickup".into());
+	handler_set.register_clone::<PowerupPickup>();
+
+	registry.register::<WeaponPickup>("WeaponPickup".into());
+	handler_set.register_clone::<WeaponPickup>();
+
 	SystemBuilder::new("player_touch")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameOptions>()
+		.read_resource::<GameTime>()
+		.write_resource::<LevelStats>()
 		.with_query(<(&TouchEvent, &PlayerTouch)>::query())
 		.with_query(<&mut Camera>::query())
-		.build(move |command_buffer, world, _resources, queries| {
+		.with_query(<&KeyPickup>::query())
+		.with_query(<&mut KeyInventory>::query())
+		.with_query(<&AmmoPickup>::query())
+		.with_query(<&mut WeaponState>::query())
+		.with_query(<&ArmorPickup>::query())
+		.with_query(<&mut Armor>::query())
+		.with_query(<&CountItem>::query())
+		.with_query(<&PowerupPickup>::query())
+		.with_query(<(&mut Health, &mut Powerups)>::query())
+		.with_query(<&WeaponPickup>::query())
+		.with_query(<(&EntityTemplateRef, &Transform)>::query())
+		.with_query(<&mut MapDynamic>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_options, game_time, level_stats) = resources;
 			let (world0, mut world) = world.split_for_query(&queries.0);
 
 			for (&event, PlayerTouch) in queries.0.iter(&world0) {
@@ -416,6 +596,200 @@ pub fn player_touch(resources: &mut Resources) -> impl Runnable {
 						},));
 					}
 				}
+
+				let mut pickup_sound = None;
+				let mut respawns = game_options.deathmatch.is_some();
+				let mut stays = false;
+
+				// Pick up keys
+				if let Ok(key_pickup) = queries.2.get(&world, event.other) {
+					let key_pickup = key_pickup.clone();
+
+					if let Ok(key_inventory) = queries.3.get_mut(&mut world, event.entity) {
+						// In multiplayer, keys stay for the other players to pick up too
+						if key_inventory
+							.keys
+							.insert((key_pickup.color, key_pickup.key_type))
+						{
+							log::info!(
+								"Picked up a {} {}.",
+								key_pickup.color.name(),
+								key_pickup.key_type.name()
+							);
+
+							pickup_sound = Some(key_pickup.sound);
+							stays = game_options.is_multiplayer();
+						} else if !game_options.is_multiplayer() {
+							pickup_sound = Some(key_pickup.sound);
+						}
+					}
+				}
+
+				// Pick up ammo
+				if let Ok(ammo_pickup) = queries.4.get(&world, event.other) {
+					let ammo_pickup = ammo_pickup.clone();
+
+					if let Some(ammo_state) = queries
+						.5
+						.get_mut(&mut world, event.entity)
+						.ok()
+						.and_then(|weapon_state| weapon_state.ammo.get_mut(&ammo_pickup.ammo))
+					{
+						if ammo_state.current < ammo_state.max {
+							let count = ammo_pickup.count * game_options.skill.ammo_multiplier();
+							ammo_state.current =
+								i32::min(ammo_state.current + count, ammo_state.max);
+							log::info!(
+								"Picked up {} {}.",
+								count,
+								asset_storage.get(&ammo_pickup.ammo).unwrap().name
+							);
+
+							pickup_sound = Some(ammo_pickup.sound);
+						}
+					}
+				}
+
+				// Pick up armor
+				if let Ok(armor_pickup) = queries.6.get(&world, event.other) {
+					let armor_pickup = armor_pickup.clone();
+
+					if let Ok(armor) = queries.7.get_mut(&mut world, event.entity) {
+						if armor_pickup.bonus {
+							armor.current =
+								i32::min(armor.current + armor_pickup.amount, MAX_ARMOR);
+							armor.armor_type.get_or_insert(armor_pickup.armor_type);
+							log::info!("Picked up an armor bonus.");
+
+							pickup_sound = Some(armor_pickup.sound);
+						} else if armor.current < armor_pickup.amount {
+							armor.current = armor_pickup.amount;
+							armor.armor_type = Some(armor_pickup.armor_type);
+							log::info!("Picked up the {}.", armor_pickup.armor_type.name());
+
+							pickup_sound = Some(armor_pickup.sound);
+						}
+					}
+				}
+
+				// Pick up powerups
+				if let Ok(powerup_pickup) = queries.9.get(&world, event.other) {
+					let powerup_pickup = powerup_pickup.clone();
+
+					if let Ok((health, powerups)) = queries.10.get_mut(&mut world, event.entity) {
+						powerups.give(powerup_pickup.powerup, **game_time);
+						log::info!("Picked up the {}.", powerup_pickup.powerup.name());
+
+						// Invulnerability and invisibility never come back
+						if matches!(
+							powerup_pickup.powerup,
+							PowerupType::Invulnerability | PowerupType::Invisibility
+						) {
+							respawns = false;
+						}
+
+						// Berserk also heals and switches to the fist
+						if powerup_pickup.powerup == PowerupType::Berserk {
+							health.current = i32::max(health.current, 100);
+
+							if let (Some(fist), Ok(weapon_state)) = (
+								asset_storage.handle_for::<WeaponTemplate>("fist.weapon"),
+								queries.5.get_mut(&mut world, event.entity),
+							) {
+								if weapon_state.current != fist {
+									weapon_state.switch_to = Some(fist);
+								}
+							}
+						}
+
+						// The computer map reveals the whole map
+						if powerup_pickup.powerup == PowerupType::ComputerMap {
+							for map_dynamic in queries.13.iter_mut(&mut world) {
+								for linedef_dynamic in &mut map_dynamic.linedefs {
+									linedef_dynamic.mapped = true;
+								}
+							}
+						}
+
+						pickup_sound = Some(powerup_pickup.sound);
+					}
+				}
+
+				// Pick up weapons
+				if let Ok(weapon_pickup) = queries.11.get(&world, event.other) {
+					let weapon_pickup = weapon_pickup.clone();
+
+					if let Ok(weapon_state) = queries.5.get_mut(&mut world, event.entity) {
+						// In multiplayer, weapons stay on the floor unless playing altdeath
+						let weapon_stays = game_options.is_multiplayer()
+							&& game_options
+								.deathmatch
+								.map_or(true, |deathmatch| !deathmatch.alt_death);
+						let has_weapon = weapon_state.inventory.contains(&weapon_pickup.weapon);
+						let weapon_template = asset_storage.get(&weapon_pickup.weapon).unwrap();
+
+						if !(weapon_stays && has_weapon) {
+							let mut picked_up = false;
+
+							if let Some(ammo_state) = weapon_template
+								.ammo
+								.as_ref()
+								.and_then(|ammo| weapon_state.ammo.get_mut(&ammo.handle))
+							{
+								if ammo_state.current < ammo_state.max {
+									let count = weapon_pickup.ammo_count
+										* game_options.skill.ammo_multiplier();
+									ammo_state.current =
+										i32::min(ammo_state.current + count, ammo_state.max);
+									picked_up = true;
+								}
+							}
+
+							if !has_weapon {
+								weapon_state.inventory.insert(weapon_pickup.weapon.clone());
+								weapon_state.switch_to = Some(weapon_pickup.weapon.clone());
+								log::info!("Picked up the {}.", weapon_template.name);
+								picked_up = true;
+							}
+
+							if picked_up {
+								pickup_sound = Some(weapon_pickup.sound);
+								stays = weapon_stays;
+							}
+						}
+					}
+				}
+
+				if let Some(handle) = pickup_sound {
+					if queries.8.get(&world, event.other).is_ok() {
+						level_stats.items_found += 1;
+					}
+
+					command_buffer.push((StartSoundEvent {
+						handle,
+						entity: Some(event.entity),
+					},));
+
+					if stays {
+						continue;
+					}
+
+					// Items come back after a while in deathmatch
+					if respawns {
+						if let Ok((template_ref, &transform)) = queries.12.get(&world, event.other)
+						{
+							command_buffer.push((
+								ItemRespawn {
+									handle: template_ref.0.clone(),
+									timer: Timer::new(**game_time, ITEM_RESPAWN_TIME),
+								},
+								transform,
+							));
+						}
+					}
+
+					command_buffer.remove(event.other);
+				}
 			}
 		})
 }
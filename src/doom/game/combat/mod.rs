@@ -3,29 +3,36 @@ use crate::{
 		assets::{AssetHandle, AssetStorage},
 		geometry::{angles_to_axes, Angle, Line2, Line3, AABB2, AABB3},
 		quadtree::Quadtree,
+		random::GameRng,
 		spawn::{ComponentAccessor, SpawnContext, SpawnFrom, SpawnMergerHandlerSet},
 	},
 	doom::{
 		assets::template::{EntityTemplate, EntityTemplateRef},
 		data::FRAME_RATE,
 		game::{
+			ai::{Monster, BASE_THRESHOLD},
 			camera::Camera,
+			client::User,
+			deathmatch::Frags,
+			item::{PowerupType, Powerups},
 			map::MapDynamic,
+			options::GameOptions,
 			physics::{BoxCollider, Physics, SolidType, TouchEvent},
 			spawn::spawn_entity,
 			state::{entity::EntityStateEvent, State, StateAction, StateName},
+			stats::{CountKill, LevelStats},
 			trace::EntityTracer,
 			Transform,
 		},
 	},
 };
 use legion::{
-	systems::{ResourceSet, Runnable},
-	Entity, IntoQuery, Read, Registry, Resources, SystemBuilder, Write,
+	systems::{CommandBuffer, ResourceSet, Runnable},
+	Entity, EntityStore, IntoQuery, Read, Registry, Resources, SystemBuilder, Write,
 };
 use nalgebra::{Vector2, Vector3};
 use num_traits::Zero;
-use rand::{distributions::Uniform, thread_rng, Rng};
+use rand::{distributions::Uniform, Rng};
 use serde::{Deserialize, Serialize};
 
 pub mod weapon;
@@ -53,6 +60,41 @@ impl SpawnFrom<HealthDef> for Health {
 	}
 }
 
+/// The most armor points that can be worn.
+pub const MAX_ARMOR: i32 = 200;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ArmorType {
+	Green,
+	Blue,
+}
+
+impl ArmorType {
+	#[inline]
+	pub fn name(self) -> &'static str {
+		match self {
+			ArmorType::Green => "green armor",
+			ArmorType::Blue => "blue armor",
+		}
+	}
+
+	/// Returns how much of the damage is absorbed by this armor, as a divisor.
+	#[inline]
+	pub fn absorption_divisor(self) -> i32 {
+		match self {
+			ArmorType::Green => 3,
+			ArmorType::Blue => 2,
+		}
+	}
+}
+
+/// Component for entities that can wear armor to absorb part of the damage they take.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct Armor {
+	pub current: i32,
+	pub armor_type: Option<ArmorType>,
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct DamageEvent {
 	pub entity: Entity,
@@ -61,36 +103,101 @@ pub struct DamageEvent {
 	pub direction: Vector3<f32>,
 }
 
+/// Component for monsters that other monsters never retaliate against.
+/// They also switch to a new target whenever they are hurt, ignoring the threshold.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct NoRetaliation;
+
 pub fn apply_damage(resources: &mut Resources) -> impl Runnable {
 	let (mut handler_set, mut registry) =
 		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
 
+	registry.register::<Armor>("Armor".into());
+	handler_set.register_clone::<Armor>();
+
 	registry.register::<Health>("Health".into());
 	handler_set.register_spawn::<HealthDef, Health>();
 
+	registry.register::<NoRetaliation>("NoRetaliation".into());
+	handler_set.register_clone::<NoRetaliation>();
+
 	SystemBuilder::new("apply_damage")
 		.read_resource::<AssetStorage>()
+		.write_resource::<GameRng>()
+		.write_resource::<LevelStats>()
 		.with_query(<&DamageEvent>::query())
 		.with_query(<(
 			&EntityTemplateRef,
 			&mut Health,
+			Option<&mut Armor>,
 			Option<&mut Physics>,
+			Option<&mut Monster>,
+			Option<&Powerups>,
 			Option<&mut State>,
+			Option<&CountKill>,
 		)>::query())
+		.with_query(<(&Health, Option<&NoRetaliation>)>::query())
+		.with_query(<&mut Frags>::query())
 		.build(move |command_buffer, world, resources, queries| {
-			let asset_storage = resources;
+			let (asset_storage, game_rng, level_stats) = resources;
 			let (world0, mut world) = world.split_for_query(&queries.0);
 
 			for &event in queries.0.iter(&world0) {
-				if let Ok((template_ref, health, physics, state)) =
-					queries.1.get_mut(&mut world, event.entity)
+				// Monsters retaliate against living attackers, unless they are exempt
+				let provoked = event.source_entity != event.entity
+					&& matches!(
+						queries.2.get(&world, event.source_entity),
+						Ok((source_health, None)) if source_health.current > 0
+					);
+				let ignores_threshold = matches!(
+					queries.2.get(&world, event.entity),
+					Ok((_, Some(NoRetaliation)))
+				);
+				let source_has_health = queries.2.get(&world, event.source_entity).is_ok();
+				let mut killed = false;
+
+				if let Ok((
+					template_ref,
+					health,
+					armor,
+					physics,
+					monster,
+					powerups,
+					mut state,
+					count_kill,
+				)) = queries.1.get_mut(&mut world, event.entity)
 				{
 					// Apply damage
 					if health.current <= 0 {
 						continue;
 					}
 
-					health.current -= event.damage;
+					// Invulnerability protects against everything except telefragging
+					let invulnerable = event.damage < 1000
+						&& powerups.map_or(false, |p| p.has(PowerupType::Invulnerability));
+					let mut damage = if invulnerable { 0 } else { event.damage };
+
+					// Armor absorbs part of the damage, until it runs out
+					if let Some(armor) = armor {
+						if let Some(armor_type) = armor.armor_type {
+							let mut saved = damage / armor_type.absorption_divisor();
+
+							if armor.current <= saved {
+								saved = armor.current;
+								armor.armor_type = None;
+							}
+
+							armor.current -= saved;
+							damage -= saved;
+						}
+					}
+
+					health.current -= damage;
+					killed = health.current <= 0;
+
+					if killed && count_kill.is_some() {
+						level_stats.kills_found += 1;
+					}
 
 					// Push the entity away from the damage source
 					if let Some(physics) = physics {
@@ -105,7 +212,7 @@ pub fn apply_damage(resources: &mut Resources) -> impl Runnable {
 							// Sometimes push the other direction for low damage
 							if health.current < 0
 								&& event.damage < 40 && event.direction[2] > 64.0
-								&& thread_rng().gen_bool(0.5)
+								&& game_rng.gen_bool(0.5)
 							{
 								direction = -direction;
 								thrust *= 4.0;
@@ -115,10 +222,14 @@ pub fn apply_damage(resources: &mut Resources) -> impl Runnable {
 						}
 					}
 
-					// Trigger states
-					if let Some(state) = state {
-						let template = asset_storage.get(&template_ref.0).unwrap();
+					if invulnerable {
+						continue;
+					}
+
+					let template = asset_storage.get(&template_ref.0).unwrap();
 
+					// Trigger states
+					if let Some(state) = &mut state {
 						if health.current <= 0 {
 							if health.current < -health.max
 								&& template.states.contains_key("xdeath")
@@ -134,13 +245,50 @@ pub fn apply_damage(resources: &mut Resources) -> impl Runnable {
 							}
 						} else {
 							if template.states.contains_key("pain")
-								&& thread_rng().gen_bool(health.pain_chance as f64)
+								&& game_rng.gen_bool(health.pain_chance as f64)
 							{
 								let new = (StateName::from("pain").unwrap(), 0);
 								state.action = StateAction::Set(new);
 							}
 						}
 					}
+
+					// Retaliate against the attacker
+					if let Some(monster) = monster {
+						let can_switch = monster.threshold == 0 || ignores_threshold;
+
+						if health.current > 0 && provoked && can_switch {
+							monster.target = Some(event.source_entity);
+							monster.threshold = BASE_THRESHOLD;
+
+							// Wake up if idle
+							if let Some(state) = state {
+								let idle = matches!(
+									state.action,
+									StateAction::Wait((name, _)) if name.as_str() == "spawn"
+								);
+
+								if idle && template.states.contains_key("see") {
+									let new = (StateName::from("see").unwrap(), 0);
+									state.action = StateAction::Set(new);
+								}
+							}
+						}
+					}
+				}
+
+				// Killing another player earns a frag, dying by one's own hand or the
+				// environment loses one
+				if killed && queries.3.get_mut(&mut world, event.entity).is_ok() {
+					if event.source_entity == event.entity || !source_has_health {
+						if let Ok(Frags(frags)) = queries.3.get_mut(&mut world, event.entity) {
+							*frags -= 1;
+						}
+					} else if let Ok(Frags(frags)) =
+						queries.3.get_mut(&mut world, event.source_entity)
+					{
+						*frags += 1;
+					}
 				}
 			}
 		})
@@ -190,9 +338,11 @@ pub fn projectile_touch(resources: &mut Resources) -> impl Runnable {
 	handler_set.register_clone::<ProjectileTouch>();
 
 	SystemBuilder::new("projectile_touch")
+		.write_resource::<GameRng>()
 		.with_query(<(&TouchEvent, &ProjectileTouch)>::query())
 		.with_query(<(&Owner, &mut State)>::query())
-		.build(move |command_buffer, world, _resources, queries| {
+		.with_query(<(&EntityTemplateRef, Option<&User>)>::query())
+		.build(move |command_buffer, world, game_rng, queries| {
 			let (world0, mut world) = world.split_for_query(&queries.0);
 
 			for (event, projectile_touch) in queries.0.iter(&world0) {
@@ -204,9 +354,24 @@ pub fn projectile_touch(resources: &mut Resources) -> impl Runnable {
 						let new = (StateName::from("death").unwrap(), 0);
 						state.action = StateAction::Set(new);
 
+						// Monsters' projectiles don't hurt others of the same species
+						let same_species = match (
+							queries.2.get(&world, source_entity),
+							queries.2.get(&world, event.other),
+						) {
+							(Ok((source_template, _)), Ok((other_template, None))) => {
+								source_template.0 == other_template.0
+							}
+							_ => false,
+						};
+
+						if same_species {
+							continue;
+						}
+
 						// Apply the damage to the other entity
 						let damage = projectile_touch.damage_multiplier
-							* thread_rng().sample(projectile_touch.damage_range);
+							* game_rng.sample(projectile_touch.damage_range);
 
 						command_buffer.push((DamageEvent {
 							entity: event.other,
@@ -236,7 +401,8 @@ pub fn radius_attack(resources: &mut Resources) -> impl Runnable {
 		.with_query(<&MapDynamic>::query())
 		.with_query(<(&EntityStateEvent, &RadiusAttack)>::query())
 		.with_query(<(Option<&BoxCollider>, Option<&Owner>, &Transform)>::query())
-		.with_query(<(&BoxCollider, &Transform)>::query())
+		.read_component::<BoxCollider>() // used by radius_damage
+		.read_component::<Transform>() // used by radius_damage
 		.build(move |command_buffer, world, resources, queries| {
 			let (asset_storage, quadtree) = resources;
 			let map_dynamic = queries.0.iter(world).next().unwrap();
@@ -256,62 +422,93 @@ pub fn radius_attack(resources: &mut Resources) -> impl Runnable {
 					Err(_) => continue,
 				};
 
-				let query = &mut queries.3;
+				let tracer = EntityTracer {
+					map,
+					map_dynamic,
+					quadtree: &quadtree,
+					world,
+				};
 
-				quadtree.traverse_nodes(
-					AABB2::from_radius(radius_attack.radius),
-					Line2::new(midpoint.fixed_resize(0.0), Vector2::zeros()),
-					&mut |entities: &[Entity]| {
-						for &entity in entities {
-							let (box_collider, transform) = match query.get(world, entity) {
-								Ok(x) => x,
-								_ => continue,
-							};
+				radius_damage(
+					command_buffer,
+					&tracer,
+					radius_attack,
+					source_entity,
+					midpoint,
+				);
+			}
+		})
+}
 
-							if !box_collider.blocks_types.blocks(SolidType::PROJECTILE) {
-								continue;
-							}
+/// Damages all shootable entities within the radius of an explosion at `midpoint`,
+/// if they can be seen from it. The damage decreases with distance.
+pub fn radius_damage<W: EntityStore>(
+	command_buffer: &mut CommandBuffer,
+	tracer: &EntityTracer<W>,
+	radius_attack: &RadiusAttack,
+	source_entity: Entity,
+	midpoint: Vector3<f32>,
+) {
+	tracer.quadtree.traverse_nodes(
+		AABB2::from_radius(radius_attack.radius),
+		Line2::new(midpoint.fixed_resize(0.0), Vector2::zeros()),
+		&mut |entities: &[Entity]| {
+			for &entity in entities {
+				let (box_collider, transform) =
+					match <(&BoxCollider, &Transform)>::query().get(tracer.world, entity) {
+						Ok(x) => x,
+						_ => continue,
+					};
 
-							let bbox =
-								AABB3::from_radius_height(box_collider.radius, box_collider.height)
-									.offset(transform.position);
-							let dist_sq = bbox.direction_from(midpoint).norm_squared();
+				if !box_collider.blocks_types.blocks(SolidType::PROJECTILE) {
+					continue;
+				}
 
-							if dist_sq >= radius_attack.radius * radius_attack.radius {
-								continue;
-							}
+				let bbox = AABB3::from_radius_height(box_collider.radius, box_collider.height)
+					.offset(transform.position);
+				let dist_sq = bbox.direction_from(midpoint).norm_squared();
 
-							if map
-								.visible_interval(
-									map_dynamic,
-									Line3::new(midpoint, transform.position - midpoint),
-									box_collider.height,
-								)
-								.is_empty()
-							{
-								continue;
-							}
+				if dist_sq >= radius_attack.radius * radius_attack.radius {
+					continue;
+				}
 
-							// Apply the damage
-							let scale = 1.0 - dist_sq.sqrt() / radius_attack.radius;
+				if tracer
+					.map
+					.visible_interval(
+						tracer.map_dynamic,
+						Line3::new(midpoint, transform.position - midpoint),
+						box_collider.height,
+					)
+					.is_empty()
+				{
+					continue;
+				}
 
-							command_buffer.push((DamageEvent {
-								entity,
-								damage: (radius_attack.damage as f32 * scale) as i32,
-								source_entity,
-								direction: transform.position - midpoint,
-							},));
-						}
+				// Apply the damage
+				let scale = 1.0 - dist_sq.sqrt() / radius_attack.radius;
 
-						Vector2::zeros()
-					},
-				);
+				command_buffer.push((DamageEvent {
+					entity,
+					damage: (radius_attack.damage as f32 * scale) as i32,
+					source_entity,
+					direction: transform.position - midpoint,
+				},));
 			}
-		})
+
+			Vector2::zeros()
+		},
+	);
 }
 
+/// Component for projectiles that move at a different speed when monsters are fast.
+#[derive(Clone, Copy, Debug)]
+pub struct FastProjectileSpeed(pub f32);
+
 #[derive(Clone, Debug)]
-pub struct SpawnProjectile(pub AssetHandle<EntityTemplate>);
+pub struct SpawnProjectile {
+	pub handle: AssetHandle<EntityTemplate>,
+	pub angle: Angle,
+}
 
 pub fn spawn_projectile(resources: &mut Resources) -> impl Runnable {
 	let (mut handler_set, mut registry) =
@@ -320,6 +517,7 @@ pub fn spawn_projectile(resources: &mut Resources) -> impl Runnable {
 	registry.register::<Owner>("Owner".into());
 	handler_set.register_spawn::<OwnerDef, Owner>();
 
+	handler_set.register_clone::<FastProjectileSpeed>();
 	handler_set.register_clone::<SpawnProjectile>();
 
 	SystemBuilder::new("spawn_projectile")
@@ -329,16 +527,26 @@ pub fn spawn_projectile(resources: &mut Resources) -> impl Runnable {
 		.build(move |command_buffer, world, _resources, queries| {
 			let (world0, world) = world.split_for_query(&queries.0);
 
-			for (&event, SpawnProjectile(projectile_handle)) in queries.0.iter(&world0) {
+			for (&event, spawn_projectile) in queries.0.iter(&world0) {
 				if let Ok(&(mut transform)) = queries.1.get(&world, event.entity) {
-					let handle = projectile_handle.clone();
+					let handle = spawn_projectile.handle.clone();
+					transform.rotation[2] += spawn_projectile.angle;
 					let direction = angles_to_axes(transform.rotation)[0];
 					transform.position += direction; // Start a little forward from the spawner
 					transform.position[2] += 32.0;
 
 					command_buffer.exec_mut(move |world, resources| {
 						resources.insert(SpawnContext(Owner(event.entity)));
-						spawn_entity(world, resources, &handle, transform);
+						let entity = spawn_entity(world, resources, &handle, transform);
+
+						if <Read<GameOptions>>::fetch(resources).fast_monsters {
+							if let Ok((&FastProjectileSpeed(speed), physics)) =
+								<(&FastProjectileSpeed, &mut Physics)>::query()
+									.get_mut(world, entity)
+							{
+								physics.velocity = direction * speed;
+							}
+						}
 					});
 				}
 			}
@@ -366,6 +574,7 @@ pub fn spray_attack(resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("spray_attack")
 		.read_resource::<AssetStorage>()
 		.read_resource::<Quadtree>()
+		.write_resource::<GameRng>()
 		.with_query(<&MapDynamic>::query())
 		.with_query(<(&EntityStateEvent, &SprayAttack)>::query())
 		.with_query(<&Owner>::query())
@@ -375,7 +584,7 @@ pub fn spray_attack(resources: &mut Resources) -> impl Runnable {
 		.read_component::<Owner>() // used by EntityTracer
 		.read_component::<Transform>() // used by EntityTracer
 		.build(move |command_buffer, world, resources, queries| {
-			let (asset_storage, quadtree) = resources;
+			let (asset_storage, quadtree, game_rng) = resources;
 
 			let map_dynamic = queries.0.iter(world).next().unwrap();
 			let map = asset_storage.get(&map_dynamic.map).unwrap();
@@ -423,7 +632,7 @@ pub fn spray_attack(resources: &mut Resources) -> impl Runnable {
 					if let Some(collision) = trace.collision {
 						// Apply the damage
 						let damage = spray_attack.damage_multiplier
-							* thread_rng().sample(spray_attack.damage_range);
+							* game_rng.sample(spray_attack.damage_range);
 
 						command_buffer.push((DamageEvent {
 							entity: collision.entity,
@@ -3,6 +3,7 @@ use crate::{
 		assets::{AssetHandle, AssetStorage},
 		geometry::{angles_to_axes, Angle, Line3, AABB3},
 		quadtree::Quadtree,
+		random::GameRng,
 		spawn::{spawn_helper, ComponentAccessor, SpawnContext, SpawnFrom, SpawnMergerHandlerSet},
 		time::{GameTime, Timer},
 	},
@@ -14,9 +15,10 @@ use crate::{
 		draw::{sprite::SpriteRender, wsprite::WeaponSpriteRender},
 		game::{
 			camera::{Camera, MovementBob},
-			client::Client,
+			client::Clients,
 			combat::{DamageEvent, Owner},
-			map::{LinedefRef, MapDynamic, SectorRef},
+			item::{PowerupType, Powerups},
+			map::{sound::noise_alert, LinedefRef, MapDynamic, SectorRef},
 			physics::{BoxCollider, DamageParticle, SolidType},
 			spawn::spawn_entity,
 			state::{entity::EntityStateEvent, State, StateAction, StateName, StateSystemsRun},
@@ -28,12 +30,12 @@ use crate::{
 };
 use legion::{
 	component,
-	systems::{ResourceSet, Runnable},
+	systems::{CommandBuffer, ResourceSet, Runnable},
 	Entity, IntoQuery, Read, Registry, Resources, SystemBuilder, Write,
 };
 use nalgebra::{Vector2, Vector3};
 use num_traits::Zero;
-use rand::{distributions::Uniform, thread_rng, Rng};
+use rand::{distributions::Uniform, Rng};
 use serde::{Deserialize, Serialize};
 use std::{
 	collections::{HashMap, HashSet},
@@ -290,9 +292,14 @@ pub struct LineAttack {
 	pub miss_sound: Option<AssetHandle<Sound>>,
 }
 
+/// Component for line attacks whose damage is multiplied while the attacker has berserk.
+#[derive(Clone, Copy, Debug)]
+pub struct BerserkMultiplier(pub i32);
+
 pub fn line_attack(resources: &mut Resources) -> impl Runnable {
 	let (mut asset_storage, mut handler_set) =
 		<(Write<AssetStorage>, Write<SpawnMergerHandlerSet>)>::fetch_mut(resources);
+	handler_set.register_clone::<BerserkMultiplier>();
 	handler_set.register_clone::<LineAttack>();
 
 	let blood1 = asset_storage.load::<EntityTemplate>("blood1.entity");
@@ -304,8 +311,19 @@ pub fn line_attack(resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("line_attack")
 		.read_resource::<AssetStorage>()
 		.read_resource::<Quadtree>()
-		.with_query(<(&EntityStateEvent, &LineAttack)>::query())
-		.with_query(<(Option<&BoxCollider>, Option<&Owner>, &Transform, &WeaponState)>::query())
+		.write_resource::<GameRng>()
+		.with_query(<(
+			&EntityStateEvent,
+			&LineAttack,
+			Option<&BerserkMultiplier>,
+		)>::query())
+		.with_query(<(
+			Option<&BoxCollider>,
+			Option<&Owner>,
+			Option<&Powerups>,
+			&Transform,
+			Option<&WeaponState>,
+		)>::query())
 		.with_query(<&MapDynamic>::query())
 		.with_query(<(
 			Option<&BoxCollider>,
@@ -316,10 +334,10 @@ pub fn line_attack(resources: &mut Resources) -> impl Runnable {
 		.read_component::<Owner>() // used by EntityTracer
 		.read_component::<Transform>() // used by EntityTracer
 		.build(move |command_buffer, world, resources, queries| {
-			let (asset_storage, quadtree) = resources;
+			let (asset_storage, quadtree, game_rng) = resources;
 
-			for (&event, line_attack) in queries.0.iter(world) {
-				if let Ok((box_collider, owner, transform, weapon_state)) =
+			for (&event, line_attack, berserk_multiplier) in queries.0.iter(world) {
+				if let Ok((box_collider, owner, powerups, transform, weapon_state)) =
 					queries.1.get(world, event.entity)
 				 {
 					let map_dynamic = queries.2.iter(world).next().unwrap();
@@ -345,17 +363,19 @@ pub fn line_attack(resources: &mut Resources) -> impl Runnable {
 
 						// Apply spread if the weapon is shooting inaccurately.
 						// Subtracting two uniform random numbers results in a triangle distribution.
-						if !line_attack.accurate_until_refire || weapon_state.inaccurate {
+						if !line_attack.accurate_until_refire
+							|| weapon_state.map_or(true, |w| w.inaccurate)
+						{
 							if !line_attack.spread[0].is_zero() {
 								rotation[2] +=
-									thread_rng().gen_range(0..line_attack.spread[0].0) -
-									thread_rng().gen_range(0..line_attack.spread[0].0);
+									game_rng.gen_range(0..line_attack.spread[0].0) -
+									game_rng.gen_range(0..line_attack.spread[0].0);
 							}
 
 							if !line_attack.spread[1].is_zero() {
 								rotation[1] +=
-									thread_rng().gen_range(0..line_attack.spread[1].0) -
-									thread_rng().gen_range(0..line_attack.spread[1].0);
+									game_rng.gen_range(0..line_attack.spread[1].0) -
+									game_rng.gen_range(0..line_attack.spread[1].0);
 							}
 						}
 
@@ -370,8 +390,16 @@ pub fn line_attack(resources: &mut Resources) -> impl Runnable {
 						// Hit something!
 						if let Some(collision) = trace.collision {
 							// Apply the damage
-							let damage = line_attack.damage_multiplier
-								* thread_rng().sample(line_attack.damage_range);
+							let mut damage = line_attack.damage_multiplier
+								* game_rng.sample(line_attack.damage_range);
+
+							if let (Some(&BerserkMultiplier(multiplier)), Some(powerups)) =
+								(berserk_multiplier, powerups)
+							{
+								if powerups.has(PowerupType::Berserk) {
+									damage *= multiplier;
+								}
+							}
 
 							command_buffer.push((
 								DamageEvent {
@@ -576,6 +604,22 @@ pub fn weapon_position(resources: &mut Resources) -> impl Runnable {
 		})
 }
 
+/// Wakes up monsters within earshot of a weapon being fired by `entity`.
+fn alert_monsters(command_buffer: &mut CommandBuffer, entity: Entity) {
+	command_buffer.exec_mut(move |world, resources| {
+		let transform = match <&Transform>::query().get(world, entity) {
+			Ok(&x) => x,
+			Err(_) => return,
+		};
+
+		let asset_storage = <Read<AssetStorage>>::fetch(resources);
+		let map_dynamic = <&mut MapDynamic>::query().iter_mut(world).next().unwrap();
+		let map = asset_storage.get(&map_dynamic.map).unwrap();
+		let ssect = map.find_subsector(transform.position.fixed_resize(0.0));
+		noise_alert(map, map_dynamic, ssect.sector_index, entity);
+	});
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct WeaponReady;
 
@@ -585,24 +629,29 @@ pub fn weapon_ready(resources: &mut Resources) -> impl Runnable {
 
 	SystemBuilder::new("weapon_ready")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.with_query(<(&WeaponStateEvent, &WeaponReady)>::query())
 		.with_query(<&mut WeaponState>::query().filter(component::<WeaponState>()))
-		.build(move |_command_buffer, world, resources, queries| {
-			let (asset_storage, client) = resources;
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, clients) = resources;
 			let (world0, mut world) = world.split_for_query(&queries.0);
 
 			for (&event, WeaponReady) in queries.0.iter(&world0) {
+				let attack = clients
+					.for_entity(event.entity)
+					.map_or(false, |client| client.command.attack);
+
 				if let Ok(weapon_state) = queries.1.get_mut(&mut world, event.entity) {
 					if weapon_state.switch_to.is_some() {
 						let state_name = (StateName::from("down").unwrap(), 0);
 						weapon_state.slots[event.slot as usize].action =
 							StateAction::Set(state_name);
-					} else if client.command.attack {
+					} else if attack {
 						if weapon_state.can_fire(&asset_storage) {
 							let state_name = (StateName::from("attack").unwrap(), 0);
 							weapon_state.slots[event.slot as usize].action =
 								StateAction::Set(state_name);
+							alert_monsters(command_buffer, event.entity);
 						}
 					}
 				}
@@ -619,20 +668,25 @@ pub fn weapon_refire(resources: &mut Resources) -> impl Runnable {
 
 	SystemBuilder::new("weapon_refire")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.with_query(<(&WeaponStateEvent, &WeaponReFire)>::query())
 		.with_query(<&mut WeaponState>::query().filter(component::<WeaponState>()))
-		.build(move |_command_buffer, world, resources, queries| {
-			let (asset_storage, client) = resources;
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, clients) = resources;
 			let (world0, mut world) = world.split_for_query(&queries.0);
 
 			for (&event, WeaponReFire) in queries.0.iter(&world0) {
+				let attack = clients
+					.for_entity(event.entity)
+					.map_or(false, |client| client.command.attack);
+
 				if let Ok(weapon_state) = queries.1.get_mut(&mut world, event.entity) {
-					if client.command.attack {
+					if attack {
 						if weapon_state.can_fire(&asset_storage) {
 							let state_name = (StateName::from("attack").unwrap(), 0);
 							weapon_state.slots[event.slot as usize].action =
 								StateAction::Set(state_name);
+							alert_monsters(command_buffer, event.entity);
 							weapon_state.inaccurate = true;
 						} else {
 							weapon_state.inaccurate = false;
@@ -0,0 +1,164 @@
+use crate::{
+	common::{
+		assets::{AssetHandle, AssetStorage},
+		geometry::{AABB2, AABB3},
+		random::GameRng,
+		time::{GameTime, Timer},
+	},
+	doom::{
+		assets::{sound::Sound, template::EntityTemplate},
+		game::{
+			map::{DeathmatchSpawnPoint, MapDynamic},
+			options::GameOptions,
+			physics::BoxCollider,
+			spawn::spawn_entity,
+			Transform,
+		},
+		sound::StartSoundEvent,
+		ui::intermission::start_intermission,
+	},
+};
+use legion::{
+	component,
+	systems::{ResourceSet, Runnable},
+	Entity, EntityStore, IntoQuery, Registry, Resources, SystemBuilder, Write,
+};
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How long it takes for items that were picked up in deathmatch to come back.
+pub const ITEM_RESPAWN_TIME: Duration = Duration::from_secs(30);
+
+/// Component counting the players an entity has killed, minus the times it killed itself.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct Frags(pub i32);
+
+/// Ends the map when the frag limit or the time limit of a deathmatch game is reached.
+pub fn deathmatch_limits(resources: &mut Resources) -> impl Runnable {
+	let mut registry = <Write<Registry<String>>>::fetch_mut(resources);
+	registry.register::<Frags>("Frags".into());
+
+	SystemBuilder::new("deathmatch_limits")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameOptions>()
+		.read_resource::<GameTime>()
+		.with_query(<&Frags>::query())
+		.with_query(<&MapDynamic>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_options, game_time) = resources;
+
+			let deathmatch = match game_options.deathmatch {
+				Some(x) => x,
+				None => return,
+			};
+
+			let frag_limit_reached = deathmatch.frag_limit.map_or(false, |frag_limit| {
+				queries
+					.0
+					.iter(world)
+					.any(|&Frags(frags)| frags >= frag_limit)
+			});
+			let time_limit_reached = deathmatch
+				.time_limit
+				.map_or(false, |time_limit| game_time.0 >= time_limit);
+
+			if !frag_limit_reached && !time_limit_reached {
+				return;
+			}
+
+			let map_dynamic = queries.1.iter(world).next().unwrap();
+			let map = asset_storage.get(&map_dynamic.map).unwrap();
+			let next_map = match &map.exit {
+				Some(exit) => exit.clone(),
+				None => asset_storage.name_for(&map_dynamic.map).unwrap().to_owned(),
+			};
+
+			command_buffer
+				.exec_mut(move |world, resources| start_intermission(&next_map, world, resources));
+		})
+}
+
+/// Picks a random deathmatch start that is not blocked by anything, if there are any.
+/// If every start is blocked, one of them is picked anyway.
+pub fn deathmatch_spawn_point<W: EntityStore>(
+	world: &W,
+	box_collider: &BoxCollider,
+	game_rng: &mut GameRng,
+) -> Option<Transform> {
+	let spawn_points: Vec<Transform> = <&Transform>::query()
+		.filter(component::<DeathmatchSpawnPoint>())
+		.iter(world)
+		.copied()
+		.collect();
+
+	let free: Vec<Transform> = spawn_points
+		.iter()
+		.copied()
+		.filter(|transform| {
+			let bbox = AABB2::from(
+				AABB3::from_radius_height(box_collider.radius, box_collider.height)
+					.offset(transform.position),
+			);
+
+			!<(&BoxCollider, &Transform)>::query().iter(world).any(
+				|(other_box_collider, other_transform)| {
+					let other_bbox = AABB3::from_radius_height(
+						other_box_collider.radius,
+						other_box_collider.height,
+					);
+
+					other_box_collider
+						.blocks_types
+						.blocks(box_collider.solid_type)
+						&& bbox.overlaps(&AABB2::from(other_bbox.offset(other_transform.position)))
+				},
+			)
+		})
+		.collect();
+
+	free.choose(game_rng)
+		.or_else(|| spawn_points.choose(game_rng))
+		.copied()
+}
+
+/// Component for entities that wait to bring back an item that was picked up.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ItemRespawn {
+	pub handle: AssetHandle<EntityTemplate>,
+	pub timer: Timer,
+}
+
+pub fn item_respawn(resources: &mut Resources) -> impl Runnable {
+	let mut registry = <Write<Registry<String>>>::fetch_mut(resources);
+	registry.register::<ItemRespawn>("ItemRespawn".into());
+
+	SystemBuilder::new("item_respawn")
+		.read_resource::<GameTime>()
+		.with_query(<(Entity, &ItemRespawn, &Transform)>::query())
+		.build(move |command_buffer, world, game_time, query| {
+			for (&entity, item_respawn, &transform) in query.iter(world) {
+				if !item_respawn.timer.is_elapsed(**game_time) {
+					continue;
+				}
+
+				let handle = item_respawn.handle.clone();
+				command_buffer.exec_mut(move |world, resources| {
+					let (fog, sound) = {
+						let mut asset_storage = <Write<AssetStorage>>::fetch_mut(resources);
+						(
+							asset_storage.load::<EntityTemplate>("ifog.entity"),
+							asset_storage.load::<Sound>("dsitmbk.sound"),
+						)
+					};
+					let fog_entity = spawn_entity(world, resources, &fog, transform);
+					spawn_entity(world, resources, &handle, transform);
+					world.push((StartSoundEvent {
+						handle: sound,
+						entity: Some(fog_entity),
+					},));
+				});
+				command_buffer.remove(entity);
+			}
+		})
+}
@@ -0,0 +1,518 @@
+//! Recording the commands of a game to a file, and playing them back. Because the game is
+//! deterministic, feeding it the same commands from the same starting point makes it play out
+//! exactly the same way.
+
+use crate::{
+	common::random::GameRng,
+	doom::{
+		game::{client::Clients, net::NetClient, new_game, options::GameOptions, Transform},
+		input::UserCommand,
+	},
+};
+use anyhow::{bail, Context};
+use fnv::FnvHasher;
+use legion::{IntoQuery, Read, Resources, World};
+use rand::{thread_rng, Rng};
+use serde::{Deserialize, Serialize};
+use std::{
+	fs::File,
+	hash::{Hash, Hasher},
+	io::{BufRead, BufReader, BufWriter, Write as _},
+	path::{Path, PathBuf},
+	time::{Duration, Instant},
+};
+
+/// Increased whenever the format of demo files changes, so that old files are rejected.
+const DEMO_VERSION: u32 = 1;
+
+/// Start of a demo file, describing the game that the commands were recorded in.
+/// It is followed by one `DemoTick` for every tick that was played.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct DemoHeader {
+	version: u32,
+	map: String,
+
+	/// The options of the game, including the skill level and the seed of the random number
+	/// generator.
+	options: GameOptions,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct DemoTick {
+	/// Checksum of the world at the start of the tick, to check that playback has not diverged.
+	checksum: u64,
+
+	/// The command of each client, in order.
+	commands: Vec<UserCommand>,
+}
+
+/// Resource for a demo that is being recorded.
+pub struct DemoRecorder {
+	path: PathBuf,
+	file: BufWriter<File>,
+	tick: u32,
+}
+
+/// Resource for a demo that is being played back.
+pub struct DemoPlayer {
+	path: PathBuf,
+	file: BufReader<File>,
+	tick: u32,
+}
+
+/// Starts a new game on `map` and records the commands of its players to a file,
+/// until the game ends or `stop_demo` is called.
+pub fn record_demo(
+	path: &Path,
+	map: &str,
+	mut options: GameOptions,
+	world: &mut World,
+	resources: &mut Resources,
+) {
+	if resources.contains::<NetClient>() {
+		log::error!("Can't record a demo while connected to another game.");
+		return;
+	}
+
+	// The seed must be known to play the demo back
+	options.seed = Some(options.seed.unwrap_or_else(|| thread_rng().gen()));
+
+	let header = DemoHeader {
+		version: DEMO_VERSION,
+		map: map.to_owned(),
+		options,
+	};
+
+	let result = File::create(path)
+		.with_context(|| format!("Couldn't open \"{}\" for writing", path.display()))
+		.and_then(|file| {
+			let mut file = BufWriter::new(file);
+			rmp_serde::encode::write(&mut file, &header).context("Couldn't write demo header")?;
+			Ok(file)
+		});
+
+	match result {
+		Ok(file) => {
+			new_game(map, options, world, resources);
+
+			if resources.contains::<Clients>() {
+				resources.insert(DemoRecorder {
+					path: path.to_owned(),
+					file,
+					tick: 0,
+				});
+				log::info!("Recording demo to \"{}\".", path.display());
+			}
+		}
+		Err(err) => log::error!("{:?}", err),
+	}
+}
+
+/// Starts the game recorded in a demo file, and plays back the recorded commands.
+pub fn play_demo(path: &Path, world: &mut World, resources: &mut Resources) {
+	if resources.contains::<NetClient>() {
+		log::error!("Can't play a demo while connected to another game.");
+		return;
+	}
+
+	let result = File::open(path)
+		.with_context(|| format!("Couldn't open \"{}\" for reading", path.display()))
+		.and_then(|file| {
+			let mut file = BufReader::new(file);
+			let header: DemoHeader =
+				rmp_serde::decode::from_read(&mut file).context("Couldn't read demo header")?;
+
+			if header.version != DEMO_VERSION {
+				bail!(
+					"Demo has version {}, but only version {} is supported",
+					header.version,
+					DEMO_VERSION
+				);
+			}
+
+			Ok((header, file))
+		})
+		.with_context(|| format!("Couldn't play demo \"{}\"", path.display()));
+
+	match result {
+		Ok((header, file)) => {
+			new_game(&header.map, header.options, world, resources);
+
+			if resources.contains::<Clients>() {
+				resources.insert(DemoPlayer {
+					path: path.to_owned(),
+					file,
+					tick: 0,
+				});
+				log::info!("Playing demo \"{}\".", path.display());
+			}
+		}
+		Err(err) => log::error!("{:?}", err),
+	}
+}
+
+/// Resource for a demo being played back as a benchmark. The main loop runs the game as fast
+/// as it can instead of at the normal rate, and reports the time each frame took.
+pub struct Timedemo {
+	json_path: Option<PathBuf>,
+	start: Option<Instant>,
+	tics: u32,
+	input_times: Vec<Duration>,
+	update_times: Vec<Duration>,
+	output_times: Vec<Duration>,
+}
+
+impl Timedemo {
+	/// Adds the time that each schedule of a frame took. `update` is `None` if there was no game
+	/// tick during the frame.
+	pub fn add_frame(
+		&mut self,
+		start: Instant,
+		input: Duration,
+		update: Option<Duration>,
+		output: Duration,
+	) {
+		self.start.get_or_insert(start);
+		self.input_times.push(input);
+		self.output_times.push(output);
+
+		if let Some(update) = update {
+			self.tics += 1;
+			self.update_times.push(update);
+		}
+	}
+}
+
+#[derive(Clone, Debug, Serialize)]
+struct TimedemoReport {
+	tics: u32,
+	frames: usize,
+
+	/// In seconds.
+	wall_time: f64,
+
+	input: ScheduleTimes,
+	update: ScheduleTimes,
+	output: ScheduleTimes,
+}
+
+/// Statistics of the time taken by one schedule, in milliseconds.
+#[derive(Clone, Copy, Debug, Default, Serialize)]
+struct ScheduleTimes {
+	average: f64,
+	p50: f64,
+	p90: f64,
+	p99: f64,
+	max: f64,
+}
+
+impl ScheduleTimes {
+	fn new(times: &mut [Duration]) -> ScheduleTimes {
+		if times.is_empty() {
+			return ScheduleTimes::default();
+		}
+
+		times.sort_unstable();
+		let percentile = |p: f64| {
+			let index = ((times.len() - 1) as f64 * p).round() as usize;
+			times[index].as_secs_f64() * 1000.0
+		};
+
+		ScheduleTimes {
+			average: times.iter().sum::<Duration>().as_secs_f64() * 1000.0 / times.len() as f64,
+			p50: percentile(0.5),
+			p90: percentile(0.9),
+			p99: percentile(0.99),
+			max: percentile(1.0),
+		}
+	}
+}
+
+/// Plays back a demo as fast as possible, and reports how long it took. If `json_path` is
+/// given, the results are also written to that file as JSON.
+pub fn timedemo(
+	path: &Path,
+	json_path: Option<&Path>,
+	world: &mut World,
+	resources: &mut Resources,
+) {
+	play_demo(path, world, resources);
+
+	if resources.contains::<DemoPlayer>() {
+		resources.insert(Timedemo {
+			json_path: json_path.map(Path::to_owned),
+			start: None,
+			tics: 0,
+			input_times: Vec::new(),
+			update_times: Vec::new(),
+			output_times: Vec::new(),
+		});
+	}
+}
+
+/// Reports the results of the running timedemo, and ends it.
+pub fn finish_timedemo(resources: &mut Resources) {
+	let mut timedemo = match resources.remove::<Timedemo>() {
+		Some(x) => x,
+		None => return,
+	};
+
+	let report = TimedemoReport {
+		tics: timedemo.tics,
+		frames: timedemo.input_times.len(),
+		wall_time: timedemo
+			.start
+			.map_or(0.0, |start| start.elapsed().as_secs_f64()),
+		input: ScheduleTimes::new(&mut timedemo.input_times),
+		update: ScheduleTimes::new(&mut timedemo.update_times),
+		output: ScheduleTimes::new(&mut timedemo.output_times),
+	};
+
+	log::info!(
+		"Timedemo: {} tics, {} frames in {:.3} seconds ({:.1} fps)",
+		report.tics,
+		report.frames,
+		report.wall_time,
+		report.frames as f64 / report.wall_time,
+	);
+	log::info!(
+		"{:<8} {:>8} {:>8} {:>8} {:>8} {:>8}",
+		"ms",
+		"avg",
+		"p50",
+		"p90",
+		"p99",
+		"max"
+	);
+
+	for (name, times) in [
+		("input", &report.input),
+		("update", &report.update),
+		("output", &report.output),
+	] {
+		log::info!(
+			"{:<8} {:>8.3} {:>8.3} {:>8.3} {:>8.3} {:>8.3}",
+			name,
+			times.average,
+			times.p50,
+			times.p90,
+			times.p99,
+			times.max,
+		);
+	}
+
+	if let Some(json_path) = &timedemo.json_path {
+		let result = File::create(json_path)
+			.with_context(|| format!("Couldn't open \"{}\" for writing", json_path.display()))
+			.and_then(|file| {
+				serde_json::to_writer_pretty(BufWriter::new(file), &report)
+					.with_context(|| format!("Couldn't write \"{}\"", json_path.display()))
+			});
+
+		if let Err(err) = result {
+			log::error!("{:?}", err);
+		}
+	}
+}
+
+/// Stops recording or playing back a demo.
+pub fn stop_demo(resources: &mut Resources) {
+	if let Some(mut demo_recorder) = resources.remove::<DemoRecorder>() {
+		if let Err(err) = demo_recorder.file.flush() {
+			log::error!("Couldn't write demo: {}", err);
+		}
+
+		log::info!(
+			"Recorded {} ticks to \"{}\".",
+			demo_recorder.tick,
+			demo_recorder.path.display()
+		);
+	} else if let Some(demo_player) = resources.remove::<DemoPlayer>() {
+		log::info!(
+			"Stopped playing demo \"{}\" after {} ticks.",
+			demo_player.path.display(),
+			demo_player.tick
+		);
+	}
+}
+
+/// Records the commands of the current tick, or replaces them with the ones from the demo being
+/// played back. This must run right after the commands have been read from the input.
+pub fn demo_commands(world: &mut World, resources: &mut Resources) {
+	if let Some(mut demo_recorder) = resources.remove::<DemoRecorder>() {
+		let demo_tick = DemoTick {
+			checksum: world_checksum(world, resources),
+			commands: <Read<Clients>>::fetch(resources)
+				.clients
+				.iter()
+				.map(|client| client.command.clone())
+				.collect(),
+		};
+
+		let result = rmp_serde::encode::write(&mut demo_recorder.file, &demo_tick)
+			.map_err(anyhow::Error::from)
+			.and_then(|_| Ok(demo_recorder.file.flush()?))
+			.context("Couldn't write demo");
+		demo_recorder.tick += 1;
+		resources.insert(demo_recorder);
+
+		if let Err(err) = result {
+			log::error!("{:?}", err);
+			stop_demo(resources);
+		}
+	} else if let Some(mut demo_player) = resources.remove::<DemoPlayer>() {
+		let result = demo_player
+			.file
+			.fill_buf()
+			.map(|buf| buf.is_empty())
+			.map_err(anyhow::Error::from)
+			.and_then(|at_end| {
+				if at_end {
+					return Ok(None);
+				}
+
+				let demo_tick: DemoTick = rmp_serde::decode::from_read(&mut demo_player.file)?;
+				Ok(Some(demo_tick))
+			})
+			.context("Couldn't read demo");
+		let tick = demo_player.tick;
+		demo_player.tick += 1;
+		resources.insert(demo_player);
+
+		match result {
+			Ok(Some(demo_tick)) => {
+				if demo_tick.checksum != world_checksum(world, resources) {
+					log::error!(
+						"Demo playback diverged from the recording at tick {}.",
+						tick
+					);
+					stop_demo(resources);
+					return;
+				}
+
+				let mut clients = resources.get_mut::<Clients>().unwrap();
+
+				for (client, command) in clients.clients.iter_mut().zip(demo_tick.commands) {
+					client.command = command;
+				}
+			}
+			Ok(None) => {
+				log::info!("Demo finished.");
+				stop_demo(resources);
+			}
+			Err(err) => {
+				log::error!("{:?}", err);
+				stop_demo(resources);
+			}
+		}
+	}
+}
+
+/// Sums up the state of the world that matters to the simulation. The order that entities are
+/// stored in does not affect the result. FNV is used because, unlike the standard library's
+/// hasher, its output never changes, so demos stay valid across compiler versions.
+pub fn world_checksum(world: &World, resources: &Resources) -> u64 {
+	let mut checksum = resources.get::<GameRng>().map_or(0, |game_rng| {
+		let mut hasher = FnvHasher::default();
+		game_rng.hash(&mut hasher);
+		hasher.finish()
+	});
+
+	for transform in <&Transform>::query().iter(world) {
+		let mut hasher = FnvHasher::default();
+
+		for i in 0..3 {
+			transform.position[i].to_bits().hash(&mut hasher);
+			transform.rotation[i].0.hash(&mut hasher);
+		}
+
+		checksum = checksum.wrapping_add(hasher.finish());
+	}
+
+	checksum
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::doom::testing::{self, DOOR_MAP};
+	use std::{env::temp_dir, fs::remove_file, process};
+
+	const TICKS: usize = 70;
+
+	fn demo_path(name: &str) -> PathBuf {
+		temp_dir().join(format!("ferret-test-{}-{}.demo", process::id(), name))
+	}
+
+	/// Records the player walking up to the door, and returns the checksum of the world at the
+	/// end of the recording.
+	fn record(path: &Path) -> u64 {
+		let (mut world, mut resources) = testing::init();
+		let mut update_systems = testing::update_systems(&mut resources);
+		let options = GameOptions {
+			players: 1,
+			..GameOptions::default()
+		};
+		record_demo(path, DOOR_MAP, options, &mut world, &mut resources);
+		assert!(resources.contains::<DemoRecorder>());
+		testing::walk_forward(&mut resources);
+
+		for _ in 0..TICKS {
+			update_systems.execute(&mut world, &mut resources);
+		}
+
+		stop_demo(&mut resources);
+		world_checksum(&world, &resources)
+	}
+
+	#[test]
+	fn playback_reproduces_recording() {
+		let path = demo_path("playback");
+		let checksum = record(&path);
+
+		let (mut world, mut resources) = testing::init();
+		let mut update_systems = testing::update_systems(&mut resources);
+		play_demo(&path, &mut world, &mut resources);
+
+		for tick in 0..TICKS {
+			update_systems.execute(&mut world, &mut resources);
+			assert!(
+				resources.contains::<DemoPlayer>(),
+				"Playback stopped at tick {}",
+				tick
+			);
+		}
+
+		assert_eq!(world_checksum(&world, &resources), checksum);
+
+		// There are no more commands on the next tick
+		update_systems.execute(&mut world, &mut resources);
+		assert!(!resources.contains::<DemoPlayer>());
+
+		remove_file(&path).ok();
+	}
+
+	#[test]
+	fn changed_world_stops_playback() {
+		let path = demo_path("diverge");
+		record(&path);
+
+		let (mut world, mut resources) = testing::init();
+		let mut update_systems = testing::update_systems(&mut resources);
+		play_demo(&path, &mut world, &mut resources);
+		update_systems.execute(&mut world, &mut resources);
+		assert!(resources.contains::<DemoPlayer>());
+
+		// Move the player somewhere the recording didn't
+		let entity = resources.get::<Clients>().unwrap().local_entity().unwrap();
+		<&mut Transform>::query()
+			.get_mut(&mut world, entity)
+			.unwrap()
+			.position[1] += 1.0;
+
+		update_systems.execute(&mut world, &mut resources);
+		assert!(!resources.contains::<DemoPlayer>());
+
+		remove_file(&path).ok();
+	}
+}
@@ -0,0 +1,219 @@
+use crate::{
+	common::{
+		assets::AssetHandle,
+		spawn::SpawnMergerHandlerSet,
+		time::{GameTime, Timer},
+	},
+	doom::{
+		assets::{
+			sound::Sound,
+			template::{AmmoTemplate, WeaponTemplate},
+		},
+		draw::sprite::SpriteFuzz,
+		game::combat::ArmorType,
+	},
+};
+use legion::{
+	systems::{ResourceSet, Runnable},
+	Entity, IntoQuery, Registry, Resources, SystemBuilder, Write,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::{HashMap, HashSet},
+	time::Duration,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyColor {
+	Blue,
+	Yellow,
+	Red,
+}
+
+impl KeyColor {
+	#[inline]
+	pub fn name(self) -> &'static str {
+		match self {
+			KeyColor::Blue => "blue",
+			KeyColor::Yellow => "yellow",
+			KeyColor::Red => "red",
+		}
+	}
+}
+
+/// Keys come in two variants, but either variant of a given color opens the same locks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum KeyType {
+	Card,
+	Skull,
+}
+
+impl KeyType {
+	#[inline]
+	pub fn name(self) -> &'static str {
+		match self {
+			KeyType::Card => "keycard",
+			KeyType::Skull => "skull key",
+		}
+	}
+}
+
+/// Component for entities that can carry keys.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct KeyInventory {
+	pub keys: HashSet<(KeyColor, KeyType)>,
+}
+
+impl KeyInventory {
+	/// Returns whether the inventory contains a key of the given color, of either type.
+	#[inline]
+	pub fn has_color(&self, color: KeyColor) -> bool {
+		self.keys.iter().any(|&(c, _)| c == color)
+	}
+}
+
+/// Component for items that add a key to the inventory of the player that touches them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KeyPickup {
+	pub color: KeyColor,
+	pub key_type: KeyType,
+	pub sound: AssetHandle<Sound>,
+}
+
+/// Component for items that add ammo to the inventory of the player that touches them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AmmoPickup {
+	pub ammo: AssetHandle<AmmoTemplate>,
+	pub count: i32,
+	pub sound: AssetHandle<Sound>,
+}
+
+/// Component for items that give a weapon to the player that touches them,
+/// along with `ammo_count` of the ammo it uses.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct WeaponPickup {
+	pub weapon: AssetHandle<WeaponTemplate>,
+	pub ammo_count: i32,
+	pub sound: AssetHandle<Sound>,
+}
+
+/// Component for items that give armor to the player that touches them.
+///
+/// Bonuses add `amount` to the current armor points, up to the maximum.
+/// Other armor is only picked up if the player has fewer than `amount` armor points,
+/// and replaces the current armor.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ArmorPickup {
+	pub armor_type: ArmorType,
+	pub amount: i32,
+	pub bonus: bool,
+	pub sound: AssetHandle<Sound>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum PowerupType {
+	Invulnerability,
+	Berserk,
+	Invisibility,
+	RadiationSuit,
+	ComputerMap,
+	LightAmp,
+}
+
+impl PowerupType {
+	#[inline]
+	pub fn name(self) -> &'static str {
+		match self {
+			PowerupType::Invulnerability => "invulnerability",
+			PowerupType::Berserk => "berserk pack",
+			PowerupType::Invisibility => "partial invisibility",
+			PowerupType::RadiationSuit => "radiation shielding suit",
+			PowerupType::ComputerMap => "computer area map",
+			PowerupType::LightAmp => "light amplification visor",
+		}
+	}
+
+	/// Returns how long the powerup lasts, or `None` if it lasts until the end of the map.
+	#[inline]
+	pub fn duration(self) -> Option<Duration> {
+		match self {
+			PowerupType::Invulnerability => Some(Duration::from_secs(30)),
+			PowerupType::Invisibility => Some(Duration::from_secs(60)),
+			PowerupType::RadiationSuit => Some(Duration::from_secs(60)),
+			PowerupType::LightAmp => Some(Duration::from_secs(120)),
+			PowerupType::Berserk | PowerupType::ComputerMap => None,
+		}
+	}
+}
+
+/// Component for entities that can receive powerups, holding the ones that are active.
+/// Powerups that last until the end of the map have no timer.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Powerups {
+	pub active: HashMap<PowerupType, Option<Timer>>,
+}
+
+impl Powerups {
+	#[inline]
+	pub fn has(&self, powerup: PowerupType) -> bool {
+		self.active.contains_key(&powerup)
+	}
+
+	/// Activates a powerup, or restarts it if it was already active.
+	#[inline]
+	pub fn give(&mut self, powerup: PowerupType, game_time: GameTime) {
+		let timer = powerup
+			.duration()
+			.map(|duration| Timer::new(game_time, duration));
+		self.active.insert(powerup, timer);
+	}
+
+	/// Returns whether the visual effect of a powerup should be shown.
+	/// The effect blinks during the last few seconds, to warn that the powerup is running out.
+	#[inline]
+	pub fn shows_effect(&self, powerup: PowerupType, game_time: GameTime) -> bool {
+		match self.active.get(&powerup) {
+			Some(Some(timer)) => {
+				let remaining = timer.remaining(game_time);
+				remaining > Duration::from_secs(4) || remaining.subsec_millis() % 500 >= 250
+			}
+			Some(None) => true,
+			None => false,
+		}
+	}
+}
+
+/// Component for items that give a powerup to the player that touches them.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PowerupPickup {
+	pub powerup: PowerupType,
+	pub sound: AssetHandle<Sound>,
+}
+
+pub fn powerups(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<Powerups>("Powerups".into());
+	handler_set.register_clone::<Powerups>();
+
+	SystemBuilder::new("powerups")
+		.read_resource::<GameTime>()
+		.with_query(<(Entity, &mut Powerups, Option<&SpriteFuzz>)>::query())
+		.build(move |command_buffer, world, game_time, query| {
+			for (&entity, powerups, sprite_fuzz) in query.iter_mut(world) {
+				powerups
+					.active
+					.retain(|_, timer| timer.map_or(true, |timer| !timer.is_elapsed(**game_time)));
+
+				// Partially invisible entities are drawn with the fuzz effect
+				let invisible = powerups.has(PowerupType::Invisibility);
+
+				if invisible && sprite_fuzz.is_none() {
+					command_buffer.add_component(entity, SpriteFuzz);
+				} else if !invisible && sprite_fuzz.is_some() {
+					command_buffer.remove_component::<SpriteFuzz>(entity);
+				}
+			}
+		})
+}
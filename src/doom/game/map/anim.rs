@@ -1,6 +1,7 @@
 use crate::{
 	common::{
 		assets::AssetStorage,
+		random::GameRng,
 		spawn::{ComponentAccessor, SpawnFrom, SpawnMergerHandlerSet},
 		time::{DeltaTime, GameTime, Timer},
 	},
@@ -14,7 +15,7 @@ use legion::{
 	IntoQuery, Read, Registry, Resources, SystemBuilder, Write,
 };
 use nalgebra::Vector2;
-use rand::{thread_rng, Rng};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 
@@ -54,6 +55,7 @@ impl SpawnFrom<LightFlashDef> for LightFlash {
 		resources: &Resources,
 	) -> LightFlash {
 		let game_time = <Read<GameTime>>::fetch(resources);
+		let mut game_rng = resources.get_mut::<GameRng>().unwrap();
 
 		let LightFlashDef {
 			mut flash_type,
@@ -62,11 +64,9 @@ impl SpawnFrom<LightFlashDef> for LightFlash {
 		} = component.clone();
 
 		let time = match flash_type {
-			LightFlashType::Broken => on_time * (thread_rng().gen::<bool>() as u32) + FRAME_TIME,
+			LightFlashType::Broken => on_time * (game_rng.gen::<bool>() as u32) + FRAME_TIME,
 			LightFlashType::Strobe => on_time,
-			LightFlashType::StrobeUnSync(time) => {
-				time.mul_f64(thread_rng().gen::<f64>()) + FRAME_TIME
-			}
+			LightFlashType::StrobeUnSync(time) => time.mul_f64(game_rng.gen::<f64>()) + FRAME_TIME,
 		};
 
 		if let LightFlashType::StrobeUnSync(_) = flash_type {
@@ -93,10 +93,11 @@ pub fn light_flash(resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("light_flash")
 		.read_resource::<AssetStorage>()
 		.read_resource::<GameTime>()
+		.write_resource::<GameRng>()
 		.with_query(<(&SectorRef, &mut LightFlash)>::query())
 		.with_query(<&mut MapDynamic>::query())
 		.build(move |_command_buffer, world, resources, queries| {
-			let (asset_storage, game_time) = resources;
+			let (asset_storage, game_time, game_rng) = resources;
 			let (mut world0, mut world) = world.split_for_query(&queries.0);
 
 			for (sector_ref, mut light_flash) in queries.0.iter_mut(&mut world0) {
@@ -124,11 +125,10 @@ pub fn light_flash(resources: &mut Resources) -> impl Runnable {
 						LightFlashType::Broken => {
 							if light_flash.state {
 								sector_dynamic.light_level = max_light;
-								light_flash.on_time * (thread_rng().gen::<bool>() as u32)
-									+ FRAME_TIME
+								light_flash.on_time * (game_rng.gen::<bool>() as u32) + FRAME_TIME
 							} else {
 								sector_dynamic.light_level = min_light;
-								light_flash.off_time.mul_f64(thread_rng().gen::<f64>()) + FRAME_TIME
+								light_flash.off_time.mul_f64(game_rng.gen::<f64>()) + FRAME_TIME
 							}
 						}
 						LightFlashType::Strobe => {
@@ -0,0 +1,399 @@
+use crate::{
+	common::{
+		assets::{AssetHandle, AssetStorage},
+		spawn::SpawnMergerHandlerSet,
+		time::{GameTime, Timer},
+	},
+	doom::{
+		assets::sound::Sound,
+		game::{
+			client::{Usable, UseEvent},
+			map::{
+				sector_move::{
+					CeilingMove, CrushParams, SectorCrush, SectorMove, SectorMoveEvent,
+					SectorMoveEventType,
+				},
+				switch::{self, SwitchActive, SwitchParams},
+				LinedefRef, Map, MapDynamic,
+			},
+			physics::{TouchEvent, Touchable},
+		},
+		sound::StartSoundEvent,
+	},
+};
+use legion::{
+	component,
+	systems::{CommandBuffer, ResourceSet, Runnable},
+	EntityStore, IntoQuery, Registry, Resources, SystemBuilder, Write,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CeilingActive {
+	pub speed: f32,
+	pub crush_speed: f32,
+	pub perpetual: bool,
+	pub finish_sound: Option<AssetHandle<Sound>>,
+
+	pub low_height: f32,
+	pub high_height: f32,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CeilingParams {
+	pub speed: f32,
+	pub crush_speed: f32,
+	pub target_height_base: CeilingTargetHeight,
+	pub target_height_offset: f32,
+	pub move_sound: Option<AssetHandle<Sound>>,
+	pub move_sound_time: Duration,
+	pub finish_sound: Option<AssetHandle<Sound>>,
+	pub crush: Option<CrushParams>,
+
+	/// Move back and forth between the target and the starting height until stopped.
+	pub perpetual: bool,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum CeilingTargetHeight {
+	Floor,
+}
+
+pub fn ceiling_active(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<CeilingActive>("CeilingActive".into());
+	handler_set.register_clone::<CeilingActive>();
+
+	SystemBuilder::new("ceiling_active")
+		.with_query(<&SectorMoveEvent>::query())
+		.with_query(<(&mut CeilingMove, &mut CeilingActive)>::query())
+		.build(move |command_buffer, world, _resources, queries| {
+			let (mut world1, world) = world.split_for_query(&queries.1);
+
+			for event in queries.0.iter(&world).filter(|e| e.normal == -1.0) {
+				let (ceiling_move, ceiling_active) =
+					match queries.1.get_mut(&mut world1, event.entity) {
+						Ok(x) => x,
+						_ => continue,
+					};
+
+				let sector_move = &mut ceiling_move.0;
+
+				if sector_move.velocity == 0.0 {
+					continue;
+				}
+
+				match event.event_type {
+					SectorMoveEventType::Collided => {
+						// Keep pushing down at crush speed until the obstruction is gone
+						if sector_move.velocity < 0.0 {
+							sector_move.velocity = -ceiling_active.crush_speed;
+						}
+					}
+					SectorMoveEventType::TargetReached => {
+						if let Some(sound) = &ceiling_active.finish_sound {
+							command_buffer.push((StartSoundEvent {
+								handle: sound.clone(),
+								entity: Some(event.entity),
+							},));
+						}
+
+						if ceiling_active.perpetual {
+							if sector_move.target == ceiling_active.low_height {
+								sector_move.velocity = ceiling_active.speed;
+								sector_move.target = ceiling_active.high_height;
+							} else {
+								sector_move.velocity = -ceiling_active.speed;
+								sector_move.target = ceiling_active.low_height;
+							}
+						} else {
+							command_buffer.remove_component::<CeilingMove>(event.entity);
+							command_buffer.remove_component::<CeilingActive>(event.entity);
+							command_buffer.remove_component::<SectorCrush>(event.entity);
+						}
+					}
+				}
+			}
+		})
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CeilingSwitchUse {
+	pub params: CeilingParams,
+	pub switch_params: SwitchParams,
+}
+
+pub fn ceiling_switch_use(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<CeilingSwitchUse>("CeilingSwitchUse".into());
+	handler_set.register_clone::<CeilingSwitchUse>();
+
+	SystemBuilder::new("ceiling_switch_use")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameTime>()
+		.with_query(<(&UseEvent, &CeilingSwitchUse)>::query())
+		.with_query(<&LinedefRef>::query().filter(!component::<SwitchActive>()))
+		.with_query(<&mut MapDynamic>::query())
+		.read_component::<CeilingActive>() // used by activate_with_tag
+		.read_component::<CeilingMove>() // used by activate_with_tag
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_time) = resources;
+			let (mut world2, world) = world.split_for_query(&queries.2);
+
+			for (event, ceiling_switch_use) in queries.0.iter(&world) {
+				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
+					let map_dynamic = queries
+						.2
+						.get_mut(&mut world2, linedef_ref.map_entity)
+						.unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let linedef = &map.linedefs[linedef_ref.index];
+
+					let activated = activate_with_tag(
+						&ceiling_switch_use.params,
+						command_buffer,
+						**game_time,
+						linedef.sector_tag,
+						&world,
+						map,
+						map_dynamic,
+					);
+
+					if activated {
+						switch::activate(
+							&ceiling_switch_use.switch_params,
+							command_buffer,
+							**game_time,
+							linedef_ref.index,
+							map,
+							map_dynamic,
+						);
+
+						if ceiling_switch_use.switch_params.retrigger_time.is_none() {
+							command_buffer.remove_component::<Usable>(event.entity);
+						}
+					}
+				}
+			}
+		})
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CeilingLinedefTouch {
+	pub params: CeilingParams,
+	pub retrigger: bool,
+}
+
+pub fn ceiling_linedef_touch(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<CeilingLinedefTouch>("CeilingLinedefTouch".into());
+	handler_set.register_clone::<CeilingLinedefTouch>();
+
+	SystemBuilder::new("ceiling_linedef_touch")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameTime>()
+		.with_query(<(&TouchEvent, &CeilingLinedefTouch)>::query())
+		.with_query(<&LinedefRef>::query())
+		.with_query(<&mut MapDynamic>::query())
+		.read_component::<CeilingActive>() // used by activate_with_tag
+		.read_component::<CeilingMove>() // used by activate_with_tag
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_time) = resources;
+			let (mut world2, world) = world.split_for_query(&queries.2);
+
+			for (event, ceiling_linedef_touch) in queries.0.iter(&world) {
+				if event.collision.is_some() {
+					continue;
+				}
+
+				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
+					let map_dynamic = queries
+						.2
+						.get_mut(&mut world2, linedef_ref.map_entity)
+						.unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let linedef = &map.linedefs[linedef_ref.index];
+
+					if activate_with_tag(
+						&ceiling_linedef_touch.params,
+						command_buffer,
+						**game_time,
+						linedef.sector_tag,
+						&world,
+						map,
+						map_dynamic,
+					) {
+						if !ceiling_linedef_touch.retrigger {
+							command_buffer.remove_component::<Touchable>(event.entity);
+						}
+					}
+				}
+			}
+		})
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CeilingStopLinedefTouch {
+	pub retrigger: bool,
+}
+
+pub fn ceiling_stop_linedef_touch(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<CeilingStopLinedefTouch>("CeilingStopLinedefTouch".into());
+	handler_set.register_clone::<CeilingStopLinedefTouch>();
+
+	SystemBuilder::new("ceiling_stop_linedef_touch")
+		.read_resource::<AssetStorage>()
+		.with_query(<(&TouchEvent, &CeilingStopLinedefTouch)>::query())
+		.with_query(<&LinedefRef>::query())
+		.with_query(<&MapDynamic>::query())
+		.with_query(<(&mut CeilingMove, &CeilingActive)>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let asset_storage = resources;
+			let (mut world3, world) = world.split_for_query(&queries.3);
+
+			for (event, ceiling_stop_linedef_touch) in queries.0.iter(&world) {
+				if event.collision.is_some() {
+					continue;
+				}
+
+				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
+					let map_dynamic = queries.2.get(&world, linedef_ref.map_entity).unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let linedef = &map.linedefs[linedef_ref.index];
+
+					// Put all perpetual ceilings with the same tag in stasis
+					for (sector_index, _) in map
+						.sectors
+						.iter()
+						.enumerate()
+						.filter(|(_, s)| s.sector_tag == linedef.sector_tag)
+					{
+						let sector_entity = map_dynamic.sectors[sector_index].entity;
+
+						if let Ok((ceiling_move, ceiling_active)) =
+							queries.3.get_mut(&mut world3, sector_entity)
+						{
+							if ceiling_active.perpetual {
+								ceiling_move.0.velocity = 0.0;
+							}
+						}
+					}
+
+					if !ceiling_stop_linedef_touch.retrigger {
+						command_buffer.remove_component::<Touchable>(event.entity);
+					}
+				}
+			}
+		})
+}
+
+fn activate(
+	params: &CeilingParams,
+	command_buffer: &mut CommandBuffer,
+	game_time: GameTime,
+	sector_index: usize,
+	map_dynamic: &MapDynamic,
+) {
+	let sector_dynamic = &map_dynamic.sectors[sector_index];
+
+	let target = match params.target_height_base {
+		CeilingTargetHeight::Floor => sector_dynamic.interval.min + params.target_height_offset,
+	};
+
+	let direction = if target < sector_dynamic.interval.max {
+		-1.0
+	} else {
+		1.0
+	};
+
+	command_buffer.add_component(
+		sector_dynamic.entity,
+		CeilingMove(SectorMove {
+			velocity: direction * params.speed,
+			target,
+			sound: params.move_sound.clone(),
+			sound_timer: Timer::new_elapsed(game_time, params.move_sound_time),
+		}),
+	);
+
+	command_buffer.add_component(
+		sector_dynamic.entity,
+		CeilingActive {
+			speed: params.speed,
+			crush_speed: params.crush_speed,
+			perpetual: params.perpetual,
+			finish_sound: params.finish_sound.clone(),
+
+			low_height: f32::min(target, sector_dynamic.interval.max),
+			high_height: f32::max(target, sector_dynamic.interval.max),
+		},
+	);
+
+	if let Some(crush) = &params.crush {
+		command_buffer.add_component(sector_dynamic.entity, SectorCrush::new(crush, game_time));
+	}
+}
+
+fn activate_with_tag<W: EntityStore>(
+	params: &CeilingParams,
+	command_buffer: &mut CommandBuffer,
+	game_time: GameTime,
+	sector_tag: u16,
+	world: &W,
+	map: &Map,
+	map_dynamic: &MapDynamic,
+) -> bool {
+	let mut activated = false;
+
+	// Activate all the ceilings with the same tag
+	for (sector_index, _) in map
+		.sectors
+		.iter()
+		.enumerate()
+		.filter(|(_, s)| s.sector_tag == sector_tag)
+	{
+		let sector_entity = map_dynamic.sectors[sector_index].entity;
+		let entry = world.entry_ref(sector_entity).unwrap();
+
+		if let Ok(ceiling_active) = entry.get_component::<CeilingActive>() {
+			// Perpetual ceilings in stasis are started up again
+			if params.perpetual && ceiling_active.perpetual {
+				if let Ok(ceiling_move) = entry.get_component::<CeilingMove>() {
+					if ceiling_move.0.velocity == 0.0 {
+						let mut ceiling_move = ceiling_move.clone();
+						ceiling_move.0.velocity =
+							if ceiling_move.0.target == ceiling_active.low_height {
+								-ceiling_active.speed
+							} else {
+								ceiling_active.speed
+							};
+
+						activated = true;
+						command_buffer.add_component(sector_entity, ceiling_move);
+					}
+				}
+			}
+
+			continue;
+		}
+
+		if entry.get_component::<CeilingMove>().is_ok() {
+			continue;
+		}
+
+		activated = true;
+		activate(params, command_buffer, game_time, sector_index, map_dynamic);
+	}
+
+	activated
+}
@@ -8,7 +8,8 @@ use crate::{
 	doom::{
 		assets::sound::Sound,
 		game::{
-			client::{Usable, UseEvent},
+			client::{Usable, UseEvent, User},
+			item::{KeyColor, KeyInventory},
 			map::{
 				sector_move::{CeilingMove, SectorMove, SectorMoveEvent, SectorMoveEventType},
 				switch::{self, SwitchActive, SwitchParams},
@@ -167,6 +168,7 @@ pub fn door_active(resources: &mut Resources) -> impl Runnable {
 pub struct DoorUse {
 	pub params: DoorParams,
 	pub retrigger: bool,
+	pub key: Option<KeyColor>,
 }
 
 pub fn door_use(resources: &mut Resources) -> impl Runnable {
@@ -183,11 +185,19 @@ pub fn door_use(resources: &mut Resources) -> impl Runnable {
 		.with_query(<&LinedefRef>::query())
 		.with_query(<&MapDynamic>::query())
 		.with_query(<(&mut CeilingMove, &mut DoorActive)>::query())
+		.read_component::<KeyInventory>() // used by has_key
+		.read_component::<User>() // used by has_key
 		.build(move |command_buffer, world, resources, queries| {
 			let (asset_storage, game_time) = resources;
 			let (mut world3, world) = world.split_for_query(&queries.3);
 
 			for (event, door_use) in queries.0.iter(&world) {
+				if let Some(key) = door_use.key {
+					if !has_key(command_buffer, &world, event.other, key) {
+						continue;
+					}
+				}
+
 				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
 					let map_dynamic = queries.2.get(&world, linedef_ref.map_entity).unwrap();
 					let map = asset_storage.get(&map_dynamic.map).unwrap();
@@ -250,6 +260,7 @@ pub fn door_use(resources: &mut Resources) -> impl Runnable {
 pub struct DoorSwitchUse {
 	pub params: DoorParams,
 	pub switch_params: SwitchParams,
+	pub key: Option<KeyColor>,
 }
 
 pub fn door_switch_use(resources: &mut Resources) -> impl Runnable {
@@ -266,11 +277,19 @@ pub fn door_switch_use(resources: &mut Resources) -> impl Runnable {
 		.with_query(<&LinedefRef>::query().filter(!component::<SwitchActive>()))
 		.with_query(<&mut MapDynamic>::query())
 		.read_component::<DoorActive>() // used by activate_with_tag
+		.read_component::<KeyInventory>() // used by has_key
+		.read_component::<User>() // used by has_key
 		.build(move |command_buffer, world, resources, queries| {
 			let (asset_storage, game_time) = resources;
 			let (mut world2, world) = world.split_for_query(&queries.2);
 
 			for (event, door_switch_use) in queries.0.iter(&world) {
+				if let Some(key) = door_switch_use.key {
+					if !has_key(command_buffer, &world, event.other, key) {
+						continue;
+					}
+				}
+
 				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
 					let map_dynamic = queries
 						.2
@@ -363,6 +382,31 @@ pub fn door_linedef_touch(resources: &mut Resources) -> impl Runnable {
 		})
 }
 
+/// Checks whether the user has a key of the given color, and complains if not.
+fn has_key<W: EntityStore>(
+	command_buffer: &mut CommandBuffer,
+	world: &W,
+	entity: Entity,
+	key: KeyColor,
+) -> bool {
+	let (user, key_inventory) = match <(&User, Option<&KeyInventory>)>::query().get(world, entity) {
+		Ok(x) => x,
+		Err(_) => return false,
+	};
+
+	if key_inventory.map_or(false, |k| k.has_color(key)) {
+		return true;
+	}
+
+	log::info!("You need a {} key", key.name());
+	command_buffer.push((StartSoundEvent {
+		handle: user.error_sound.clone(),
+		entity: Some(entity),
+	},));
+
+	false
+}
+
 fn activate(
 	params: &DoorParams,
 	command_buffer: &mut CommandBuffer,
@@ -409,7 +453,7 @@ fn activate(
 	);
 }
 
-fn activate_with_tag<W: EntityStore>(
+pub fn activate_with_tag<W: EntityStore>(
 	params: &DoorParams,
 	command_buffer: &mut CommandBuffer,
 	game_time: GameTime,
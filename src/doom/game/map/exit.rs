@@ -4,15 +4,17 @@ use crate::{
 		spawn::{ComponentAccessor, SpawnContext, SpawnFrom, SpawnMergerHandlerSet},
 		time::GameTime,
 	},
-	doom::game::{
-		client::{Usable, UseEvent},
-		map::{
-			switch::{self, SwitchActive, SwitchParams},
-			LinedefRef, MapDynamic,
+	doom::{
+		game::{
+			client::{Usable, UseEvent},
+			map::{
+				switch::{self, SwitchActive, SwitchParams},
+				LinedefRef, MapDynamic,
+			},
 		},
+		ui::intermission::start_intermission,
 	},
 };
-use crossbeam_channel::Sender;
 use legion::{
 	component,
 	systems::{ResourceSet, Runnable},
@@ -26,7 +28,7 @@ pub struct ExitSwitchUse {
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
-pub struct ExitMap(String);
+pub struct ExitMap(pub String);
 
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ExitMapDef {
@@ -61,13 +63,12 @@ pub fn exit_switch_use(resources: &mut Resources) -> impl Runnable {
 
 	SystemBuilder::new("exit_switch_use")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Sender<String>>()
 		.read_resource::<GameTime>()
 		.with_query(<(&UseEvent, &ExitSwitchUse)>::query())
 		.with_query(<(&LinedefRef, &ExitMap)>::query().filter(!component::<SwitchActive>()))
 		.with_query(<&mut MapDynamic>::query())
 		.build(move |command_buffer, world, resources, queries| {
-			let (asset_storage, command_sender, game_time) = resources;
+			let (asset_storage, game_time) = resources;
 			let (mut world2, world) = world.split_for_query(&queries.2);
 
 			for (event, exit_switch_use) in queries.0.iter(&world) {
@@ -78,7 +79,10 @@ pub fn exit_switch_use(resources: &mut Resources) -> impl Runnable {
 						.unwrap();
 					let map = asset_storage.get(&map_dynamic.map).unwrap();
 
-					command_sender.send(format!("change {}", next_map)).ok();
+					let next_map = next_map.clone();
+					command_buffer.exec_mut(move |world, resources| {
+						start_intermission(&next_map, world, resources)
+					});
 
 					switch::activate(
 						&exit_switch_use.switch_params,
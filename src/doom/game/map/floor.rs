@@ -1,17 +1,25 @@
 use crate::{
 	common::{
 		assets::{AssetHandle, AssetStorage},
+		geometry::Side,
 		spawn::SpawnMergerHandlerSet,
 		time::{GameTime, Timer},
 	},
 	doom::{
-		assets::sound::Sound,
+		assets::{
+			map::{textures::TextureType, Linedef, SectorSlot, SidedefSlot},
+			sound::Sound,
+		},
 		game::{
 			client::{Usable, UseEvent},
 			map::{
-				sector_move::{FloorMove, SectorMove, SectorMoveEvent, SectorMoveEventType},
+				sector_move::{
+					CrushParams, FloorMove, SectorCrush, SectorMove, SectorMoveEvent,
+					SectorMoveEventType,
+				},
+				sector_special,
 				switch::{self, SwitchActive, SwitchParams},
-				LinedefRef, Map, MapDynamic,
+				LinedefRef, Map, MapDynamic, SectorRef,
 			},
 			physics::{TouchEvent, Touchable},
 		},
@@ -29,6 +37,10 @@ use std::time::Duration;
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FloorActive {
 	pub finish_sound: Option<AssetHandle<Sound>>,
+
+	/// Floor texture to change to when the target is reached, and the sector to copy the special
+	/// type from.
+	pub change: Option<(TextureType, usize)>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -39,6 +51,8 @@ pub struct FloorParams {
 	pub move_sound: Option<AssetHandle<Sound>>,
 	pub move_sound_time: Duration,
 	pub finish_sound: Option<AssetHandle<Sound>>,
+	pub crush: Option<CrushParams>,
+	pub change_type: Option<FloorChangeType>,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -48,6 +62,19 @@ pub enum FloorTargetHeight {
 	LowestNeighbourFloorAbove,
 	LowestNeighbourCeiling,
 	HighestNeighbourFloor,
+	/// The current floor height raised by the height of the shortest lower texture
+	/// on the sector's two-sided linedefs.
+	ShortestLowerTexture,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum FloorChangeType {
+	/// Copy the floor texture and special type of the front sector of the triggering linedef,
+	/// as soon as the floor starts moving.
+	Trigger,
+	/// Copy the floor texture and special type of a neighbouring sector whose floor is at the
+	/// target height, once the floor reaches it.
+	Neighbour,
 }
 
 pub fn floor_active(resources: &mut Resources) -> impl Runnable {
@@ -59,16 +86,18 @@ pub fn floor_active(resources: &mut Resources) -> impl Runnable {
 
 	SystemBuilder::new("floor_active")
 		.with_query(<&SectorMoveEvent>::query())
-		.with_query(<(&mut FloorMove, &mut FloorActive)>::query())
+		.with_query(<(&SectorRef, &mut FloorMove, &mut FloorActive)>::query())
+		.with_query(<&mut MapDynamic>::query())
 		.build(move |command_buffer, world, _resources, queries| {
-			let (mut world1, world) = world.split_for_query(&queries.1);
+			let (mut world1, mut world) = world.split_for_query(&queries.1);
+			let (world0, mut world) = world.split_for_query(&queries.0);
 
-			for event in queries.0.iter(&world).filter(|e| e.normal == 1.0) {
-				let (floor_move, floor_active) = match queries.1.get_mut(&mut world1, event.entity)
-				{
-					Ok(x) => x,
-					_ => continue,
-				};
+			for event in queries.0.iter(&world0).filter(|e| e.normal == 1.0) {
+				let (sector_ref, floor_move, floor_active) =
+					match queries.1.get_mut(&mut world1, event.entity) {
+						Ok(x) => x,
+						_ => continue,
+					};
 
 				let sector_move = &floor_move.0;
 
@@ -88,8 +117,24 @@ pub fn floor_active(resources: &mut Resources) -> impl Runnable {
 							},));
 						}
 
+						if let Some((texture, model_index)) = floor_active.change.take() {
+							let map_dynamic = queries
+								.2
+								.get_mut(&mut world, sector_ref.map_entity)
+								.unwrap();
+							map_dynamic.sectors[sector_ref.index].textures
+								[SectorSlot::Floor as usize] = texture;
+							sector_special::change_special(
+								command_buffer,
+								sector_ref.index,
+								Some(model_index),
+								map_dynamic,
+							);
+						}
+
 						command_buffer.remove_component::<FloorMove>(event.entity);
 						command_buffer.remove_component::<FloorActive>(event.entity);
+						command_buffer.remove_component::<SectorCrush>(event.entity);
 					}
 				}
 			}
@@ -134,7 +179,9 @@ pub fn floor_switch_use(resources: &mut Resources) -> impl Runnable {
 						command_buffer,
 						**game_time,
 						linedef.sector_tag,
+						Some(linedef),
 						&world,
+						asset_storage,
 						map,
 						map_dynamic,
 					);
@@ -200,7 +247,9 @@ pub fn floor_linedef_touch(resources: &mut Resources) -> impl Runnable {
 						command_buffer,
 						**game_time,
 						linedef.sector_tag,
+						Some(linedef),
 						&world,
+						asset_storage,
 						map,
 						map_dynamic,
 					) {
@@ -218,8 +267,10 @@ fn activate(
 	command_buffer: &mut CommandBuffer,
 	game_time: GameTime,
 	sector_index: usize,
+	linedef: Option<&Linedef>,
+	asset_storage: &AssetStorage,
 	map: &Map,
-	map_dynamic: &MapDynamic,
+	map_dynamic: &mut MapDynamic,
 ) {
 	let sector_dynamic = &map_dynamic.sectors[sector_index];
 
@@ -250,6 +301,28 @@ fn activate(
 				target_height
 			}
 		}
+		FloorTargetHeight::ShortestLowerTexture => {
+			let texture_height = map.sectors[sector_index]
+				.linedefs
+				.iter()
+				.filter(|i| map.linedefs[**i].sidedefs.iter().all(Option::is_some))
+				.flat_map(|i| map_dynamic.linedefs[*i].sidedefs.iter().flatten())
+				.filter_map(
+					|sidedef| match &sidedef.textures[SidedefSlot::Bottom as usize] {
+						TextureType::Normal(handle) => {
+							asset_storage.get(handle).map(|i| i.size()[1])
+						}
+						_ => None,
+					},
+				)
+				.fold(f32::INFINITY, f32::min);
+
+			if texture_height.is_finite() {
+				sector_dynamic.interval.min + texture_height + params.target_height_offset
+			} else {
+				sector_dynamic.interval.min + params.target_height_offset
+			}
+		}
 	};
 
 	let direction = if target < sector_dynamic.interval.min {
@@ -258,6 +331,31 @@ fn activate(
 		1.0
 	};
 
+	let model_index = match params.change_type {
+		Some(FloorChangeType::Trigger) => linedef
+			.and_then(|linedef| linedef.sidedefs[Side::Right as usize].as_ref())
+			.map(|sidedef| sidedef.sector_index),
+		Some(FloorChangeType::Neighbour) => map.sectors[sector_index]
+			.linedefs
+			.iter()
+			.filter_map(|i| match &map.linedefs[*i].sidedefs {
+				[Some(front), Some(back)] if front.sector_index == sector_index => {
+					Some(back.sector_index)
+				}
+				[Some(front), Some(_)] => Some(front.sector_index),
+				_ => None,
+			})
+			.find(|i| map_dynamic.sectors[*i].interval.min == target),
+		None => None,
+	};
+
+	let mut change = model_index.map(|i| {
+		(
+			map_dynamic.sectors[i].textures[SectorSlot::Floor as usize].clone(),
+			i,
+		)
+	});
+
 	command_buffer.add_component(
 		sector_dynamic.entity,
 		FloorMove(SectorMove {
@@ -272,22 +370,45 @@ fn activate(
 		sector_dynamic.entity,
 		FloorActive {
 			finish_sound: params.finish_sound.clone(),
+			change: match params.change_type {
+				Some(FloorChangeType::Neighbour) => change.take(),
+				_ => None,
+			},
 		},
 	);
+
+	if let Some(crush) = &params.crush {
+		command_buffer.add_component(sector_dynamic.entity, SectorCrush::new(crush, game_time));
+	}
+
+	// Other changes happen right away
+	if let Some((texture, model_index)) = change {
+		map_dynamic.sectors[sector_index].textures[SectorSlot::Floor as usize] = texture;
+		sector_special::change_special(
+			command_buffer,
+			sector_index,
+			Some(model_index),
+			map_dynamic,
+		);
+	}
 }
 
-fn activate_with_tag<W: EntityStore>(
+/// Activates all the floors with the given tag. `linedef` is the linedef that triggered them,
+/// if any.
+pub fn activate_with_tag<W: EntityStore>(
 	params: &FloorParams,
 	command_buffer: &mut CommandBuffer,
 	game_time: GameTime,
 	sector_tag: u16,
+	linedef: Option<&Linedef>,
 	world: &W,
+	asset_storage: &AssetStorage,
 	map: &Map,
-	map_dynamic: &MapDynamic,
+	map_dynamic: &mut MapDynamic,
 ) -> bool {
 	let mut activated = false;
 
-	// Activate all the doors with the same tag
+	// Activate all the floors with the same tag
 	for (sector_index, _) in map
 		.sectors
 		.iter()
@@ -311,6 +432,8 @@ fn activate_with_tag<W: EntityStore>(
 			command_buffer,
 			game_time,
 			sector_index,
+			linedef,
+			asset_storage,
 			map,
 			map_dynamic,
 		);
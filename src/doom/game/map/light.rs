@@ -0,0 +1,197 @@
+use crate::{
+	common::{
+		assets::AssetStorage,
+		random::GameRng,
+		spawn::SpawnMergerHandlerSet,
+		time::{GameTime, Timer},
+	},
+	doom::{
+		data::FRAME_TIME,
+		game::{
+			client::{Usable, UseEvent},
+			map::{
+				anim::{LightFlash, LightFlashType},
+				switch::{self, SwitchActive, SwitchParams},
+				LinedefRef, Map, MapDynamic,
+			},
+			physics::{TouchEvent, Touchable},
+		},
+	},
+};
+use legion::{
+	component,
+	systems::{CommandBuffer, ResourceSet, Runnable},
+	IntoQuery, Registry, Resources, SystemBuilder, Write,
+};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum LightParams {
+	Set(f32),
+	LowestNeighbour,
+	HighestNeighbour,
+	Strobe {
+		on_time: Duration,
+		off_time: Duration,
+	},
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LightSwitchUse {
+	pub params: LightParams,
+	pub switch_params: SwitchParams,
+}
+
+pub fn light_switch_use(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<LightSwitchUse>("LightSwitchUse".into());
+	handler_set.register_clone::<LightSwitchUse>();
+
+	SystemBuilder::new("light_switch_use")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameTime>()
+		.write_resource::<GameRng>()
+		.with_query(<(&UseEvent, &LightSwitchUse)>::query())
+		.with_query(<&LinedefRef>::query().filter(!component::<SwitchActive>()))
+		.with_query(<&mut MapDynamic>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_time, game_rng) = resources;
+			let (mut world2, world) = world.split_for_query(&queries.2);
+
+			for (event, light_switch_use) in queries.0.iter(&world) {
+				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
+					let map_dynamic = queries
+						.2
+						.get_mut(&mut world2, linedef_ref.map_entity)
+						.unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let linedef = &map.linedefs[linedef_ref.index];
+
+					activate_with_tag(
+						&light_switch_use.params,
+						command_buffer,
+						**game_time,
+						linedef.sector_tag,
+						map,
+						map_dynamic,
+						game_rng,
+					);
+
+					switch::activate(
+						&light_switch_use.switch_params,
+						command_buffer,
+						**game_time,
+						linedef_ref.index,
+						map,
+						map_dynamic,
+					);
+
+					if light_switch_use.switch_params.retrigger_time.is_none() {
+						command_buffer.remove_component::<Usable>(event.entity);
+					}
+				}
+			}
+		})
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LightLinedefTouch {
+	pub params: LightParams,
+	pub retrigger: bool,
+}
+
+pub fn light_linedef_touch(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<LightLinedefTouch>("LightLinedefTouch".into());
+	handler_set.register_clone::<LightLinedefTouch>();
+
+	SystemBuilder::new("light_linedef_touch")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameTime>()
+		.write_resource::<GameRng>()
+		.with_query(<(&TouchEvent, &LightLinedefTouch)>::query())
+		.with_query(<&LinedefRef>::query())
+		.with_query(<&mut MapDynamic>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_time, game_rng) = resources;
+			let (mut world2, world) = world.split_for_query(&queries.2);
+
+			for (event, light_linedef_touch) in queries.0.iter(&world) {
+				if event.collision.is_some() {
+					continue;
+				}
+
+				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
+					let map_dynamic = queries
+						.2
+						.get_mut(&mut world2, linedef_ref.map_entity)
+						.unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let linedef = &map.linedefs[linedef_ref.index];
+
+					activate_with_tag(
+						&light_linedef_touch.params,
+						command_buffer,
+						**game_time,
+						linedef.sector_tag,
+						map,
+						map_dynamic,
+						game_rng,
+					);
+
+					if !light_linedef_touch.retrigger {
+						command_buffer.remove_component::<Touchable>(event.entity);
+					}
+				}
+			}
+		})
+}
+
+fn activate_with_tag(
+	params: &LightParams,
+	command_buffer: &mut CommandBuffer,
+	game_time: GameTime,
+	sector_tag: u16,
+	map: &Map,
+	map_dynamic: &mut MapDynamic,
+	game_rng: &mut GameRng,
+) {
+	// Change the light of all the sectors with the same tag
+	for (sector_index, _) in map
+		.sectors
+		.iter()
+		.enumerate()
+		.filter(|(_, s)| s.sector_tag == sector_tag)
+	{
+		let light_level = match *params {
+			LightParams::Set(light_level) => light_level,
+			LightParams::LowestNeighbour => f32::min(
+				map.lowest_neighbour_light(map_dynamic, sector_index),
+				map_dynamic.sectors[sector_index].light_level,
+			),
+			LightParams::HighestNeighbour => map.highest_neighbour_light(map_dynamic, sector_index),
+			LightParams::Strobe { on_time, off_time } => {
+				command_buffer.add_component(
+					map_dynamic.sectors[sector_index].entity,
+					LightFlash {
+						flash_type: LightFlashType::Strobe,
+						on_time,
+						off_time,
+						timer: Timer::new(game_time, FRAME_TIME * game_rng.gen_range(1..=8)),
+						state: true,
+					},
+				);
+
+				continue;
+			}
+		};
+
+		map_dynamic.sectors[sector_index].light_level = light_level;
+	}
+}
@@ -1,10 +1,16 @@
 pub mod anim;
+pub mod ceiling;
 pub mod door;
 pub mod exit;
 pub mod floor;
+pub mod light;
 pub mod plat;
 pub mod sector_move;
+pub mod sector_special;
+pub mod sound;
+pub mod stairs;
 pub mod switch;
+pub mod teleport;
 
 use crate::{
 	common::{
@@ -43,6 +49,10 @@ pub struct LinedefDynamic {
 	pub entity: Entity,
 	pub sidedefs: [Option<SidedefDynamic>; 2],
 	pub texture_offset: Vector2<f32>,
+
+	/// Whether the linedef is shown on the map, which the computer map powerup does for all
+	/// linedefs.
+	pub mapped: bool,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -74,6 +84,9 @@ pub struct SectorDynamic {
 	pub entity: Entity,
 	pub light_level: f32,
 	pub interval: Interval,
+	pub textures: [TextureType; 2],
+	pub special_type: u16,
+	pub sound_target: Option<Entity>,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
@@ -95,6 +108,10 @@ impl SpawnFrom<SectorRefDef> for SectorRef {
 	}
 }
 
+/// Component for the spots where players can start or respawn in deathmatch games.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct DeathmatchSpawnPoint;
+
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub struct SpawnPoint {
 	pub player_num: usize,
@@ -1,16 +1,21 @@
 use crate::{
 	common::{
 		assets::{AssetHandle, AssetStorage},
+		geometry::Side,
 		spawn::SpawnMergerHandlerSet,
 		time::{GameTime, Timer},
 	},
 	doom::{
-		assets::sound::Sound,
+		assets::{
+			map::{Linedef, SectorSlot},
+			sound::Sound,
+		},
 		game::{
 			client::{Usable, UseEvent},
 			combat::Owner,
 			map::{
 				sector_move::{FloorMove, SectorMove, SectorMoveEvent, SectorMoveEventType},
+				sector_special,
 				switch::{self, SwitchActive, SwitchParams},
 				LinedefRef, Map, MapDynamic,
 			},
@@ -56,12 +61,24 @@ pub struct PlatParams {
 	pub low_height_offset: f32,
 	pub high_height_base: PlatTargetHeight,
 	pub high_height_offset: f32,
+
+	pub change_type: Option<PlatChangeType>,
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize)]
 pub enum PlatTargetHeight {
 	Current,
 	LowestNeighbourFloor,
+	LowestNeighbourFloorAbove,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub enum PlatChangeType {
+	/// Copy the floor texture of the front sector of the triggering linedef.
+	Texture,
+	/// Copy the floor texture of the front sector of the triggering linedef,
+	/// and remove the special type.
+	TextureClearSpecial,
 }
 
 pub fn plat_active(resources: &mut Resources) -> impl Runnable {
@@ -197,7 +214,7 @@ pub fn plat_switch_use(resources: &mut Resources) -> impl Runnable {
 						&plat_switch_use.params,
 						command_buffer,
 						**game_time,
-						linedef.sector_tag,
+						linedef,
 						&world,
 						map,
 						map_dynamic,
@@ -263,7 +280,7 @@ pub fn plat_linedef_touch(resources: &mut Resources) -> impl Runnable {
 						&plat_linedef_touch.params,
 						command_buffer,
 						**game_time,
-						linedef.sector_tag,
+						linedef,
 						&world,
 						map,
 						map_dynamic,
@@ -282,8 +299,9 @@ fn activate(
 	command_buffer: &mut CommandBuffer,
 	game_time: GameTime,
 	sector_index: usize,
+	linedef: &Linedef,
 	map: &Map,
-	map_dynamic: &MapDynamic,
+	map_dynamic: &mut MapDynamic,
 ) {
 	let sector_dynamic = &map_dynamic.sectors[sector_index];
 
@@ -292,6 +310,10 @@ fn activate(
 		PlatTargetHeight::LowestNeighbourFloor => {
 			map.lowest_neighbour_floor(map_dynamic, sector_index) + params.low_height_offset
 		}
+		PlatTargetHeight::LowestNeighbourFloorAbove => {
+			map.lowest_neighbour_floor_above(map_dynamic, sector_index, sector_dynamic.interval.min)
+				+ params.low_height_offset
+		}
 	};
 
 	let high_height = match params.high_height_base {
@@ -299,6 +321,10 @@ fn activate(
 		PlatTargetHeight::LowestNeighbourFloor => {
 			map.lowest_neighbour_floor(map_dynamic, sector_index) + params.high_height_offset
 		}
+		PlatTargetHeight::LowestNeighbourFloorAbove => {
+			map.lowest_neighbour_floor_above(map_dynamic, sector_index, sector_dynamic.interval.min)
+				+ params.high_height_offset
+		}
 	};
 
 	command_buffer.add_component(
@@ -325,25 +351,38 @@ fn activate(
 			low_height,
 		},
 	);
+
+	if let Some(change_type) = params.change_type {
+		if let Some(sidedef) = &linedef.sidedefs[Side::Right as usize] {
+			let texture = map_dynamic.sectors[sidedef.sector_index].textures
+				[SectorSlot::Floor as usize]
+				.clone();
+			map_dynamic.sectors[sector_index].textures[SectorSlot::Floor as usize] = texture;
+
+			if let PlatChangeType::TextureClearSpecial = change_type {
+				sector_special::change_special(command_buffer, sector_index, None, map_dynamic);
+			}
+		}
+	}
 }
 
 fn activate_with_tag<W: EntityStore>(
 	params: &PlatParams,
 	command_buffer: &mut CommandBuffer,
 	game_time: GameTime,
-	sector_tag: u16,
+	linedef: &Linedef,
 	world: &W,
 	map: &Map,
-	map_dynamic: &MapDynamic,
+	map_dynamic: &mut MapDynamic,
 ) -> bool {
 	let mut activated = false;
 
-	// Activate all the doors with the same tag
+	// Activate all the plats with the same tag
 	for (sector_index, _) in map
 		.sectors
 		.iter()
 		.enumerate()
-		.filter(|(_, s)| s.sector_tag == sector_tag)
+		.filter(|(_, s)| s.sector_tag == linedef.sector_tag)
 	{
 		let sector_entity = map_dynamic.sectors[sector_index].entity;
 
@@ -362,6 +401,7 @@ fn activate_with_tag<W: EntityStore>(
 			command_buffer,
 			game_time,
 			sector_index,
+			linedef,
 			map,
 			map_dynamic,
 		);
@@ -6,11 +6,13 @@ use crate::{
 		time::{DeltaTime, GameTime, Timer},
 	},
 	doom::{
-		assets::sound::Sound,
+		assets::{sound::Sound, sprite::Sprite},
+		draw::sprite::SpriteRender,
 		game::{
-			combat::Owner,
+			combat::{DamageEvent, Health, Owner},
 			map::{MapDynamic, SectorRef},
 			physics::BoxCollider,
+			state::{State, StateAction},
 			trace::SectorTracer,
 			Transform,
 		},
@@ -22,7 +24,10 @@ use legion::{
 	world::SubWorld,
 	Entity, IntoQuery, Registry, Resources, SystemBuilder, Write,
 };
+use nalgebra::Vector3;
 use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+use std::time::Duration;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct FloorMove(pub SectorMove);
@@ -38,11 +43,14 @@ pub struct SectorMove {
 	pub sound_timer: Timer,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct SectorMoveEvent {
 	pub entity: Entity,
 	pub event_type: SectorMoveEventType,
 	pub normal: f32,
+
+	/// Entities that were pushed by the move, including any that blocked it.
+	pub pushed_entities: SmallVec<[Entity; 8]>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -164,6 +172,7 @@ pub fn sector_move(resources: &mut Resources) -> impl Runnable {
 						entity,
 						event_type,
 						normal,
+						pushed_entities: trace.pushed_entities.iter().map(|e| e.entity).collect(),
 					},));
 				}
 			};
@@ -197,3 +206,105 @@ pub fn sector_move(resources: &mut Resources) -> impl Runnable {
 			}
 		})
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CrushParams {
+	pub damage: i32,
+	pub damage_time: Duration,
+	pub gibs_sprite: AssetHandle<Sprite>,
+}
+
+/// Component for sectors that damage the entities that block their movement.
+///
+/// Living entities take damage at a fixed interval for as long as they are in the way.
+/// Corpses are turned into gibs, which no longer block the move.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SectorCrush {
+	pub params: CrushParams,
+	pub damage_timer: Timer,
+}
+
+impl SectorCrush {
+	pub fn new(params: &CrushParams, game_time: GameTime) -> SectorCrush {
+		SectorCrush {
+			params: params.clone(),
+			damage_timer: Timer::new_elapsed(game_time, params.damage_time),
+		}
+	}
+}
+
+pub fn sector_crush(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<SectorCrush>("SectorCrush".into());
+	handler_set.register_clone::<SectorCrush>();
+
+	SystemBuilder::new("sector_crush")
+		.read_resource::<GameTime>()
+		.with_query(<&SectorMoveEvent>::query())
+		.with_query(<&mut SectorCrush>::query())
+		.with_query(<(
+			&mut BoxCollider,
+			&Health,
+			Option<&mut SpriteRender>,
+			Option<&mut State>,
+		)>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let game_time = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+			let (mut world1, mut world) = world.split_for_query(&queries.1);
+
+			for event in queries.0.iter(&world0) {
+				if event.event_type != SectorMoveEventType::Collided {
+					continue;
+				}
+
+				let sector_crush = match queries.1.get_mut(&mut world1, event.entity) {
+					Ok(x) => x,
+					Err(_) => continue,
+				};
+
+				let do_damage = sector_crush.damage_timer.is_elapsed(**game_time);
+
+				if do_damage {
+					sector_crush.damage_timer.restart(**game_time);
+				}
+
+				for &entity in event.pushed_entities.iter() {
+					let (box_collider, health, sprite_render, state) =
+						match queries.2.get_mut(&mut world, entity) {
+							Ok(x) => x,
+							Err(_) => continue,
+						};
+
+					if health.current <= 0 {
+						// Crush the corpse into gibs
+						if box_collider.height > 0.0 {
+							box_collider.height = 0.0;
+							box_collider.radius = 0.0;
+
+							if let Some(sprite_render) = sprite_render {
+								*sprite_render = SpriteRender {
+									sprite: sector_crush.params.gibs_sprite.clone(),
+									frame: 0,
+									full_bright: false,
+								};
+							}
+
+							if let Some(state) = state {
+								state.action = StateAction::None;
+							}
+						}
+					} else if do_damage {
+						command_buffer.push((DamageEvent {
+							entity,
+							damage: sector_crush.params.damage,
+							source_entity: event.entity,
+							direction: Vector3::zeros(),
+						},));
+					}
+				}
+			}
+		})
+}
@@ -0,0 +1,194 @@
+use crate::{
+	common::{
+		assets::AssetStorage,
+		random::GameRng,
+		spawn::{ComponentAccessor, SpawnFrom, SpawnMergerHandlerSet},
+		time::{GameTime, Timer},
+	},
+	doom::{
+		game::{
+			client::User,
+			combat::{DamageEvent, Health},
+			item::{PowerupType, Powerups},
+			map::{exit::ExitMap, MapDynamic, SectorRef},
+			physics::DISTANCE_EPSILON,
+			stats::LevelStats,
+			Transform,
+		},
+		ui::intermission::start_intermission,
+	},
+};
+use legion::{
+	component,
+	systems::{CommandBuffer, ResourceSet, Runnable},
+	Entity, IntoQuery, Read, Registry, Resources, SystemBuilder, Write,
+};
+use nalgebra::Vector3;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct SectorDamage {
+	pub damage: i32,
+	pub timer: Timer,
+
+	/// Chance that the damage goes through a radiation suit.
+	pub suit_leak_chance: f32,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SectorDamageDef {
+	pub damage: i32,
+	pub damage_time: Duration,
+	pub suit_leak_chance: f32,
+}
+
+impl SpawnFrom<SectorDamageDef> for SectorDamage {
+	fn spawn(
+		component: &SectorDamageDef,
+		_accessor: ComponentAccessor,
+		resources: &Resources,
+	) -> SectorDamage {
+		let game_time = <Read<GameTime>>::fetch(resources);
+
+		SectorDamage {
+			damage: component.damage,
+			timer: Timer::new_elapsed(*game_time, component.damage_time),
+			suit_leak_chance: component.suit_leak_chance,
+		}
+	}
+}
+
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct SectorSecret;
+
+pub fn sector_special(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<SectorDamage>("SectorDamage".into());
+	handler_set.register_spawn::<SectorDamageDef, SectorDamage>();
+
+	registry.register::<SectorSecret>("SectorSecret".into());
+	handler_set.register_clone::<SectorSecret>();
+
+	SystemBuilder::new("sector_special")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameTime>()
+		.write_resource::<GameRng>()
+		.write_resource::<LevelStats>()
+		.with_query(
+			<(Entity, &Health, Option<&Powerups>, &Transform)>::query().filter(component::<User>()),
+		)
+		.with_query(<&mut MapDynamic>::query())
+		.with_query(<(
+			&SectorRef,
+			Option<&SectorDamage>,
+			Option<&ExitMap>,
+			Option<&SectorSecret>,
+		)>::query())
+		.with_query(<&mut SectorDamage>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_time, game_rng, level_stats) = resources;
+			let (mut world1, mut world) = world.split_for_query(&queries.1);
+
+			if let Some(map_dynamic) = queries.1.iter_mut(&mut world1).next() {
+				let map = asset_storage.get(&map_dynamic.map).unwrap();
+
+				for (&entity, health, powerups, transform) in queries.0.iter(&world) {
+					let sector_index = map
+						.find_subsector(transform.position.fixed_rows::<2>(0).into_owned())
+						.sector_index;
+					let sector_dynamic = &mut map_dynamic.sectors[sector_index];
+
+					// Only affect players standing on the floor
+					if transform.position[2] > sector_dynamic.interval.min + DISTANCE_EPSILON {
+						continue;
+					}
+
+					let (_, sector_damage, exit_map, sector_secret) =
+						match queries.2.get(&world, sector_dynamic.entity) {
+							Ok(x) => x,
+							Err(_) => continue,
+						};
+
+					if let Some(sector_damage) = sector_damage {
+						// A radiation suit protects against the damage, but some may leak through
+						let mut protected = || {
+							powerups.map_or(false, |p| p.has(PowerupType::RadiationSuit))
+								&& !game_rng.gen_bool(sector_damage.suit_leak_chance as f64)
+						};
+
+						if sector_damage.timer.is_elapsed(**game_time) && !protected() {
+							command_buffer.push((DamageEvent {
+								entity,
+								damage: sector_damage.damage,
+								source_entity: sector_dynamic.entity,
+								direction: Vector3::zeros(),
+							},));
+						}
+					}
+
+					if let Some(ExitMap(next_map)) = exit_map {
+						if health.current <= 10 {
+							let next_map = next_map.clone();
+							command_buffer.exec_mut(move |world, resources| {
+								start_intermission(&next_map, world, resources)
+							});
+							command_buffer.remove_component::<ExitMap>(sector_dynamic.entity);
+						}
+					}
+
+					if sector_secret.is_some() {
+						level_stats.secrets_found += 1;
+						sector_dynamic.special_type = 0;
+						command_buffer.remove_component::<SectorSecret>(sector_dynamic.entity);
+					}
+				}
+			}
+
+			// Damage happens at the same interval for all players
+			for sector_damage in queries.3.iter_mut(&mut world) {
+				if sector_damage.timer.is_elapsed(**game_time) {
+					sector_damage.timer.restart(**game_time);
+				}
+			}
+		})
+}
+
+/// Changes the special type of a sector to that of another sector, or clears it.
+pub fn change_special(
+	command_buffer: &mut CommandBuffer,
+	sector_index: usize,
+	model_index: Option<usize>,
+	map_dynamic: &mut MapDynamic,
+) {
+	let entity = map_dynamic.sectors[sector_index].entity;
+	let model_entity = model_index.map(|i| map_dynamic.sectors[i].entity);
+	map_dynamic.sectors[sector_index].special_type =
+		model_index.map_or(0, |i| map_dynamic.sectors[i].special_type);
+
+	command_buffer.exec_mut(move |world, _resources| {
+		let (sector_damage, sector_secret) =
+			match model_entity.and_then(|e| world.entry_ref(e).ok()) {
+				Some(entry) => (
+					entry.get_component::<SectorDamage>().ok().copied(),
+					entry.get_component::<SectorSecret>().ok().copied(),
+				),
+				None => (None, None),
+			};
+
+		if let Some(mut entry) = world.entry(entity) {
+			match sector_damage {
+				Some(x) => entry.add_component(x),
+				None => entry.remove_component::<SectorDamage>(),
+			}
+
+			match sector_secret {
+				Some(x) => entry.add_component(x),
+				None => entry.remove_component::<SectorSecret>(),
+			}
+		}
+	});
+}
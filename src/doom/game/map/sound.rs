@@ -0,0 +1,53 @@
+use crate::doom::{
+	assets::map::{load::LinedefFlags, Map},
+	game::map::MapDynamic,
+};
+use legion::Entity;
+
+/// Propagates a noise made by `emitter` from the given sector to all sectors it can be heard in,
+/// and marks those sectors as having heard it.
+///
+/// Sound travels through two-sided linedefs as long as the space between the two sectors is not
+/// closed off, like a closed door. It passes through the first sound-blocking linedef it meets,
+/// but stops at the second.
+pub fn noise_alert(map: &Map, map_dynamic: &mut MapDynamic, sector_index: usize, emitter: Entity) {
+	// The lowest number of sound-blocking linedefs crossed to reach each sector
+	let mut traversed: Vec<Option<u8>> = vec![None; map.sectors.len()];
+	let mut stack = vec![(sector_index, 0u8)];
+
+	while let Some((sector_index, blocks)) = stack.pop() {
+		if matches!(traversed[sector_index], Some(t) if t <= blocks) {
+			continue;
+		}
+
+		traversed[sector_index] = Some(blocks);
+		map_dynamic.sectors[sector_index].sound_target = Some(emitter);
+
+		for &linedef_index in &map.sectors[sector_index].linedefs {
+			let linedef = &map.linedefs[linedef_index];
+			let (front, back) = match &linedef.sidedefs {
+				[Some(front), Some(back)] => (front.sector_index, back.sector_index),
+				_ => continue,
+			};
+
+			// Closed door
+			let opening = map_dynamic.sectors[front]
+				.interval
+				.intersection(map_dynamic.sectors[back].interval);
+
+			if opening.len() <= 0.0 {
+				continue;
+			}
+
+			let other = if front == sector_index { back } else { front };
+
+			if linedef.flags.intersects(LinedefFlags::BLOCKSOUND) {
+				if blocks == 0 {
+					stack.push((other, 1));
+				}
+			} else {
+				stack.push((other, blocks));
+			}
+		}
+	}
+}
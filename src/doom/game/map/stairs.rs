@@ -0,0 +1,276 @@
+use crate::{
+	common::{
+		assets::{AssetHandle, AssetStorage},
+		spawn::SpawnMergerHandlerSet,
+		time::{GameTime, Timer},
+	},
+	doom::{
+		assets::{map::SectorSlot, sound::Sound},
+		game::{
+			client::{Usable, UseEvent},
+			map::{
+				floor::FloorActive,
+				sector_move::{FloorMove, SectorMove},
+				switch::{self, SwitchActive, SwitchParams},
+				LinedefRef, Map, MapDynamic,
+			},
+			physics::{TouchEvent, Touchable},
+		},
+	},
+};
+use fnv::FnvHashSet;
+use legion::{
+	component,
+	systems::{CommandBuffer, ResourceSet, Runnable},
+	EntityStore, IntoQuery, Registry, Resources, SystemBuilder, Write,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StairsParams {
+	pub speed: f32,
+	pub step_height: f32,
+	pub move_sound: Option<AssetHandle<Sound>>,
+	pub move_sound_time: Duration,
+	pub finish_sound: Option<AssetHandle<Sound>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StairsSwitchUse {
+	pub params: StairsParams,
+	pub switch_params: SwitchParams,
+}
+
+pub fn stairs_switch_use(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<StairsSwitchUse>("StairsSwitchUse".into());
+	handler_set.register_clone::<StairsSwitchUse>();
+
+	SystemBuilder::new("stairs_switch_use")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameTime>()
+		.with_query(<(&UseEvent, &StairsSwitchUse)>::query())
+		.with_query(<&LinedefRef>::query().filter(!component::<SwitchActive>()))
+		.with_query(<&mut MapDynamic>::query())
+		.read_component::<FloorActive>() // used by activate_with_tag
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_time) = resources;
+			let (mut world2, world) = world.split_for_query(&queries.2);
+
+			for (event, stairs_switch_use) in queries.0.iter(&world) {
+				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
+					let map_dynamic = queries
+						.2
+						.get_mut(&mut world2, linedef_ref.map_entity)
+						.unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let linedef = &map.linedefs[linedef_ref.index];
+
+					let activated = activate_with_tag(
+						&stairs_switch_use.params,
+						command_buffer,
+						**game_time,
+						linedef.sector_tag,
+						&world,
+						map,
+						map_dynamic,
+					);
+
+					if activated {
+						switch::activate(
+							&stairs_switch_use.switch_params,
+							command_buffer,
+							**game_time,
+							linedef_ref.index,
+							map,
+							map_dynamic,
+						);
+
+						if stairs_switch_use.switch_params.retrigger_time.is_none() {
+							command_buffer.remove_component::<Usable>(event.entity);
+						}
+					}
+				}
+			}
+		})
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StairsLinedefTouch {
+	pub params: StairsParams,
+	pub retrigger: bool,
+}
+
+pub fn stairs_linedef_touch(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<StairsLinedefTouch>("StairsLinedefTouch".into());
+	handler_set.register_clone::<StairsLinedefTouch>();
+
+	SystemBuilder::new("stairs_linedef_touch")
+		.read_resource::<AssetStorage>()
+		.read_resource::<GameTime>()
+		.with_query(<(&TouchEvent, &StairsLinedefTouch)>::query())
+		.with_query(<&LinedefRef>::query())
+		.with_query(<&mut MapDynamic>::query())
+		.read_component::<FloorActive>() // used by activate_with_tag
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, game_time) = resources;
+			let (mut world2, world) = world.split_for_query(&queries.2);
+
+			for (event, stairs_linedef_touch) in queries.0.iter(&world) {
+				if event.collision.is_some() {
+					continue;
+				}
+
+				if let Ok(linedef_ref) = queries.1.get(&world, event.entity) {
+					let map_dynamic = queries
+						.2
+						.get_mut(&mut world2, linedef_ref.map_entity)
+						.unwrap();
+					let map = asset_storage.get(&map_dynamic.map).unwrap();
+					let linedef = &map.linedefs[linedef_ref.index];
+
+					if activate_with_tag(
+						&stairs_linedef_touch.params,
+						command_buffer,
+						**game_time,
+						linedef.sector_tag,
+						&world,
+						map,
+						map_dynamic,
+					) {
+						if !stairs_linedef_touch.retrigger {
+							command_buffer.remove_component::<Touchable>(event.entity);
+						}
+					}
+				}
+			}
+		})
+}
+
+fn activate(
+	params: &StairsParams,
+	command_buffer: &mut CommandBuffer,
+	game_time: GameTime,
+	sector_index: usize,
+	target: f32,
+	map_dynamic: &MapDynamic,
+) {
+	let sector_dynamic = &map_dynamic.sectors[sector_index];
+
+	command_buffer.add_component(
+		sector_dynamic.entity,
+		FloorMove(SectorMove {
+			velocity: params.speed,
+			target,
+			sound: params.move_sound.clone(),
+			sound_timer: Timer::new_elapsed(game_time, params.move_sound_time),
+		}),
+	);
+
+	command_buffer.add_component(
+		sector_dynamic.entity,
+		FloorActive {
+			finish_sound: params.finish_sound.clone(),
+			change: None,
+		},
+	);
+}
+
+fn activate_with_tag<W: EntityStore>(
+	params: &StairsParams,
+	command_buffer: &mut CommandBuffer,
+	game_time: GameTime,
+	sector_tag: u16,
+	world: &W,
+	map: &Map,
+	map_dynamic: &MapDynamic,
+) -> bool {
+	let mut activated = false;
+	let mut active_sectors = FnvHashSet::default();
+
+	let is_active = |sector_index: usize, active_sectors: &FnvHashSet<usize>| {
+		active_sectors.contains(&sector_index)
+			|| world
+				.entry_ref(map_dynamic.sectors[sector_index].entity)
+				.unwrap()
+				.get_component::<FloorActive>()
+				.is_ok()
+	};
+
+	// Build a staircase starting at each sector with the same tag
+	for (mut sector_index, _) in map
+		.sectors
+		.iter()
+		.enumerate()
+		.filter(|(_, s)| s.sector_tag == sector_tag)
+	{
+		if is_active(sector_index, &active_sectors) {
+			continue;
+		}
+
+		let texture = &map_dynamic.sectors[sector_index].textures[SectorSlot::Floor as usize];
+		let mut target = map_dynamic.sectors[sector_index].interval.min + params.step_height;
+
+		activated = true;
+		active_sectors.insert(sector_index);
+		activate(
+			params,
+			command_buffer,
+			game_time,
+			sector_index,
+			target,
+			map_dynamic,
+		);
+
+		// Each step is the sector behind a two-sided linedef facing out of the previous step,
+		// as long as it has the same floor texture
+		'steps: loop {
+			for linedef in map.sectors[sector_index]
+				.linedefs
+				.iter()
+				.map(|i| &map.linedefs[*i])
+			{
+				let next_index = match &linedef.sidedefs {
+					[Some(front), Some(back)] if front.sector_index == sector_index => {
+						back.sector_index
+					}
+					_ => continue,
+				};
+
+				if map_dynamic.sectors[next_index].textures[SectorSlot::Floor as usize] != *texture
+				{
+					continue;
+				}
+
+				target += params.step_height;
+
+				if is_active(next_index, &active_sectors) {
+					continue;
+				}
+
+				sector_index = next_index;
+				active_sectors.insert(sector_index);
+				activate(
+					params,
+					command_buffer,
+					game_time,
+					sector_index,
+					target,
+					map_dynamic,
+				);
+
+				continue 'steps;
+			}
+
+			break;
+		}
+	}
+
+	activated
+}
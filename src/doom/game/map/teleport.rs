@@ -0,0 +1,193 @@
+use crate::{
+	common::{
+		assets::{AssetHandle, AssetStorage},
+		geometry::{AABB2, AABB3},
+		quadtree::Quadtree,
+		spawn::SpawnMergerHandlerSet,
+	},
+	doom::{
+		assets::template::EntityTemplate,
+		game::{
+			ai::Monster,
+			client::User,
+			combat::{DamageEvent, Health},
+			map::{LinedefRef, MapDynamic},
+			physics::{BoxCollider, Physics, TouchEvent, Touchable},
+			spawn::spawn_entity,
+			Transform,
+		},
+	},
+};
+use legion::{
+	component,
+	systems::{ResourceSet, Runnable},
+	Entity, IntoQuery, Registry, Resources, SystemBuilder, Write,
+};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+/// Component for entities that mark the destination of a teleporter.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct TeleportDest;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TeleportParams {
+	pub fog: Option<AssetHandle<EntityTemplate>>,
+	pub monsters_only: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct TeleportLinedefTouch {
+	pub params: TeleportParams,
+	pub retrigger: bool,
+}
+
+pub fn teleport_linedef_touch(resources: &mut Resources) -> impl Runnable {
+	let (mut handler_set, mut registry) =
+		<(Write<SpawnMergerHandlerSet>, Write<Registry<String>>)>::fetch_mut(resources);
+
+	registry.register::<TeleportDest>("TeleportDest".into());
+	handler_set.register_clone::<TeleportDest>();
+
+	registry.register::<TeleportLinedefTouch>("TeleportLinedefTouch".into());
+	handler_set.register_clone::<TeleportLinedefTouch>();
+
+	SystemBuilder::new("teleport_linedef_touch")
+		.read_resource::<AssetStorage>()
+		.write_resource::<Quadtree>()
+		.with_query(<(&TouchEvent, &TeleportLinedefTouch)>::query())
+		.with_query(<&LinedefRef>::query())
+		.with_query(<&MapDynamic>::query())
+		.with_query(<&Transform>::query().filter(component::<TeleportDest>()))
+		.with_query(<(
+			&BoxCollider,
+			Option<&Monster>,
+			&Physics,
+			&Transform,
+			Option<&User>,
+		)>::query())
+		.with_query(<(Entity, &BoxCollider, &Transform)>::query().filter(component::<Health>()))
+		.with_query(<(&mut Physics, &mut Transform)>::query())
+		.build(move |command_buffer, world, resources, queries| {
+			let (asset_storage, quadtree) = resources;
+			let (world0, mut world) = world.split_for_query(&queries.0);
+			let (world2, mut world) = world.split_for_query(&queries.2);
+
+			for (event, teleport_linedef_touch) in queries.0.iter(&world0) {
+				if event.collision.is_some() {
+					continue;
+				}
+
+				let params = &teleport_linedef_touch.params;
+				let linedef_ref = match queries.1.get(&world, event.entity) {
+					Ok(x) => x,
+					Err(_) => continue,
+				};
+				let map_dynamic = queries.2.get(&world2, linedef_ref.map_entity).unwrap();
+				let map = asset_storage.get(&map_dynamic.map).unwrap();
+				let linedef = &map.linedefs[linedef_ref.index];
+
+				let (box_collider, physics, transform, is_player) =
+					match queries.4.get(&world, event.other) {
+						Ok((&box_collider, monster, &physics, &transform, user)) => {
+							// Only walking things can teleport, not projectiles or particles
+							if user.is_none() && monster.is_none()
+								|| params.monsters_only && monster.is_none()
+							{
+								continue;
+							}
+
+							(box_collider, physics, transform, user.is_some())
+						}
+						Err(_) => continue,
+					};
+
+				// Only teleport when crossing the linedef from the front
+				if physics.velocity.fixed_rows::<2>(0).dot(&linedef.normal) >= 0.0 {
+					continue;
+				}
+
+				// Find a destination in the tagged sector
+				let dest_transform = match queries.3.iter(&world).find(|dest_transform| {
+					let sector_index = map
+						.find_subsector(dest_transform.position.fixed_rows::<2>(0).into_owned())
+						.sector_index;
+					map.sectors[sector_index].sector_tag == linedef.sector_tag
+				}) {
+					Some(&x) => x,
+					None => continue,
+				};
+
+				// Kill anything standing at the destination
+				let bbox = AABB3::from_radius_height(box_collider.radius, box_collider.height);
+				let dest_bbox2 = AABB2::from(bbox.offset(dest_transform.position));
+				let mut telefragged: SmallVec<[Entity; 4]> = SmallVec::new();
+
+				for (&entity, other_box_collider, other_transform) in queries.5.iter(&world) {
+					if entity == event.other
+						|| !other_box_collider
+							.blocks_types
+							.blocks(box_collider.solid_type)
+					{
+						continue;
+					}
+
+					let other_bbox = AABB3::from_radius_height(
+						other_box_collider.radius,
+						other_box_collider.height,
+					);
+
+					if dest_bbox2
+						.overlaps(&AABB2::from(other_bbox.offset(other_transform.position)))
+					{
+						telefragged.push(entity);
+					}
+				}
+
+				// Monsters don't telefrag
+				if !is_player && !telefragged.is_empty() {
+					continue;
+				}
+
+				for entity in telefragged {
+					command_buffer.push((DamageEvent {
+						entity,
+						damage: 10000,
+						source_entity: event.other,
+						direction: Vector3::zeros(),
+					},));
+				}
+
+				// Spawn fog at the old and new position
+				if let Some(fog) = &params.fog {
+					let yaw = dest_transform.rotation[2];
+					let mut dest_fog_transform = dest_transform;
+					dest_fog_transform.position +=
+						Vector3::new(yaw.cos() as f32, yaw.sin() as f32, 0.0) * 20.0;
+
+					for fog_transform in [transform, dest_fog_transform] {
+						let handle = fog.clone();
+						command_buffer.exec_mut(move |world, resources| {
+							spawn_entity(world, resources, &handle, fog_transform);
+						});
+					}
+				}
+
+				// Move the entity
+				if let Ok((physics, transform)) = queries.6.get_mut(&mut world, event.other) {
+					transform.position = dest_transform.position;
+					transform.rotation[1] = 0.into();
+					transform.rotation[2] = dest_transform.rotation[2];
+					physics.velocity = Vector3::zeros();
+
+					quadtree.remove(event.other);
+					quadtree.insert(event.other, &AABB2::from(bbox.offset(transform.position)));
+				}
+
+				if !teleport_linedef_touch.retrigger {
+					command_buffer.remove_component::<Touchable>(event.entity);
+				}
+			}
+		})
+}
@@ -1,11 +1,19 @@
+pub mod ai;
+pub mod boss;
 pub mod camera;
 pub mod cheats;
 pub mod client;
 pub mod combat;
+pub mod deathmatch;
+pub mod demo;
+pub mod item;
 pub mod map;
+pub mod net;
+pub mod options;
 pub mod physics;
 pub mod spawn;
 pub mod state;
+pub mod stats;
 pub mod trace;
 
 use crate::{
@@ -14,6 +22,7 @@ use crate::{
 		dirs::config_dir,
 		geometry::{Angle, Interval, AABB2},
 		quadtree::Quadtree,
+		random::GameRng,
 		spawn::{ComponentAccessor, SpawnContext, SpawnFrom, SpawnMergerHandlerSet},
 		time::GameTime,
 	},
@@ -25,10 +34,16 @@ use crate::{
 		clear_event,
 		draw::sprite::SpriteRender,
 		game::{
+			ai::{
+				charge_touch, follow_target, melee_attack, monster_blast_target, monster_chase,
+				monster_charge, monster_face_target, monster_look, monster_refire, monster_respawn,
+				spawn_on_target,
+			},
+			boss::{boss_death, brain_cube, brain_spit},
 			camera::{camera_move, movement_bob},
 			client::{
-				player_command, player_move, player_touch, player_use, player_weapon, Client,
-				UseEvent,
+				player_command, player_move, player_respawn, player_touch, player_use,
+				player_weapon, Client, Clients, UseEvent,
 			},
 			combat::{
 				apply_damage, extra_light, projectile_touch, radius_attack, spawn_projectile,
@@ -36,20 +51,36 @@ use crate::{
 				weapon::{
 					change_ammo_count, line_attack, next_weapon_state, set_weapon_sprite,
 					set_weapon_state, weapon_position, weapon_ready, weapon_refire,
-					WeaponStateEvent,
+					WeaponSpriteSlot, WeaponState, WeaponStateEvent,
 				},
-				DamageEvent,
+				Armor, DamageEvent, Health,
 			},
+			deathmatch::{deathmatch_limits, item_respawn},
+			demo::{demo_commands, stop_demo, DemoPlayer, DemoRecorder},
+			item::powerups,
 			map::{
 				anim::{light_flash, light_glow, texture_animation, texture_scroll},
+				ceiling::{
+					ceiling_active, ceiling_linedef_touch, ceiling_stop_linedef_touch,
+					ceiling_switch_use,
+				},
 				door::{door_active, door_linedef_touch, door_switch_use, door_use},
 				exit::exit_switch_use,
 				floor::{floor_active, floor_linedef_touch, floor_switch_use},
+				light::{light_linedef_touch, light_switch_use},
 				plat::{plat_active, plat_linedef_touch, plat_switch_use},
-				sector_move::{sector_move, SectorMoveEvent},
+				sector_move::{sector_crush, sector_move, SectorMoveEvent},
+				sector_special::{sector_special, SectorSecret},
+				stairs::{stairs_linedef_touch, stairs_switch_use},
 				switch::switch_active,
+				teleport::teleport_linedef_touch,
 				LinedefRef, MapDynamic, SectorRef,
 			},
+			net::{
+				net_client_predict, net_client_receive, net_client_send, net_server_receive,
+				net_server_send, NetClient,
+			},
+			options::GameOptions,
 			physics::{
 				physics, set_blocks_types, set_solid_type, BoxCollider, StepEvent, TouchEvent,
 				DISTANCE_EPSILON,
@@ -57,11 +88,15 @@ use crate::{
 			spawn::{spawn_map_entities, spawn_things},
 			state::{
 				entity::{next_entity_state, remove_entity, EntityStateEvent},
-				state,
+				state, StateAction, StateName,
 			},
+			stats::{CountItem, CountKill, LevelStats},
 		},
 		iwad::IWADInfo,
-		ui::hud::{ammo_stat, arms_stat, health_stat},
+		ui::{
+			hud::{ammo_stat, armor_stat, arms_stat, frags_stat, health_stat},
+			intermission::{intermission, Intermission},
+		},
 		ASSET_SERIALIZER,
 	},
 };
@@ -73,7 +108,7 @@ use legion::{
 	Entity, IntoQuery, Read, Registry, Resources, Schedule, SystemBuilder, World, Write,
 };
 use nalgebra::Vector3;
-use rand::{distributions::Uniform, thread_rng, Rng};
+use rand::{distributions::Uniform, thread_rng, Rng, SeedableRng};
 use relative_path::RelativePathBuf;
 use serde::{de::DeserializeSeed, Deserialize, Serialize};
 use std::{
@@ -83,26 +118,89 @@ use std::{
 };
 
 pub fn add_update_systems(builder: &mut Builder, resources: &mut Resources) -> anyhow::Result<()> {
+	let mut gameplay_systems = {
+		let mut builder = Schedule::builder();
+		add_gameplay_systems(&mut builder, resources)?;
+		builder.build()
+	};
+
+	let mut intermission_systems = Schedule::builder()
+		.add_thread_local_fn(net_server_receive)
+		.add_system(player_command(resources))
+		.add_thread_local_fn(demo_commands)
+		.add_system(intermission(resources))
+		.add_system(net_server_send(resources))
+		.build();
+
+	// Clients connected to a server only predict their own movement,
+	// the rest of the game is run by the server
+	#[rustfmt::skip]
+	let mut net_client_systems = Schedule::builder()
+		.add_thread_local_fn(net_client_receive)
+		.add_system(player_command(resources))
+		.add_system(net_client_send(resources))
+		.add_system(player_move(resources))
+		.add_system(physics(resources))
+		.flush()
+		.add_system(movement_bob(resources))
+		.add_system(camera_move(resources))
+		.add_system(net_client_predict(resources))
+		.add_system(clear_event::<StepEvent>())
+		.add_system(clear_event::<TouchEvent>())
+		.build();
+
+	// The game is paused while the intermission screen is showing
+	builder.add_thread_local_fn(move |world, resources| {
+		if resources.contains::<NetClient>() {
+			// Nothing to predict until the server has sent the map
+			if resources.contains::<Clients>() {
+				net_client_systems.execute(world, resources);
+			} else {
+				net_client_receive(world, resources);
+			}
+		} else if resources.contains::<Intermission>() {
+			intermission_systems.execute(world, resources);
+		} else {
+			gameplay_systems.execute(world, resources);
+		}
+	});
+
+	Ok(())
+}
+
+fn add_gameplay_systems(builder: &mut Builder, resources: &mut Resources) -> anyhow::Result<()> {
 	#[rustfmt::skip]
 	builder
+		.add_thread_local_fn(net_server_receive)
 		.add_system(player_command(resources))
+		.add_thread_local_fn(demo_commands)
 		.add_system(player_move(resources))
 		.add_system(player_weapon(resources))
 
+		.add_system(player_respawn(resources))
 		.add_system(player_use(resources))
 		.flush()
+		.add_system(ceiling_switch_use(resources))
 		.add_system(door_use(resources))
 		.add_system(door_switch_use(resources))
 		.add_system(exit_switch_use(resources))
 		.add_system(floor_switch_use(resources))
+		.add_system(light_switch_use(resources))
 		.add_system(plat_switch_use(resources))
+		.add_system(stairs_switch_use(resources))
 		.add_system(clear_event::<UseEvent>())
 
 		.add_system(physics(resources))
 		.flush()
+		.add_system(ceiling_linedef_touch(resources))
+		.add_system(ceiling_stop_linedef_touch(resources))
 		.add_system(door_linedef_touch(resources))
 		.add_system(floor_linedef_touch(resources))
+		.add_system(light_linedef_touch(resources))
 		.add_system(plat_linedef_touch(resources))
+		.add_system(stairs_linedef_touch(resources))
+		.add_system(teleport_linedef_touch(resources))
+		.add_system(charge_touch(resources))
 		.add_system(player_touch(resources))
 		.add_system(projectile_touch(resources))
 		.add_system(movement_bob(resources))
@@ -112,19 +210,28 @@ pub fn add_update_systems(builder: &mut Builder, resources: &mut Resources) -> a
 
 		.add_system(sector_move(resources))
 		.flush()
+		.add_system(ceiling_active(resources))
 		.add_system(door_active(resources))
 		.add_system(floor_active(resources))
 		.add_system(plat_active(resources))
+		.add_system(sector_crush(resources))
 		.add_system(clear_event::<SectorMoveEvent>())
 
 		.add_system(light_flash(resources))
 		.add_system(light_glow(resources))
+		.add_system(sector_special(resources))
 		.add_system(switch_active(resources))
 		.add_system(texture_animation(resources))
 		.add_system(texture_scroll(resources))
-		
+
+		.add_system(brain_cube(resources))
+		.add_system(follow_target(resources))
+		.add_system(item_respawn(resources))
+		.add_system(monster_respawn(resources))
+		.add_system(powerups(resources))
 		.add_system(apply_damage(resources))
 		.add_system(clear_event::<DamageEvent>())
+		.add_system(deathmatch_limits(resources))
 		.flush()
 
 		.add_thread_local_fn({
@@ -137,10 +244,20 @@ pub fn add_update_systems(builder: &mut Builder, resources: &mut Resources) -> a
 				.add_system(set_entity_sprite(resources))
 				.add_system(set_solid_type(resources))
 				.add_system(next_weapon_state(resources))
+				.add_system(boss_death(resources))
+				.add_system(brain_spit(resources))
 				.add_system(line_attack(resources))
+				.add_system(melee_attack(resources))
+				.add_system(monster_blast_target(resources))
+				.add_system(monster_chase(resources))
+				.add_system(monster_charge(resources))
+				.add_system(monster_face_target(resources))
+				.add_system(monster_look(resources))
+				.add_system(monster_refire(resources))
 				.add_system(radius_attack(resources))
 				.add_system(set_weapon_sprite(resources))
 				.add_system(set_weapon_state(resources))
+				.add_system(spawn_on_target(resources))
 				.add_system(spawn_projectile(resources))
 				.add_system(spray_attack(resources))
 				.add_system(weapon_position(resources))
@@ -153,13 +270,16 @@ pub fn add_update_systems(builder: &mut Builder, resources: &mut Resources) -> a
 			state(resources, actions)
 		})
 		.add_system(ammo_stat(resources))
+		.add_system(armor_stat(resources))
 		.add_system(health_stat(resources))
-		.add_system(arms_stat(resources));
+		.add_system(arms_stat(resources))
+		.add_system(frags_stat(resources))
+		.add_system(net_server_send(resources));
 
 	Ok(())
 }
 
-pub fn new_game(map: &str, world: &mut World, resources: &mut Resources) {
+pub fn new_game(map: &str, options: GameOptions, world: &mut World, resources: &mut Resources) {
 	let mut map = RelativePathBuf::from(map.to_ascii_lowercase());
 	map.set_extension("map");
 
@@ -169,6 +289,10 @@ pub fn new_game(map: &str, world: &mut World, resources: &mut Resources) {
 
 	let result = || -> anyhow::Result<()> {
 		resources.insert(GameTime::default());
+		resources.insert(GameRng::seed_from_u64(
+			options.seed.unwrap_or_else(|| thread_rng().gen()),
+		));
+		resources.insert(options);
 
 		log::info!("Loading map...");
 		let map_handle: AssetHandle<Map> = {
@@ -188,13 +312,22 @@ pub fn new_game(map: &str, world: &mut World, resources: &mut Resources) {
 		};
 		spawn_things(things, world, resources)?;
 
-		// Spawn player
-		let entity = spawn::spawn_player(world, resources, 1)?;
-		resources.insert(Client {
-			entity: Some(entity),
-			..Client::default()
+		resources.insert(LevelStats {
+			kills_total: <&CountKill>::query().iter(world).count(),
+			items_total: <&CountItem>::query().iter(world).count(),
+			secrets_total: <&SectorSecret>::query().iter(world).count(),
+			..LevelStats::default()
 		});
 
+		// Spawn players
+		let clients = (1..=options.players.max(1))
+			.map(|player_num| {
+				let entity = spawn::spawn_player(world, resources, player_num)?;
+				Ok(Client::new(player_num, entity))
+			})
+			.collect::<anyhow::Result<Vec<_>>>()?;
+		resources.insert(Clients { clients, local: 0 });
+
 		process_assets(resources);
 
 		Ok(())
@@ -212,14 +345,102 @@ pub fn change_map(map: &str, world: &mut World, resources: &mut Resources) {
 		return;
 	}
 
-	// TODO continue existing game
-	new_game(map, world, resources);
+	// Carry the players' inventories over to the next map, unless they died
+	let inventories: Vec<(usize, PlayerInventory)> = {
+		let clients = <Read<Clients>>::fetch(resources);
+		clients
+			.clients
+			.iter()
+			.filter_map(|client| {
+				client
+					.entity
+					.and_then(|entity| {
+						<(&Armor, &Health, &WeaponState)>::query()
+							.get(world, entity)
+							.ok()
+					})
+					.filter(|(_, health, _)| health.current > 0)
+					.map(|(&armor, health, weapon_state)| {
+						(
+							client.player_num,
+							PlayerInventory {
+								armor,
+								health: health.clone(),
+								weapon_state: weapon_state.clone(),
+							},
+						)
+					})
+			})
+			.collect()
+	};
+
+	// Keep the random sequence going across maps, so the whole game follows from the seed
+	let game_rng = resources.remove::<GameRng>();
+	let demo_player = resources.remove::<DemoPlayer>();
+	let demo_recorder = resources.remove::<DemoRecorder>();
+	let options = *<Read<GameOptions>>::fetch(resources);
+	new_game(map, options, world, resources);
+
+	if resources.contains::<GameRng>() {
+		if let Some(game_rng) = game_rng {
+			resources.insert(game_rng);
+		}
+
+		if let Some(demo_player) = demo_player {
+			resources.insert(demo_player);
+		}
+
+		if let Some(demo_recorder) = demo_recorder {
+			resources.insert(demo_recorder);
+		}
+	}
+
+	for (player_num, inventory) in inventories {
+		let clients = match resources.get::<Clients>() {
+			Some(x) => x,
+			None => break,
+		};
+		let entity = clients
+			.clients
+			.iter()
+			.find(|client| client.player_num == player_num)
+			.and_then(|client| client.entity);
+
+		if let Some((armor, health, weapon_state)) = entity.and_then(|entity| {
+			<(&mut Armor, &mut Health, &mut WeaponState)>::query()
+				.get_mut(world, entity)
+				.ok()
+		}) {
+			*armor = inventory.armor;
+			health.current = inventory.health.current;
+			weapon_state.inventory = inventory.weapon_state.inventory;
+			weapon_state.ammo = inventory.weapon_state.ammo;
+
+			// Bring up the carried weapon from the start, instead of the one the player
+			// spawned with
+			weapon_state.current = inventory.weapon_state.current;
+			weapon_state.switch_to = None;
+			weapon_state.slots[WeaponSpriteSlot::Weapon as usize].action =
+				StateAction::Set((StateName::from("up").unwrap(), 0));
+			weapon_state.slots[WeaponSpriteSlot::Flash as usize].action = StateAction::None;
+		}
+	}
+}
+
+/// Parts of a player's state that are kept when moving on to the next map.
+struct PlayerInventory {
+	armor: Armor,
+	health: Health,
+	weapon_state: WeaponState,
 }
 
 #[derive(Serialize, Deserialize)]
 struct SavedResources {
-	client: Client,
+	clients: Clients,
+	game_options: GameOptions,
+	game_rng: GameRng,
 	game_time: GameTime,
+	level_stats: LevelStats,
 }
 
 #[inline]
@@ -276,17 +497,32 @@ pub fn save_game(name: &str, world: &mut World, resources: &mut Resources) {
 		})
 		.and_then(|file| {
 			let mut file = BufWriter::new(file);
-			let (canon, client, game_time, registry, mut asset_storage) = <(
+			let (
+				canon,
+				clients,
+				game_options,
+				game_rng,
+				game_time,
+				level_stats,
+				registry,
+				mut asset_storage,
+			) = <(
 				Read<Canon>,
-				Read<Client>,
+				Read<Clients>,
+				Read<GameOptions>,
+				Read<GameRng>,
 				Read<GameTime>,
+				Read<LevelStats>,
 				Read<Registry<String>>,
 				Write<AssetStorage>,
 			)>::fetch_mut(resources);
 
 			let saved_resources = SavedResources {
-				client: client.clone(),
+				clients: clients.clone(),
+				game_options: *game_options,
+				game_rng: game_rng.clone(),
 				game_time: *game_time,
+				level_stats: *level_stats,
 			};
 
 			ASSET_SERIALIZER.set(&mut asset_storage, || -> anyhow::Result<()> {
@@ -350,8 +586,11 @@ pub fn load_game(name: &str, world: &mut World, resources: &mut Resources) {
 
 	match result {
 		Ok(saved_resources) => {
-			resources.insert(saved_resources.client);
+			resources.insert(saved_resources.clients);
+			resources.insert(saved_resources.game_options);
+			resources.insert(saved_resources.game_rng);
 			resources.insert(saved_resources.game_time);
+			resources.insert(saved_resources.level_stats);
 
 			process_assets(resources);
 
@@ -366,10 +605,21 @@ pub fn load_game(name: &str, world: &mut World, resources: &mut Resources) {
 
 pub fn clear_game(world: &mut World, resources: &mut Resources) {
 	log::debug!("Clearing game...");
+	stop_demo(resources);
 	let mut command_buffer = CommandBuffer::new(world);
+
+	if let Some(intermission) = resources.remove::<Intermission>() {
+		for entity in intermission.entities {
+			command_buffer.remove(entity);
+		}
+	}
+
 	command_buffer.exec_mut(|_, resources| {
-		resources.remove::<Client>();
+		resources.remove::<Clients>();
+		resources.remove::<GameOptions>();
+		resources.remove::<GameRng>();
 		resources.remove::<GameTime>();
+		resources.remove::<LevelStats>();
 		resources.remove::<Quadtree>();
 	});
 	for &entity in <Entity>::query().filter(game_entities!()).iter(world) {
@@ -444,7 +694,8 @@ impl SpawnFrom<RandomTransformDef> for Transform {
 	) -> Self {
 		let transform = <Read<SpawnContext<Transform>>>::fetch(resources);
 		let mut transform = transform.0;
-		let offset = Vector3::from_iterator(component.0.iter().map(|u| thread_rng().sample(u)));
+		let mut game_rng = resources.get_mut::<GameRng>().unwrap();
+		let offset = Vector3::from_iterator(component.0.iter().map(|u| game_rng.sample(u)));
 		transform.position += offset;
 		transform
 	}
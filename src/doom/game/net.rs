@@ -0,0 +1,1105 @@
+//! Playing over the network. One game acts as the server, running the game for everyone, while
+//! the others connect to it as clients. Clients send the server their commands, and the server
+//! sends back snapshots of the state of the world.
+
+use crate::{
+	common::{
+		assets::{AssetHandle, AssetStorage, ASSET_SERIALIZER},
+		geometry::{AABB2, AABB3},
+		quadtree::Quadtree,
+	},
+	doom::{
+		assets::{
+			image::Image,
+			template::{EntityTemplate, EntityTemplateRef},
+		},
+		draw::sprite::SpriteRender,
+		game::{
+			clear_game,
+			client::{Client, Clients},
+			create_quadtree,
+			map::{AnimState, LinedefDynamic, MapDynamic, SectorDynamic},
+			new_game,
+			options::GameOptions,
+			physics::{BoxCollider, Physics},
+			spawn::{spawn_entity, spawn_player},
+			state::State,
+			Transform,
+		},
+		input::UserCommand,
+	},
+};
+use anyhow::Context;
+use fnv::FnvHashMap;
+use legion::{
+	component,
+	serialize::{set_entity_serializer, Canon},
+	systems::{CommandBuffer, ResourceSet, Runnable},
+	Entity, IntoQuery, Read, Resources, SystemBuilder, World, Write,
+};
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::{
+	collections::VecDeque,
+	io::ErrorKind,
+	net::{SocketAddr, ToSocketAddrs, UdpSocket},
+	time::{Duration, Instant},
+};
+
+/// The port that servers listen on if no other is given.
+pub const DEFAULT_PORT: u16 = 5029;
+
+const MAX_PLAYERS: usize = 4;
+
+/// Largest amount of data that fits in a single UDP datagram.
+const MAX_DATAGRAM_SIZE: usize = 65507;
+
+/// How much map and entity data the server puts in one datagram before starting another.
+const SNAPSHOT_BUDGET: usize = 32768;
+
+/// Every this many ticks, the server sends every entity to the clients, not only the ones that
+/// changed. This way, clients recover from any lost datagrams.
+const FULL_SNAPSHOT_INTERVAL: u32 = 35;
+
+/// How many commands the server keeps waiting for a client, before it starts dropping old ones.
+const MAX_QUEUED_COMMANDS: usize = 8;
+
+/// How many predicted positions a client remembers, to compare with the server's.
+const MAX_PREDICTED: usize = 64;
+
+/// Prediction errors below this distance are ignored.
+const PREDICTION_TOLERANCE: f32 = 0.5;
+
+/// Prediction errors above this distance make the client jump straight to the server's
+/// transform, as happens when teleporting.
+const PREDICTION_SNAP: f32 = 64.0;
+
+const CONNECT_RETRY: Duration = Duration::from_secs(1);
+const TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum ClientMessage {
+	Connect,
+	Command { sequence: u32, command: UserCommand },
+	Disconnect,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum ServerMessage {
+	Welcome { player_num: usize },
+	Snapshot(SnapshotHeader),
+	Disconnect,
+}
+
+/// Start of a snapshot datagram. It is followed by `map_updates` separately serialized
+/// `MapUpdate`s and then `updates` `EntityUpdate`s, so that the server can serialize each part
+/// of the world only once for all clients.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SnapshotHeader {
+	tick: u32,
+	map: String,
+	options: GameOptions,
+	player_entity: Option<Entity>,
+
+	/// Sequence number of the last command of the client that the server carried out.
+	last_command: u32,
+
+	removed: Vec<Entity>,
+	map_updates: usize,
+	updates: usize,
+}
+
+/// A part of the `MapDynamic` that changed. The map is sent in parts, because all of it
+/// together does not fit in a datagram on large maps.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum MapUpdate {
+	AnimStates(FnvHashMap<AssetHandle<Image>, AnimState>),
+	Linedef(usize, LinedefDynamic),
+	Sector(usize, SectorDynamic),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct EntityUpdate {
+	entity: Entity,
+	template: AssetHandle<EntityTemplate>,
+	transform: Transform,
+	sprite_render: SpriteRender,
+	state: Option<State>,
+}
+
+fn send_message<M: Serialize>(socket: &UdpSocket, address: SocketAddr, message: &M) {
+	let result = rmp_serde::to_vec(message)
+		.context("Couldn't serialize message")
+		.and_then(|datagram| {
+			socket
+				.send_to(&datagram, address)
+				.with_context(|| format!("Couldn't send to {}", address))
+		});
+
+	if let Err(err) = result {
+		log::warn!("{:?}", err);
+	}
+}
+
+/// Returns the first datagram waiting on the socket, or `None` if there are no more.
+fn receive(socket: &UdpSocket, buffer: &mut [u8]) -> Option<(usize, SocketAddr)> {
+	loop {
+		match socket.recv_from(buffer) {
+			Ok(x) => return Some(x),
+			Err(err) if err.kind() == ErrorKind::WouldBlock => return None,
+			// Sent when a datagram could not be delivered to the other side, ignore it
+			Err(err) if err.kind() == ErrorKind::ConnectionReset => continue,
+			Err(err) => {
+				log::warn!("Couldn't receive from the network: {}", err);
+				return None;
+			}
+		}
+	}
+}
+
+/// Resource for a game that other players can connect to.
+pub struct NetServer {
+	socket: UdpSocket,
+	buffer: Vec<u8>,
+	peers: Vec<Peer>,
+	tick: u32,
+
+	/// The entity updates that were last sent out, to find out which entities changed.
+	sent: FnvHashMap<Entity, Vec<u8>>,
+	sent_map: Vec<Vec<u8>>,
+
+	/// Entities that were removed recently, and the tick they were removed on.
+	removed: Vec<(Entity, u32)>,
+}
+
+struct Peer {
+	address: SocketAddr,
+	player_num: usize,
+	last_heard: Instant,
+	last_sequence: u32,
+	needs_full: bool,
+}
+
+/// Starts accepting players over the network on the given port, with this game as the server.
+pub fn host(port: u16, resources: &mut Resources) {
+	if resources.contains::<NetClient>() {
+		log::error!("Can't host a game while connected to another.");
+		return;
+	}
+
+	if resources.contains::<NetServer>() {
+		log::error!("Already hosting a game.");
+		return;
+	}
+
+	let result = UdpSocket::bind(("0.0.0.0", port))
+		.and_then(|socket| socket.set_nonblocking(true).map(|_| socket))
+		.with_context(|| format!("Couldn't listen on port {}", port));
+
+	match result {
+		Ok(socket) => {
+			resources.insert(NetServer {
+				socket,
+				buffer: vec![0; MAX_DATAGRAM_SIZE],
+				peers: Vec::new(),
+				tick: 0,
+				sent: FnvHashMap::default(),
+				sent_map: Vec::new(),
+				removed: Vec::new(),
+			});
+			log::info!("Listening for players on port {}.", port);
+		}
+		Err(err) => log::error!("{:?}", err),
+	}
+}
+
+pub fn net_server_receive(world: &mut World, resources: &mut Resources) {
+	let mut net_server = match resources.remove::<NetServer>() {
+		Some(x) => x,
+		None => return,
+	};
+
+	while let Some((len, address)) = receive(&net_server.socket, &mut net_server.buffer) {
+		let message: ClientMessage = match rmp_serde::from_read_ref(&net_server.buffer[..len]) {
+			Ok(x) => x,
+			Err(err) => {
+				log::debug!("Invalid message from {}: {}", address, err);
+				continue;
+			}
+		};
+
+		let peer_index = net_server
+			.peers
+			.iter()
+			.position(|peer| peer.address == address);
+
+		match message {
+			ClientMessage::Connect => {
+				let player_num = match peer_index {
+					Some(index) => net_server.peers[index].player_num,
+					None => match add_player(world, resources, &net_server, address) {
+						Some(player_num) => {
+							net_server.peers.push(Peer {
+								address,
+								player_num,
+								last_heard: Instant::now(),
+								last_sequence: 0,
+								needs_full: true,
+							});
+							player_num
+						}
+						None => continue,
+					},
+				};
+
+				send_message(
+					&net_server.socket,
+					address,
+					&ServerMessage::Welcome { player_num },
+				);
+			}
+			ClientMessage::Command { sequence, command } => {
+				let peer = match peer_index {
+					Some(index) => &mut net_server.peers[index],
+					None => continue,
+				};
+				peer.last_heard = Instant::now();
+
+				// Datagrams can arrive out of order, drop anything older than what we have
+				if sequence <= peer.last_sequence {
+					continue;
+				}
+
+				peer.last_sequence = sequence;
+
+				if let Some(mut clients) = resources.get_mut::<Clients>() {
+					if let Some(client) = clients
+						.clients
+						.iter_mut()
+						.find(|client| client.player_num == peer.player_num)
+					{
+						client.queued_commands.push_back(command);
+
+						if client.queued_commands.len() > MAX_QUEUED_COMMANDS {
+							client.queued_commands.pop_front();
+						}
+					}
+				}
+			}
+			ClientMessage::Disconnect => {
+				if let Some(index) = peer_index {
+					let peer = net_server.peers.remove(index);
+					log::info!("Player {} left the game.", peer.player_num);
+					remove_player(world, resources, peer.player_num);
+				}
+			}
+		}
+	}
+
+	// Drop players we haven't heard from in a while
+	let (kept, timed_out) = net_server
+		.peers
+		.drain(..)
+		.partition(|peer| peer.last_heard.elapsed() < TIMEOUT);
+	net_server.peers = kept;
+
+	for peer in timed_out {
+		log::info!("Player {} timed out.", peer.player_num);
+		remove_player(world, resources, peer.player_num);
+	}
+
+	resources.insert(net_server);
+}
+
+/// Gives a newly connected player a place in the game, and returns their player number.
+fn add_player(
+	world: &mut World,
+	resources: &mut Resources,
+	net_server: &NetServer,
+	address: SocketAddr,
+) -> Option<usize> {
+	// Players can only join a game that is running
+	if !resources.contains::<Clients>() {
+		return None;
+	}
+
+	// Player 1 is the one playing on the server
+	let player_num = match (2..=MAX_PLAYERS)
+		.find(|&num| net_server.peers.iter().all(|peer| peer.player_num != num))
+	{
+		Some(x) => x,
+		None => {
+			log::info!("Player from {} couldn't join, the game is full.", address);
+			send_message(&net_server.socket, address, &ServerMessage::Disconnect);
+			return None;
+		}
+	};
+
+	// A player might still be in the game from before, if they came in on a map change
+	let has_client = <Read<Clients>>::fetch(resources)
+		.clients
+		.iter()
+		.any(|client| client.player_num == player_num);
+
+	if !has_client {
+		let entity = match spawn_player(world, resources, player_num) {
+			Ok(x) => x,
+			Err(err) => {
+				log::error!("{:?}", err);
+				return None;
+			}
+		};
+
+		let mut clients = <Write<Clients>>::fetch_mut(resources);
+		clients.clients.push(Client::new(player_num, entity));
+	}
+
+	// Make sure the player is spawned again when the map changes
+	let mut game_options = <Write<GameOptions>>::fetch_mut(resources);
+	game_options.players = game_options.players.max(player_num);
+
+	log::info!("Player {} joined the game from {}.", player_num, address);
+	Some(player_num)
+}
+
+fn remove_player(world: &mut World, resources: &mut Resources, player_num: usize) {
+	let entity = match resources.get_mut::<Clients>() {
+		Some(mut clients) => {
+			match clients
+				.clients
+				.iter()
+				.position(|client| client.player_num == player_num)
+			{
+				Some(index) => clients.clients.remove(index).entity,
+				None => None,
+			}
+		}
+		None => None,
+	};
+
+	if let Some(entity) = entity {
+		if let Some(mut quadtree) = resources.get_mut::<Quadtree>() {
+			quadtree.remove(entity);
+		}
+
+		world.remove(entity);
+	}
+}
+
+pub fn net_server_send(_resources: &mut Resources) -> impl Runnable {
+	SystemBuilder::new("net_server_send")
+		.read_resource::<Canon>()
+		.read_resource::<Clients>()
+		.read_resource::<GameOptions>()
+		.write_resource::<AssetStorage>()
+		.write_resource::<NetServer>()
+		.with_query(<(
+			Entity,
+			&EntityTemplateRef,
+			&Transform,
+			&SpriteRender,
+			Option<&State>,
+		)>::query())
+		.with_query(<&MapDynamic>::query())
+		.build(move |_command_buffer, world, resources, queries| {
+			let (canon, clients, game_options, asset_storage, net_server) = resources;
+			let net_server: &mut NetServer = &mut *net_server; // This prevents borrow errors
+
+			if net_server.peers.is_empty() {
+				return;
+			}
+
+			net_server.tick += 1;
+			let tick = net_server.tick;
+			let full_tick = tick % FULL_SNAPSHOT_INTERVAL == 0;
+
+			let map_dynamic = match queries.1.iter(world).next() {
+				Some(x) => x,
+				None => return,
+			};
+			let map = asset_storage.name_for(&map_dynamic.map).unwrap().to_owned();
+
+			ASSET_SERIALIZER.set(asset_storage, || {
+				set_entity_serializer(&**canon, || {
+					// Serialize each entity once, and compare with what was sent last time
+					let mut sent = FnvHashMap::default();
+					let mut changed = Vec::new();
+
+					for (&entity, template_ref, &transform, sprite_render, state) in
+						queries.0.iter(world)
+					{
+						let update = EntityUpdate {
+							entity,
+							template: template_ref.0.clone(),
+							transform,
+							sprite_render: sprite_render.clone(),
+							state: state.cloned(),
+						};
+						let bytes = rmp_serde::to_vec(&update).unwrap();
+
+						if net_server.sent.get(&entity) != Some(&bytes) {
+							changed.push(entity);
+						}
+
+						sent.insert(entity, bytes);
+					}
+
+					let old_sent = std::mem::replace(&mut net_server.sent, sent);
+					net_server.removed.extend(
+						old_sent
+							.into_keys()
+							.filter(|entity| !net_server.sent.contains_key(entity))
+							.map(|entity| (entity, tick)),
+					);
+
+					// Keep telling clients about removed entities for a while,
+					// in case a datagram gets lost
+					net_server
+						.removed
+						.retain(|&(_, removed_tick)| tick - removed_tick < FULL_SNAPSHOT_INTERVAL);
+					let removed: Vec<Entity> = net_server
+						.removed
+						.iter()
+						.map(|&(entity, _)| entity)
+						.collect();
+
+					// The same for the parts of the map
+					let sent_map: Vec<Vec<u8>> =
+						std::iter::once(MapUpdate::AnimStates(map_dynamic.anim_states.clone()))
+							.chain(map_dynamic.linedefs.iter().cloned().enumerate().map(
+								|(index, linedef_dynamic)| {
+									MapUpdate::Linedef(index, linedef_dynamic)
+								},
+							))
+							.chain(map_dynamic.sectors.iter().cloned().enumerate().map(
+								|(index, sector_dynamic)| MapUpdate::Sector(index, sector_dynamic),
+							))
+							.map(|map_update| rmp_serde::to_vec(&map_update).unwrap())
+							.collect();
+					let map_changed: Vec<usize> = (0..sent_map.len())
+						.filter(|&index| net_server.sent_map.get(index) != Some(&sent_map[index]))
+						.collect();
+					net_server.sent_map = sent_map;
+
+					let all: Vec<&[u8]> = net_server.sent.values().map(Vec::as_slice).collect();
+					let changed: Vec<&[u8]> = changed
+						.iter()
+						.map(|entity| net_server.sent[entity].as_slice())
+						.collect();
+					let map_all: Vec<&[u8]> =
+						net_server.sent_map.iter().map(Vec::as_slice).collect();
+					let map_changed: Vec<&[u8]> = map_changed
+						.iter()
+						.map(|&index| net_server.sent_map[index].as_slice())
+						.collect();
+
+					for peer in net_server.peers.iter_mut() {
+						let full = full_tick || peer.needs_full;
+						peer.needs_full = false;
+
+						let client = clients
+							.clients
+							.iter()
+							.find(|client| client.player_num == peer.player_num);
+						let player_entity = client.and_then(|client| client.entity);
+
+						// The server takes one command from the queue every tick,
+						// so the ones still in the queue haven't been carried out yet
+						let queued = client.map_or(0, |client| client.queued_commands.len());
+						let last_command = peer.last_sequence.saturating_sub(queued as u32);
+
+						let mut map_updates = if full { &map_all[..] } else { &map_changed[..] };
+						let mut updates = if full { &all[..] } else { &changed[..] };
+						let mut first = true;
+
+						// Split the updates over as many datagrams as needed,
+						// the map first and then the entities
+						loop {
+							let mut size = 0;
+							let mut fits = |bytes: &&&[u8]| {
+								size += bytes.len();
+								size <= SNAPSHOT_BUDGET
+							};
+							let mut map_count = map_updates.iter().take_while(&mut fits).count();
+							let mut count = updates.iter().take_while(&mut fits).count();
+
+							// Always send at least one update, even if it's over budget
+							if map_count == 0 && count == 0 {
+								if map_updates.is_empty() {
+									count = updates.len().min(1);
+								} else {
+									map_count = 1;
+								}
+							}
+
+							let (map_chunk, map_rest) = map_updates.split_at(map_count);
+							let (chunk, rest) = updates.split_at(count);
+
+							let header = SnapshotHeader {
+								tick,
+								map: map.clone(),
+								options: **game_options,
+								player_entity,
+								last_command,
+								removed: if first { removed.clone() } else { Vec::new() },
+								map_updates: map_chunk.len(),
+								updates: chunk.len(),
+							};
+
+							let mut datagram =
+								rmp_serde::to_vec(&ServerMessage::Snapshot(header)).unwrap();
+
+							for bytes in map_chunk.iter().chain(chunk) {
+								datagram.extend_from_slice(bytes);
+							}
+
+							if datagram.len() > MAX_DATAGRAM_SIZE {
+								log::warn!(
+									"Couldn't send to {}: snapshot of {} bytes is too large",
+									peer.address,
+									datagram.len(),
+								);
+							} else if let Err(err) =
+								net_server.socket.send_to(&datagram, peer.address)
+							{
+								log::warn!("Couldn't send to {}: {}", peer.address, err);
+							}
+
+							map_updates = map_rest;
+							updates = rest;
+							first = false;
+
+							if map_updates.is_empty() && updates.is_empty() {
+								break;
+							}
+						}
+					}
+				})
+			});
+		})
+}
+
+/// Resource for a game that is connected to a server.
+pub struct NetClient {
+	socket: UdpSocket,
+	server: SocketAddr,
+	buffer: Vec<u8>,
+	player_num: Option<usize>,
+	last_connect: Option<Instant>,
+	last_heard: Instant,
+	sequence: u32,
+	tick: u32,
+	map: Option<String>,
+
+	/// The entities that the server told us about, the local entity they are shown with,
+	/// and the last tick they were updated.
+	entities: FnvHashMap<Entity, (Entity, u32)>,
+
+	/// Where the local player ended up after carrying out each command.
+	predicted: VecDeque<(u32, Vector3<f32>)>,
+}
+
+/// Connects to a server, and joins the game running on it. If no port is given, the default
+/// port is used. If no address is given at all, the server is looked for on this machine.
+pub fn connect(address: Option<&str>, world: &mut World, resources: &mut Resources) {
+	if resources.contains::<NetServer>() {
+		log::error!("Can't connect to another game while hosting one.");
+		return;
+	}
+
+	if resources.contains::<NetClient>() {
+		disconnect(world, resources);
+	}
+
+	let address = address.unwrap_or("127.0.0.1");
+	let result = address
+		.to_socket_addrs()
+		.or_else(|_| (address, DEFAULT_PORT).to_socket_addrs())
+		.ok()
+		.and_then(|mut addresses| addresses.find(SocketAddr::is_ipv4))
+		.with_context(|| format!("Couldn't find server \"{}\"", address))
+		.and_then(|server| {
+			let socket = UdpSocket::bind(("0.0.0.0", 0))
+				.and_then(|socket| socket.set_nonblocking(true).map(|_| socket))
+				.context("Couldn't open socket")?;
+			Ok((socket, server))
+		});
+
+	match result {
+		Ok((socket, server)) => {
+			clear_game(world, resources);
+			resources.insert(NetClient {
+				socket,
+				server,
+				buffer: vec![0; MAX_DATAGRAM_SIZE],
+				player_num: None,
+				last_connect: None,
+				last_heard: Instant::now(),
+				sequence: 0,
+				tick: 0,
+				map: None,
+				entities: FnvHashMap::default(),
+				predicted: VecDeque::new(),
+			});
+			log::info!("Connecting to {}...", server);
+		}
+		Err(err) => log::error!("{:?}", err),
+	}
+}
+
+/// Leaves the game on the server we're connected to, or stops hosting a game.
+pub fn disconnect(world: &mut World, resources: &mut Resources) {
+	if let Some(net_client) = resources.remove::<NetClient>() {
+		send_message(
+			&net_client.socket,
+			net_client.server,
+			&ClientMessage::Disconnect,
+		);
+		clear_game(world, resources);
+		log::info!("Disconnected from {}.", net_client.server);
+	} else if let Some(net_server) = resources.remove::<NetServer>() {
+		for peer in &net_server.peers {
+			send_message(&net_server.socket, peer.address, &ServerMessage::Disconnect);
+			remove_player(world, resources, peer.player_num);
+		}
+
+		log::info!("Stopped hosting the game.");
+	} else {
+		log::error!("Not connected to any game.");
+	}
+}
+
+pub fn net_client_receive(world: &mut World, resources: &mut Resources) {
+	let mut net_client = match resources.remove::<NetClient>() {
+		Some(x) => x,
+		None => return,
+	};
+
+	// Keep asking to join until the server answers
+	if net_client.player_num.is_none()
+		&& net_client
+			.last_connect
+			.map_or(true, |last| last.elapsed() >= CONNECT_RETRY)
+	{
+		send_message(
+			&net_client.socket,
+			net_client.server,
+			&ClientMessage::Connect,
+		);
+		net_client.last_connect = Some(Instant::now());
+	}
+
+	while let Some((len, address)) = receive(&net_client.socket, &mut net_client.buffer) {
+		if address != net_client.server {
+			continue;
+		}
+
+		let (message, map_updates, updates) =
+			match decode_server_message(&net_client.buffer[..len], resources) {
+				Ok(x) => x,
+				Err(err) => {
+					log::debug!("Invalid message from server: {:?}", err);
+					continue;
+				}
+			};
+
+		net_client.last_heard = Instant::now();
+
+		match message {
+			ServerMessage::Welcome { player_num } => {
+				if net_client.player_num.is_none() {
+					log::info!("Joined the game as player {}.", player_num);
+					net_client.player_num = Some(player_num);
+				}
+			}
+			ServerMessage::Snapshot(header) => {
+				// Datagrams can arrive out of order, drop anything older than what we have
+				if net_client.player_num.is_none() || header.tick < net_client.tick {
+					continue;
+				}
+
+				apply_snapshot(
+					world,
+					resources,
+					&mut net_client,
+					header,
+					map_updates,
+					updates,
+				);
+			}
+			ServerMessage::Disconnect => {
+				log::info!("The server ended the connection.");
+				clear_game(world, resources);
+				return;
+			}
+		}
+	}
+
+	if net_client.last_heard.elapsed() >= TIMEOUT {
+		log::error!("Lost connection to the server.");
+		clear_game(world, resources);
+		return;
+	}
+
+	resources.insert(net_client);
+}
+
+fn decode_server_message(
+	datagram: &[u8],
+	resources: &mut Resources,
+) -> anyhow::Result<(ServerMessage, Vec<MapUpdate>, Vec<EntityUpdate>)> {
+	let (canon, mut asset_storage) = <(Read<Canon>, Write<AssetStorage>)>::fetch_mut(resources);
+
+	ASSET_SERIALIZER.set(&mut asset_storage, || {
+		set_entity_serializer(&*canon, || -> anyhow::Result<_> {
+			let mut deserializer = rmp_serde::decode::Deserializer::new(datagram);
+			let message = ServerMessage::deserialize(&mut deserializer)?;
+			let (map_count, count) = match &message {
+				ServerMessage::Snapshot(header) => (header.map_updates, header.updates),
+				_ => (0, 0),
+			};
+			let map_updates = (0..map_count)
+				.map(|_| MapUpdate::deserialize(&mut deserializer))
+				.collect::<Result<Vec<_>, _>>()?;
+			let updates = (0..count)
+				.map(|_| EntityUpdate::deserialize(&mut deserializer))
+				.collect::<Result<Vec<_>, _>>()?;
+
+			Ok((message, map_updates, updates))
+		})
+	})
+}
+
+fn apply_snapshot(
+	world: &mut World,
+	resources: &mut Resources,
+	net_client: &mut NetClient,
+	header: SnapshotHeader,
+	map_updates: Vec<MapUpdate>,
+	updates: Vec<EntityUpdate>,
+) {
+	net_client.tick = header.tick;
+
+	// Load the map the server is on
+	if net_client.map.as_ref() != Some(&header.map) {
+		let options = GameOptions {
+			players: 1,
+			..header.options
+		};
+		new_game(&header.map, options, world, resources);
+
+		if !resources.contains::<Clients>() {
+			return;
+		}
+
+		// The things on the map are sent by the server instead
+		let mut command_buffer = CommandBuffer::new(world);
+
+		for &entity in <Entity>::query()
+			.filter(component::<EntityTemplateRef>() & component::<Transform>())
+			.iter(world)
+		{
+			command_buffer.remove(entity);
+		}
+
+		command_buffer.flush(world, resources);
+		let quadtree = create_quadtree(world, resources);
+		resources.insert(quadtree);
+
+		resources.insert(Clients {
+			clients: vec![Client {
+				player_num: net_client.player_num.unwrap(),
+				..Client::default()
+			}],
+			local: 0,
+		});
+
+		net_client.map = Some(header.map.clone());
+		net_client.entities.clear();
+		net_client.predicted.clear();
+	}
+
+	if let Some(map_dynamic) = <&mut MapDynamic>::query().iter_mut(world).next() {
+		for map_update in map_updates {
+			match map_update {
+				MapUpdate::AnimStates(anim_states) => map_dynamic.anim_states = anim_states,
+				MapUpdate::Linedef(index, linedef_dynamic) => {
+					if let Some(x) = map_dynamic.linedefs.get_mut(index) {
+						*x = linedef_dynamic;
+					}
+				}
+				MapUpdate::Sector(index, sector_dynamic) => {
+					if let Some(x) = map_dynamic.sectors.get_mut(index) {
+						*x = sector_dynamic;
+					}
+				}
+			}
+		}
+	}
+
+	for update in updates {
+		let is_player = header.player_entity == Some(update.entity);
+		let entity = match net_client.entities.get(&update.entity) {
+			Some(&(entity, _)) if world.contains(entity) => {
+				if is_player {
+					predict_correct(world, net_client, entity, &header, update.transform);
+				} else {
+					set_transform(world, resources, entity, update.transform);
+				}
+
+				entity
+			}
+			_ => {
+				let entity = spawn_entity(world, resources, &update.template, update.transform);
+
+				// Only the local player moves by itself, everything else goes where it's told
+				if !is_player {
+					world.entry(entity).unwrap().remove_component::<Physics>();
+				}
+
+				entity
+			}
+		};
+
+		net_client
+			.entities
+			.insert(update.entity, (entity, header.tick));
+
+		let mut entry = world.entry(entity).unwrap();
+		entry.add_component(update.sprite_render);
+
+		if let Some(state) = update.state {
+			entry.add_component(state);
+		}
+	}
+
+	// Remove entities that the server removed, or hasn't mentioned in a long time
+	let stale: Vec<Entity> = net_client
+		.entities
+		.iter()
+		.filter(|&(entity, &(_, tick))| {
+			header.removed.contains(entity) || header.tick - tick > 3 * FULL_SNAPSHOT_INTERVAL
+		})
+		.map(|(&entity, _)| entity)
+		.collect();
+
+	for entity in stale {
+		let (local, _) = net_client.entities.remove(&entity).unwrap();
+
+		if let Some(mut quadtree) = resources.get_mut::<Quadtree>() {
+			quadtree.remove(local);
+		}
+
+		world.remove(local);
+	}
+
+	let player_entity = header
+		.player_entity
+		.and_then(|entity| net_client.entities.get(&entity))
+		.map(|&(local, _)| local);
+
+	if let Some(mut clients) = resources.get_mut::<Clients>() {
+		clients.clients[0].entity = player_entity;
+	}
+}
+
+fn set_transform(world: &mut World, resources: &Resources, entity: Entity, transform: Transform) {
+	let mut entry = world.entry(entity).unwrap();
+
+	if let Ok(box_collider) = entry.get_component::<BoxCollider>() {
+		let bbox = AABB3::from_radius_height(box_collider.radius, box_collider.height);
+		if let Some(mut quadtree) = resources.get_mut::<Quadtree>() {
+			quadtree.remove(entity);
+			quadtree.insert(entity, &AABB2::from(bbox.offset(transform.position)));
+		}
+	}
+
+	entry.add_component(transform);
+}
+
+/// Compares the server's position for the local player with where we predicted it would be,
+/// and moves the player by the difference.
+fn predict_correct(
+	world: &mut World,
+	net_client: &mut NetClient,
+	entity: Entity,
+	header: &SnapshotHeader,
+	server_transform: Transform,
+) {
+	let mut query = <&mut Transform>::query();
+	let transform = match query.get_mut(world, entity) {
+		Ok(x) => x,
+		Err(_) => return,
+	};
+
+	// Forget predictions for commands that the server has already carried out
+	let predicted = loop {
+		match net_client.predicted.front() {
+			Some(&(sequence, _)) if sequence < header.last_command => {
+				net_client.predicted.pop_front();
+			}
+			Some(&(sequence, position)) if sequence == header.last_command => {
+				net_client.predicted.pop_front();
+				break Some(position);
+			}
+			_ => break None,
+		}
+	};
+
+	// Without a prediction to compare with, only correct the player if they're way off
+	let error = match predicted {
+		Some(position) => server_transform.position - position,
+		None => {
+			if (server_transform.position - transform.position).norm() > PREDICTION_SNAP {
+				*transform = server_transform;
+				net_client.predicted.clear();
+			}
+
+			return;
+		}
+	};
+
+	if error.norm() > PREDICTION_SNAP {
+		*transform = server_transform;
+		net_client.predicted.clear();
+	} else if error.norm() > PREDICTION_TOLERANCE {
+		transform.position += error;
+
+		for (_, position) in net_client.predicted.iter_mut() {
+			*position += error;
+		}
+	}
+}
+
+pub fn net_client_send(_resources: &mut Resources) -> impl Runnable {
+	SystemBuilder::new("net_client_send")
+		.read_resource::<Clients>()
+		.write_resource::<NetClient>()
+		.build(move |_command_buffer, _world, resources, _queries| {
+			let (clients, net_client) = resources;
+
+			if net_client.player_num.is_none() {
+				return;
+			}
+
+			let command = match clients.local() {
+				Some(client) => client.command.clone(),
+				None => return,
+			};
+
+			net_client.sequence += 1;
+			send_message(
+				&net_client.socket,
+				net_client.server,
+				&ClientMessage::Command {
+					sequence: net_client.sequence,
+					command,
+				},
+			);
+		})
+}
+
+/// Remembers where the local player ended up after the last command, so it can be compared
+/// with the server's position later.
+pub fn net_client_predict(_resources: &mut Resources) -> impl Runnable {
+	SystemBuilder::new("net_client_predict")
+		.read_resource::<Clients>()
+		.write_resource::<NetClient>()
+		.with_query(<&Transform>::query())
+		.build(move |_command_buffer, world, resources, query| {
+			let (clients, net_client) = resources;
+
+			if let Some(transform) = clients
+				.local_entity()
+				.and_then(|entity| query.get(world, entity).ok())
+			{
+				let sequence = net_client.sequence;
+				net_client
+					.predicted
+					.push_back((sequence, transform.position));
+
+				if net_client.predicted.len() > MAX_PREDICTED {
+					net_client.predicted.pop_front();
+				}
+			}
+		})
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::doom::testing::{self, DOOR_MAP};
+	use std::thread::sleep;
+
+	fn player_position(world: &World, resources: &Resources, player_num: usize) -> Vector3<f32> {
+		let clients = <Read<Clients>>::fetch(resources);
+		let entity = clients
+			.clients
+			.iter()
+			.find(|client| client.player_num == player_num)
+			.and_then(|client| client.entity)
+			.expect("Player has no entity");
+		<&Transform>::query().get(world, entity).unwrap().position
+	}
+
+	#[test]
+	fn loopback() {
+		let (mut server_world, mut server_resources) = testing::init();
+		let mut server_systems = testing::update_systems(&mut server_resources);
+		let options = GameOptions {
+			players: 1,
+			..GameOptions::default()
+		};
+		new_game(DOOR_MAP, options, &mut server_world, &mut server_resources);
+		host(0, &mut server_resources);
+		let port = server_resources
+			.get::<NetServer>()
+			.expect("Couldn't host game")
+			.socket
+			.local_addr()
+			.unwrap()
+			.port();
+
+		let (mut client_world, mut client_resources) = testing::init();
+		let mut client_systems = testing::update_systems(&mut client_resources);
+		connect(
+			Some(&format!("127.0.0.1:{}", port)),
+			&mut client_world,
+			&mut client_resources,
+		);
+		assert!(client_resources.contains::<NetClient>());
+
+		testing::walk_forward(&mut client_resources);
+
+		for _ in 0..200 {
+			server_systems.execute(&mut server_world, &mut server_resources);
+			client_systems.execute(&mut client_world, &mut client_resources);
+			sleep(Duration::from_millis(1));
+		}
+
+		// The client joined as player 2 and loaded the server's map
+		{
+			let net_client = client_resources.get::<NetClient>().unwrap();
+			assert_eq!(net_client.player_num, Some(2));
+			assert_eq!(net_client.map.as_deref(), Some("map01.map"));
+		}
+
+		// Player 2 starts at (64, 64) facing east, and the server moved them with the
+		// client's commands
+		let server_position = player_position(&server_world, &server_resources, 2);
+		assert!(
+			server_position[0] > 100.0,
+			"Player didn't move on the server: {:?}",
+			server_position
+		);
+
+		// The snapshots gave the client its own player entity, which moved along
+		let client_position = player_position(&client_world, &client_resources, 2);
+		assert!(
+			client_position[0] > 100.0,
+			"Player didn't move on the client: {:?}",
+			client_position
+		);
+		assert!((client_position - server_position).norm() < 64.0);
+	}
+}
@@ -0,0 +1,160 @@
+use crate::doom::assets::map::ThingFlags;
+use clap::{Arg, ArgMatches};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Skill {
+	Baby = 1,
+	Easy = 2,
+	Medium = 3,
+	Hard = 4,
+	Nightmare = 5,
+}
+
+impl Skill {
+	/// Returns the flag that things must have to be spawned on this skill level.
+	#[inline]
+	pub fn thing_flag(self) -> ThingFlags {
+		match self {
+			Skill::Baby | Skill::Easy => ThingFlags::EASY,
+			Skill::Medium => ThingFlags::NORMAL,
+			Skill::Hard | Skill::Nightmare => ThingFlags::HARD,
+		}
+	}
+
+	/// Returns the factor that ammo pickups are multiplied by on this skill level.
+	#[inline]
+	pub fn ammo_multiplier(self) -> i32 {
+		match self {
+			Skill::Baby | Skill::Nightmare => 2,
+			_ => 1,
+		}
+	}
+}
+
+impl Default for Skill {
+	#[inline]
+	fn default() -> Self {
+		Skill::Medium
+	}
+}
+
+/// Rules for deathmatch games.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct DeathmatchOptions {
+	/// Weapons are removed when picked up and respawn like other items,
+	/// instead of staying on the floor.
+	pub alt_death: bool,
+
+	/// The map ends when a player reaches this many frags.
+	pub frag_limit: Option<i32>,
+
+	/// The map ends after this much time has passed.
+	pub time_limit: Option<Duration>,
+}
+
+/// Resource holding the options that a game was started with.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct GameOptions {
+	pub skill: Skill,
+	pub no_monsters: bool,
+	pub fast_monsters: bool,
+	pub respawn_monsters: bool,
+	pub deathmatch: Option<DeathmatchOptions>,
+
+	/// The number of players taking part in the game. Player 1 is played on this machine,
+	/// the others take part by connecting to it over the network.
+	pub players: usize,
+
+	/// Seed for the game's random number generator. If not given, a random seed is used.
+	pub seed: Option<u64>,
+}
+
+impl GameOptions {
+	/// Returns whether more than one player is taking part in the game, or it is a deathmatch.
+	/// Some things only appear in multiplayer games, and items are shared between players.
+	#[inline]
+	pub fn is_multiplayer(&self) -> bool {
+		self.players > 1 || self.deathmatch.is_some()
+	}
+
+	/// Arguments for setting the game options, shared by the command line and the `new` command.
+	pub fn args() -> [Arg<'static, 'static>; 10] {
+		[
+			Arg::with_name("skill")
+				.help("Skill level, from 1 (easiest) to 5 (Nightmare)")
+				.long("skill")
+				.value_name("SKILL")
+				.possible_values(&["1", "2", "3", "4", "5"]),
+			Arg::with_name("nomonsters")
+				.help("Don't spawn any monsters")
+				.long("nomonsters"),
+			Arg::with_name("fast")
+				.help("Make monsters attack faster")
+				.long("fast"),
+			Arg::with_name("respawn")
+				.help("Make dead monsters come back to life")
+				.long("respawn"),
+			Arg::with_name("deathmatch")
+				.help("Play deathmatch, with weapons staying on the floor")
+				.long("deathmatch"),
+			Arg::with_name("altdeath")
+				.help("Play deathmatch, with weapons respawning like other items")
+				.long("altdeath"),
+			Arg::with_name("fraglimit")
+				.help("End deathmatch maps when a player reaches this many frags")
+				.long("fraglimit")
+				.value_name("FRAGS"),
+			Arg::with_name("timelimit")
+				.help("End deathmatch maps after this many minutes")
+				.long("timelimit")
+				.value_name("MINUTES"),
+			Arg::with_name("players")
+				.help("Number of players taking part in the game, all but the first join over the network")
+				.long("players")
+				.value_name("PLAYERS")
+				.possible_values(&["1", "2", "3", "4"]),
+			Arg::with_name("seed")
+				.help("Seed for the game's random number generator")
+				.long("seed")
+				.value_name("SEED"),
+		]
+	}
+
+	pub fn from_arg_matches(matches: &ArgMatches) -> GameOptions {
+		let skill = match matches.value_of("skill") {
+			Some("1") => Skill::Baby,
+			Some("2") => Skill::Easy,
+			Some("4") => Skill::Hard,
+			Some("5") => Skill::Nightmare,
+			_ => Skill::Medium,
+		};
+
+		let deathmatch = if matches.is_present("deathmatch") || matches.is_present("altdeath") {
+			Some(DeathmatchOptions {
+				alt_death: matches.is_present("altdeath"),
+				frag_limit: matches.value_of("fraglimit").and_then(|x| x.parse().ok()),
+				time_limit: matches
+					.value_of("timelimit")
+					.and_then(|x| x.parse::<u64>().ok())
+					.map(|minutes| Duration::from_secs(minutes * 60)),
+			})
+		} else {
+			None
+		};
+
+		GameOptions {
+			skill,
+			no_monsters: matches.is_present("nomonsters"),
+			fast_monsters: matches.is_present("fast") || skill == Skill::Nightmare,
+			respawn_monsters: matches.is_present("respawn") || skill == Skill::Nightmare,
+			deathmatch,
+			players: matches
+				.value_of("players")
+				.and_then(|x| x.parse().ok())
+				.unwrap_or(1),
+			seed: matches.value_of("seed").and_then(|x| x.parse().ok()),
+		}
+	}
+}
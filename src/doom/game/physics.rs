@@ -86,6 +86,8 @@ pub struct Physics {
 pub enum CollisionResponse {
 	Stop,
 	StepSlide,
+	/// Moves through everything without colliding or touching.
+	NoClip,
 }
 
 /// Spawns a Physics component using the specified initial speed,
@@ -248,6 +250,9 @@ pub fn physics(resources: &mut Resources) -> impl Runnable {
 							ignore,
 							delta_time.0,
 						),
+						CollisionResponse::NoClip => {
+							transform.position += physics.velocity * delta_time.0.as_secs_f32();
+						}
 					}
 
 					// Set new position and velocity
@@ -410,7 +415,6 @@ fn step_slide_move<W: EntityStore>(
 		// If entity collided with a step, try to step up first
 		if let Some(step_z) = collision.step_z {
 			let height = step_z - position[2];
-			const MAX_STEP: f32 = 24.5;
 
 			// See if it can move up by the step height
 			if height > 0.0 && height < MAX_STEP {
@@ -475,6 +479,7 @@ fn step_slide_move<W: EntityStore>(
 }
 
 pub const DISTANCE_EPSILON: f32 = 0.03125;
+pub const MAX_STEP: f32 = 24.5;
 
 #[derive(Clone, Copy, Debug)]
 pub struct StepEvent {
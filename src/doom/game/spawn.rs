@@ -3,6 +3,7 @@ use crate::{
 		assets::{AssetHandle, AssetStorage},
 		geometry::{Interval, AABB2, AABB3},
 		quadtree::Quadtree,
+		random::GameRng,
 		spawn::{spawn_helper, SpawnContext},
 		time::{GameTime, Timer},
 	},
@@ -13,11 +14,15 @@ use crate::{
 		},
 		data::DOOMEDNUMS,
 		game::{
+			ai::{Monster, MonsterAmbush, MonsterDef, MonsterRespawn, RESPAWN_TIME},
+			deathmatch::deathmatch_spawn_point,
 			map::{
 				exit::MapExits, AnimState, LinedefDynamic, LinedefRef, MapDynamic, SectorDynamic,
 				SectorRef, SidedefDynamic, SpawnPoint,
 			},
+			options::GameOptions,
 			physics::BoxCollider,
+			stats::CountKill,
 			Transform,
 		},
 	},
@@ -71,12 +76,14 @@ pub fn spawn_things(
 	world: &mut World,
 	resources: &mut Resources,
 ) -> anyhow::Result<()> {
+	let game_options = *<Read<GameOptions>>::fetch(resources);
+
 	for (i, thing) in things.into_iter().enumerate() {
-		if thing.flags.intersects(ThingFlags::DMONLY) {
+		if thing.flags.intersects(ThingFlags::DMONLY) && !game_options.is_multiplayer() {
 			continue;
 		}
 
-		if !thing.flags.intersects(ThingFlags::EASY) {
+		if !thing.flags.intersects(game_options.skill.thing_flag()) {
 			continue;
 		}
 
@@ -93,6 +100,19 @@ pub fn spawn_things(
 			}
 		};
 
+		if game_options.no_monsters {
+			let asset_storage = <Read<AssetStorage>>::fetch(resources);
+			let template = asset_storage.get(&template_handle).unwrap();
+
+			if <&MonsterDef>::query()
+				.iter(&template.world)
+				.next()
+				.is_some()
+			{
+				continue;
+			}
+		}
+
 		// Use NAN to use the default spawn height based on the sector interval
 		let transform = Transform {
 			position: Vector3::new(thing.position[0], thing.position[1], f32::NAN),
@@ -108,9 +128,32 @@ pub fn spawn_things(
 		};
 		resources.insert(sector_interval);
 
-		spawn_entity(world, resources, &template_handle, transform);
+		let entity = spawn_entity(world, resources, &template_handle, transform);
 
 		resources.remove::<SpawnContext<Interval>>();
+
+		// Ambush monsters only react to noises once they can see who made them
+		if thing.flags.intersects(ThingFlags::DEAF) {
+			let mut entry = world.entry(entity).unwrap();
+
+			if entry.get_component::<Monster>().is_ok() {
+				entry.add_component(MonsterAmbush);
+			}
+		}
+
+		// Remember where monsters started, so they can respawn there
+		if game_options.respawn_monsters {
+			let game_time = <Read<GameTime>>::fetch(resources);
+			let mut entry = world.entry(entity).unwrap();
+
+			if entry.get_component::<CountKill>().is_ok() {
+				let transform = *entry.get_component::<Transform>().unwrap();
+				entry.add_component(MonsterRespawn {
+					transform,
+					timer: Timer::new(*game_time, RESPAWN_TIME),
+				});
+			}
+		}
 	}
 
 	Ok(())
@@ -126,16 +169,34 @@ pub fn spawn_player(
 		asset_storage.load::<EntityTemplate>("player.entity")
 	};
 
+	// In deathmatch, start at a random deathmatch spawn point if the map has any
+	let deathmatch_transform = {
+		let (asset_storage, game_options, mut game_rng) =
+			<(Read<AssetStorage>, Read<GameOptions>, Write<GameRng>)>::fetch_mut(resources);
+		let template = asset_storage.get(&template_handle).unwrap();
+		let box_collider = <&BoxCollider>::query()
+			.iter(&template.world)
+			.next()
+			.copied();
+
+		game_options
+			.deathmatch
+			.and(box_collider)
+			.and_then(|box_collider| deathmatch_spawn_point(world, &box_collider, &mut game_rng))
+	};
+
 	// Get spawn point transform
-	let transform = match <(&Transform, &SpawnPoint)>::query()
-		.iter(world)
-		.find_map(|(t, s)| {
-			if s.player_num == player_num {
-				Some(*t)
-			} else {
-				None
-			}
-		}) {
+	let transform = match deathmatch_transform.or_else(|| {
+		<(&Transform, &SpawnPoint)>::query()
+			.iter(world)
+			.find_map(|(t, s)| {
+				if s.player_num == player_num {
+					Some(*t)
+				} else {
+					None
+				}
+			})
+	}) {
 		Some(x) => x,
 		None => bail!("Spawn point for player {} not found", player_num),
 	};
@@ -238,6 +299,7 @@ pub fn spawn_map_entities(
 			entity: linedef_entity,
 			sidedefs,
 			texture_offset: Vector2::new(0.0, 0.0),
+			mapped: false,
 		});
 	}
 
@@ -306,6 +368,9 @@ pub fn spawn_map_entities(
 			entity: sector_entity,
 			light_level: sector.light_level,
 			interval: sector.interval,
+			textures: sector.textures.clone(),
+			special_type: sector.special_type,
+			sound_target: None,
 		});
 	}
 
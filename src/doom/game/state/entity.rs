@@ -1,12 +1,16 @@
 use crate::{
 	common::{
 		assets::{AssetHandle, AssetStorage},
+		random::GameRng,
 		spawn::{spawn_helper, ComponentAccessor, SpawnContext, SpawnFrom, SpawnMergerHandlerSet},
 		time::{GameTime, Timer},
 	},
 	doom::{
 		assets::template::{EntityTemplate, EntityTemplateRef},
-		game::state::{State, StateAction, StateName, StateSystemsRun},
+		game::{
+			options::GameOptions,
+			state::{State, StateAction, StateName, StateSystemsRun},
+		},
 		sound::StartSoundEventEntity,
 	},
 };
@@ -14,7 +18,7 @@ use legion::{
 	systems::{ResourceSet, Runnable},
 	Entity, IntoQuery, Read, Registry, Resources, SystemBuilder, Write,
 };
-use rand::{distributions::Uniform, thread_rng, Rng};
+use rand::{distributions::Uniform, Rng};
 use std::{sync::atomic::Ordering, time::Duration};
 
 #[derive(Clone, Copy, Debug)]
@@ -129,32 +133,55 @@ impl SpawnFrom<NextStateRandomTimeDef> for NextState {
 	fn spawn(
 		component: &NextStateRandomTimeDef,
 		_accessor: ComponentAccessor,
-		_resources: &Resources,
+		resources: &Resources,
 	) -> Self {
+		let mut game_rng = resources.get_mut::<GameRng>().unwrap();
+
 		NextState {
-			time: thread_rng().sample(component.time),
+			time: game_rng.sample(component.time),
 			state: component.state,
 		}
 	}
 }
 
+/// Component for states that only last half as long when monsters are fast.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FastState;
+
+/// Returns how long to wait before going to the next state.
+#[inline]
+pub fn next_state_time(
+	next_state: &NextState,
+	fast_state: Option<&FastState>,
+	game_options: &GameOptions,
+) -> Duration {
+	if fast_state.is_some() && game_options.fast_monsters {
+		next_state.time / 2
+	} else {
+		next_state.time
+	}
+}
+
 pub fn next_entity_state(resources: &mut Resources) -> impl Runnable {
 	let mut handler_set = <Write<SpawnMergerHandlerSet>>::fetch_mut(resources);
+	handler_set.register_clone::<FastState>();
 	handler_set.register_clone::<NextState>();
 	handler_set.register_spawn::<NextStateRandomTimeDef, NextState>();
 
 	SystemBuilder::new("next_entity_state")
+		.read_resource::<GameOptions>()
 		.read_resource::<GameTime>()
-		.with_query(<(&EntityStateEvent, &NextState)>::query())
+		.with_query(<(&EntityStateEvent, &NextState, Option<&FastState>)>::query())
 		.with_query(<&mut State>::query())
 		.build(move |_command_buffer, world, resources, queries| {
-			let game_time = resources;
+			let (game_options, game_time) = resources;
 			let (world0, mut world) = world.split_for_query(&queries.0);
 
-			for (&event, next_state) in queries.0.iter(&world0) {
+			for (&event, next_state, fast_state) in queries.0.iter(&world0) {
 				if let Ok(state) = queries.1.get_mut(&mut world, event.entity) {
 					if let StateAction::None = state.action {
-						state.timer.restart_with(**game_time, next_state.time);
+						let time = next_state_time(next_state, fast_state, game_options);
+						state.timer.restart_with(**game_time, time);
 						state.action = StateAction::Wait(next_state.state);
 					}
 				}
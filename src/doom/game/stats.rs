@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// Statistics about the player's progress through the current map.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct LevelStats {
+	pub kills_found: usize,
+	pub kills_total: usize,
+	pub items_found: usize,
+	pub items_total: usize,
+	pub secrets_found: usize,
+	pub secrets_total: usize,
+}
+
+/// Component for monsters that count towards the kill total.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct CountKill;
+
+/// Component for items that count towards the item total.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+pub struct CountItem;
@@ -438,6 +438,17 @@ impl<'a, W: EntityStore> EntityTracer<'a, W> {
 		trace_touched
 	}
 
+	/// Returns whether an entity standing at `position` with the given height can be seen
+	/// from `point`. Only the map geometry can block the view, not other entities.
+	pub fn is_visible(&self, point: Vector3<f32>, position: Vector3<f32>, height: f32) -> bool {
+		let move_step = Line3::new(point, position - point);
+
+		!self
+			.map
+			.visible_interval(self.map_dynamic, move_step, height)
+			.is_empty()
+	}
+
 	pub fn closest_visible_target(&self, ignore: Option<Entity>, move_step: Line3) -> EntityTrace {
 		let mut trace = EntityTrace {
 			fraction: 1.0,
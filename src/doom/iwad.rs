@@ -1,4 +1,5 @@
-use std::collections::HashMap;
+use crate::doom::game::boss::BossSpecial;
+use std::{collections::HashMap, time::Duration};
 
 #[derive(Clone, Debug)]
 pub struct IWADInfo {
@@ -16,4 +17,6 @@ pub struct MapInfo {
 	pub music: &'static str,
 	pub exit: Option<&'static str>,
 	pub secret_exit: Option<&'static str>,
+	pub par_time: Option<Duration>,
+	pub boss_specials: &'static [BossSpecial],
 }
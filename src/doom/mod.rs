@@ -9,6 +9,8 @@ pub mod game;
 pub mod input;
 pub mod iwad;
 pub mod sound;
+#[cfg(test)]
+pub mod testing;
 pub mod ui;
 
 use crate::{
@@ -39,7 +41,7 @@ use legion::{
 	systems::{Builder, ResourceSet, Runnable},
 	Entity, IntoQuery, Read, Resources, SystemBuilder, Write,
 };
-use std::{any::type_name, fs::File, io::BufWriter, path::Path};
+use std::{any::type_name, fmt::Write as _, fs::File, io::BufWriter, path::Path};
 use vulkano::{
 	sampler::{Filter, MipmapMode, Sampler, SamplerAddressMode},
 	sync::GpuFuture,
@@ -48,26 +50,29 @@ use vulkano::{
 pub fn init_resources(resources: &mut Resources, arg_matches: &ArgMatches) -> anyhow::Result<()> {
 	resources.insert(DeltaTime(FRAME_TIME));
 
-	let dimensions = <Read<DrawTarget>>::fetch(resources).dimensions();
-	resources.insert(UiParams::new(dimensions));
-
-	let device = <Read<RenderContext>>::fetch(resources).device().clone();
-	resources.insert(
-		Sampler::new(
-			device,
-			Filter::Nearest,
-			Filter::Nearest,
-			MipmapMode::Nearest,
-			SamplerAddressMode::Repeat,
-			SamplerAddressMode::Repeat,
-			SamplerAddressMode::Repeat,
-			0.0,
-			1.0,
-			0.0,
-			0.0,
-		)
-		.context("Couldn't create texture sampler")?,
-	);
+	// Video resources don't exist when running headless
+	if resources.contains::<RenderContext>() {
+		let dimensions = <Read<DrawTarget>>::fetch(resources).dimensions();
+		resources.insert(UiParams::new(dimensions));
+
+		let device = <Read<RenderContext>>::fetch(resources).device().clone();
+		resources.insert(
+			Sampler::new(
+				device,
+				Filter::Nearest,
+				Filter::Nearest,
+				MipmapMode::Nearest,
+				SamplerAddressMode::Repeat,
+				SamplerAddressMode::Repeat,
+				SamplerAddressMode::Repeat,
+				0.0,
+				1.0,
+				0.0,
+				0.0,
+			)
+			.context("Couldn't create texture sampler")?,
+		);
+	}
 
 	register_assets(resources);
 	register_components(resources);
@@ -86,8 +91,51 @@ pub fn init_resources(resources: &mut Resources, arg_matches: &ArgMatches) -> an
 		<Read<IWADInfo>>::fetch(resources).map
 	};
 
+	// Pass on the game options given on the command line
+	let mut command = format!("new {}", map);
+
+	if let Some(skill) = arg_matches.value_of("skill") {
+		write!(command, " --skill {}", skill).ok();
+	}
+
+	for flag in ["nomonsters", "fast", "respawn", "deathmatch", "altdeath"] {
+		if arg_matches.is_present(flag) {
+			write!(command, " --{}", flag).ok();
+		}
+	}
+
+	for option in ["fraglimit", "timelimit", "players", "seed"] {
+		if let Some(value) = arg_matches.value_of(option) {
+			write!(command, " --{} {}", option, value).ok();
+		}
+	}
+
+	if let Some(file) = arg_matches.value_of("record") {
+		command = format!("record {:?} {}", file, &command["new ".len()..]);
+	}
+
+	// When joining another game, the server decides on the map and options
+	if let Some(address) = arg_matches.value_of("connect") {
+		command = format!("connect {}", address);
+	}
+
+	// The demo decides on the map and options
+	if let Some(file) = arg_matches.value_of("playdemo") {
+		command = format!("playdemo {:?}", file);
+	} else if let Some(file) = arg_matches.value_of("timedemo") {
+		command = format!("timedemo {:?}", file);
+
+		if let Some(json) = arg_matches.value_of("timedemo-json") {
+			write!(command, " --json {:?}", json).ok();
+		}
+	}
+
 	let command_sender = <Read<Sender<String>>>::fetch(resources);
-	command_sender.send(format!("new {}", map)).ok();
+	command_sender.send(command).ok();
+
+	if arg_matches.is_present("host") {
+		command_sender.send("host".into()).ok();
+	}
 
 	Ok(())
 }
@@ -108,6 +156,12 @@ pub fn add_output_systems(builder: &mut Builder, resources: &mut Resources) -> a
 	Ok(())
 }
 
+/// Output systems to use instead of `add_output_systems` when running without video or sound.
+/// Nothing is shown or played, but events meant for the output must still be cleared.
+pub fn add_headless_output_systems(builder: &mut Builder) {
+	builder.add_system(clear_event::<StartSoundEvent>());
+}
+
 fn load_wads(resources: &mut Resources, arg_matches: &ArgMatches) -> anyhow::Result<()> {
 	// Determine IWAD
 	let mut iter = IWADINFO
@@ -182,6 +236,11 @@ fn load_wads(resources: &mut Resources, arg_matches: &ArgMatches) -> anyhow::Res
 }
 
 pub fn take_screenshot(resources: &Resources) {
+	if !resources.contains::<DrawTarget>() {
+		log::error!("Can't take a screenshot without video.");
+		return;
+	}
+
 	let result = || -> anyhow::Result<_> {
 		let (draw_target, render_context) =
 			<(Read<DrawTarget>, Read<RenderContext>)>::fetch(resources);
@@ -7,7 +7,7 @@ use crate::{
 	},
 	doom::{
 		assets::sound::Sound,
-		game::{client::Client, Transform},
+		game::{client::Clients, Transform},
 	},
 };
 use crossbeam_channel::Sender;
@@ -63,14 +63,16 @@ pub fn start_sound(resources: &mut Resources) -> impl Runnable {
 
 	SystemBuilder::new("start_sound")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.read_resource::<SoundSender>()
 		.write_resource::<Vec<SoundPlaying>>()
 		.with_query(<&Transform>::query())
 		.with_query(<&StartSoundEvent>::query())
 		.build(move |_command_buffer, world, resources, queries| {
-			let (asset_storage, client, sound_sender, sounds_playing) = resources;
-			let client_transform = *queries.0.get(world, client.entity.unwrap()).unwrap();
+			let (asset_storage, clients, sound_sender, sounds_playing) = resources;
+			let listener_transform = clients
+				.local_entity()
+				.and_then(|entity| queries.0.get(world, entity).ok().copied());
 			let (world1, mut world) = world.split_for_query(&queries.1);
 
 			for event in queries.1.iter(&world1) {
@@ -99,8 +101,10 @@ pub fn start_sound(resources: &mut Resources) -> impl Runnable {
 					}
 
 					// Set distance falloff and stereo panning
-					if let Ok(transform) = queries.0.get_mut(&mut world, entity) {
-						let volumes = calculate_volumes(&client_transform, transform);
+					if let (Some(listener_transform), Ok(transform)) =
+						(&listener_transform, queries.0.get_mut(&mut world, entity))
+					{
+						let volumes = calculate_volumes(listener_transform, transform);
 						sound_playing.controller.set_volumes(volumes.into());
 					}
 				}
@@ -116,12 +120,14 @@ pub fn update_sound(resources: &mut Resources) -> impl Runnable {
 	resources.insert(sounds_playing);
 
 	SystemBuilder::new("update_sound")
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.write_resource::<Vec<SoundPlaying>>()
 		.with_query(<&Transform>::query())
 		.build(move |_command_buffer, world, resources, query| {
-			let (client, sounds_playing) = resources;
-			let client_transform = *query.get(world, client.entity.unwrap()).unwrap();
+			let (clients, sounds_playing) = resources;
+			let listener_transform = clients
+				.local_entity()
+				.and_then(|entity| query.get(world, entity).ok().copied());
 
 			sounds_playing.retain(|sound_playing| {
 				if sound_playing.controller.is_done() {
@@ -129,11 +135,13 @@ pub fn update_sound(resources: &mut Resources) -> impl Runnable {
 				}
 
 				// Set distance falloff and stereo panning, if attached to an entity
-				if let Some(transform) = sound_playing
-					.entity
-					.and_then(|entity| query.get(world, entity).ok())
-				{
-					let volumes = calculate_volumes(&client_transform, transform);
+				if let (Some(listener_transform), Some(transform)) = (
+					&listener_transform,
+					sound_playing
+						.entity
+						.and_then(|entity| query.get(world, entity).ok()),
+				) {
+					let volumes = calculate_volumes(listener_transform, transform);
 					sound_playing.controller.set_volumes(volumes.into());
 				}
 
@@ -0,0 +1,360 @@
+//! Helpers for tests that run the game: a WAD with placeholder assets and example maps, and a
+//! headless game to play them in.
+
+use crate::{
+	common::{
+		assets::AssetStorage,
+		input::{Axis, InputState, MouseAxis},
+		spawn::SpawnMergerHandlerSet,
+		time::{increment_game_time, DeltaTime},
+	},
+	doom::{
+		assets::{
+			map::{
+				load::LinedefFlags,
+				write::{LinedefData, MapBuilder, SectorData, SidedefData, ThingData},
+				ThingFlags,
+			},
+			register_assets,
+			wad::{WadLoader, WadWriter},
+		},
+		components::register_components,
+		data::{iwads::IWADINFO, sounds::SOUNDS, FRAME_TIME},
+		game::add_update_systems,
+		input::{bool_values, float_values},
+	},
+};
+use byteorder::{WriteBytesExt, LE};
+use legion::{serialize::Canon, systems::ResourceSet, Registry, Resources, Schedule, World, Write};
+use nalgebra::Vector2;
+use once_cell::sync::Lazy;
+use std::path::{Path, PathBuf};
+use winit::event::{DeviceEvent, DeviceId, Event};
+
+/// A map with a door between two rooms.
+pub const DOOR_MAP: &str = "map01";
+
+/// A map with a lift up to the second room.
+pub const LIFT_MAP: &str = "map02";
+
+/// A map with a teleporter into the second room.
+pub const TELEPORTER_MAP: &str = "map03";
+
+/// Sprites used by the entity templates.
+const SPRITES: &[&str] = &[
+	"ammo", "apbx", "apls", "arm1", "arm2", "bal1", "bal2", "bal7", "bar1", "bbrn", "bexp", "bfe1",
+	"bfe2", "bfgf", "bfgg", "bfs1", "bfug", "bkey", "blud", "bon1", "bon2", "bos2", "bosf", "boss",
+	"bpak", "brok", "brs1", "bsku", "bspi", "cand", "cbra", "cell", "celp", "ceye", "chgf", "chgg",
+	"clip", "col1", "col2", "col3", "col4", "col5", "col6", "colu", "cpos", "csaw", "cybr", "elec",
+	"fatb", "fatt", "fbxp", "fcan", "fire", "fsku", "gor1", "gor2", "gor3", "gor4", "gor5", "hdb1",
+	"hdb2", "hdb3", "hdb4", "hdb5", "hdb6", "head", "ifog", "keen", "laun", "manf", "medi", "mega",
+	"mgun", "misf", "misg", "misl", "pain", "pins", "pinv", "pisf", "pisg", "plas", "play", "plse",
+	"plsf", "plsg", "plss", "pmap", "pob1", "pob2", "pol1", "pol2", "pol3", "pol4", "pol5", "pol6",
+	"poss", "pstr", "puff", "pung", "pvis", "rkey", "rock", "rsku", "sarg", "sawg", "sbox", "sgn2",
+	"shel", "shot", "sht2", "shtf", "shtg", "skel", "skul", "smbt", "smgt", "smit", "smrt", "soul",
+	"spid", "spos", "sswv", "stim", "suit", "tblu", "tfog", "tgrn", "tlmp", "tlp2", "tre1", "tre2",
+	"tred", "troo", "vile", "ykey", "ysku",
+];
+
+/// Sounds used by the entity templates. The other sounds of their group in `SOUNDS` are added
+/// as well.
+const SOUNDS_USED: &[&str] = &[
+	"dsbarexp", "dsbdcls", "dsbdopn", "dsbfg", "dsbgact", "dsbgdth1", "dsbgsit1", "dsbosdth",
+	"dsbospit", "dsbospn", "dsbossit", "dsbrsdth", "dsbrssit", "dsbspact", "dsbspdth", "dsbspsit",
+	"dsbspwlk", "dscacdth", "dscacsit", "dsclaw", "dscybdth", "dscybsit", "dsdbcls", "dsdbload",
+	"dsdbopn", "dsdmact", "dsdmpain", "dsdorcls", "dsdoropn", "dsdshtgn", "dsfirsht", "dsfirxpl",
+	"dsflame", "dsflamst", "dsgetpow", "dshoof", "dsitemup", "dsitmbk", "dskeendt", "dskeenpn",
+	"dskntdth", "dskntsit", "dsmanatk", "dsmandth", "dsmansit", "dsmetal", "dsmnpain", "dsnoway",
+	"dsoof", "dspedth", "dspepain", "dspesit", "dspistol", "dsplasma", "dsplpain", "dspodth1",
+	"dspodth2", "dspopain", "dsposact", "dsposit1", "dspstart", "dspstop", "dspunch", "dsrlaunc",
+	"dsrxplod", "dssawful", "dssawhit", "dssawidl", "dssawup", "dssgtatk", "dssgtdth", "dssgtsit",
+	"dsshotgn", "dsskeact", "dsskeatk", "dsskedth", "dsskepch", "dsskesit", "dsskeswg", "dssklatk",
+	"dsslop", "dsspidth", "dsspisit", "dsssdth", "dssssit", "dsstnmov", "dsswtchn", "dsswtchx",
+	"dstelept", "dsvilact", "dsvilatk", "dsvildth", "dsvilsit", "dsvipain", "dswpnup",
+];
+
+/// Returns the path of a WAD with the example maps, and placeholders for all the other assets
+/// that a game needs. It is written to the temporary directory the first time it is used.
+pub fn test_wad() -> &'static Path {
+	static PATH: Lazy<PathBuf> = Lazy::new(|| {
+		let path = std::env::temp_dir().join(format!("ferret-test-{}.wad", std::process::id()));
+		write_test_wad()
+			.and_then(|wad| wad.save(&path))
+			.expect("Couldn't write test WAD");
+		path
+	});
+
+	&PATH
+}
+
+fn write_test_wad() -> anyhow::Result<WadWriter> {
+	let mut wad = WadWriter::new();
+
+	for name in SPRITES {
+		wad.add(&format!("{}a0", name), patch())?;
+	}
+
+	let mut sounds: Vec<&str> = SOUNDS_USED.to_vec();
+	sounds.extend(
+		SOUNDS
+			.iter()
+			.flat_map(|sound_data| sound_data.sounds)
+			.map(|sound| sound.trim_end_matches(".rawsound")),
+	);
+	sounds.sort_unstable();
+	sounds.dedup();
+
+	for name in sounds {
+		wad.add(name, raw_sound())?;
+	}
+
+	// Textures
+	wad.add("rsky1", patch())?;
+	wad.add("wallpat", patch())?;
+	wad.add("testflat", vec![0; 64 * 64])?;
+
+	let mut pnames = Vec::new();
+	pnames.write_u32::<LE>(1)?;
+	pnames.extend_from_slice(b"WALLPAT\0");
+	wad.add("pnames", pnames)?;
+
+	let mut texture1 = Vec::new();
+	texture1.write_u32::<LE>(1)?;
+	texture1.write_u32::<LE>(8)?;
+	texture1.extend_from_slice(b"TESTWALL");
+	texture1.write_u32::<LE>(0)?;
+	texture1.write_u16::<LE>(64)?;
+	texture1.write_u16::<LE>(128)?;
+	texture1.write_u32::<LE>(0)?;
+	texture1.write_u16::<LE>(1)?;
+	texture1.write_i16::<LE>(0)?;
+	texture1.write_i16::<LE>(0)?;
+	texture1.write_u16::<LE>(0)?;
+	texture1.write_u32::<LE>(0)?;
+	wad.add("texture1", texture1)?;
+
+	door_map().write(DOOR_MAP, &mut wad)?;
+	lift_map().write(LIFT_MAP, &mut wad)?;
+	teleporter_map().write(TELEPORTER_MAP, &mut wad)?;
+
+	Ok(wad)
+}
+
+/// A patch of a single pixel.
+fn patch() -> Vec<u8> {
+	vec![1, 0, 1, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 1, 0, 0, 0, 255]
+}
+
+/// A short silent sound.
+fn raw_sound() -> Vec<u8> {
+	let samples = 16;
+	let mut data = vec![3, 0, 0x11, 0x2B];
+	data.extend_from_slice(&(samples as u32 + 32).to_le_bytes());
+	data.extend(std::iter::repeat(0x80).take(samples + 32));
+	data
+}
+
+fn sector(floor_height: i16, ceiling_height: i16) -> SectorData {
+	SectorData {
+		floor_height,
+		ceiling_height,
+		textures: ["testflat".into(), "testflat".into()],
+		light_level: 160,
+		special_type: 0,
+		sector_tag: 0,
+	}
+}
+
+/// Two square rooms, with a short passage between them in sector 1. The linedefs on either end
+/// of the passage are returned, with their front side facing the rooms.
+fn two_rooms() -> (MapBuilder, [u16; 2]) {
+	let mut map = MapBuilder::new();
+
+	for _ in 0..3 {
+		map.add_sector(sector(0, 128));
+	}
+
+	let vertices: Vec<u16> = [
+		(0, 0),
+		(0, 256),
+		(256, 256),
+		(256, 160),
+		(256, 96),
+		(256, 0),
+		(288, 160),
+		(288, 96),
+		(288, 0),
+		(288, 256),
+		(544, 256),
+		(544, 0),
+	]
+	.iter()
+	.map(|&(x, y)| map.add_vertex(Vector2::new(x, y)))
+	.collect();
+
+	// Walls, running clockwise around their sector
+	for (start, end, sector) in [
+		(0, 1, 0),
+		(1, 2, 0),
+		(2, 3, 0),
+		(4, 5, 0),
+		(5, 0, 0),
+		(3, 6, 1),
+		(7, 4, 1),
+		(8, 7, 2),
+		(6, 9, 2),
+		(9, 10, 2),
+		(10, 11, 2),
+		(11, 8, 2),
+	] {
+		let sidedef = map.add_sidedef(SidedefData {
+			texture_offset: Vector2::zeros(),
+			textures: [None, None, Some("testwall".into())],
+			sector,
+		});
+		map.add_linedef(LinedefData {
+			vertices: [vertices[start], vertices[end]],
+			flags: LinedefFlags::BLOCKING,
+			special_type: 0,
+			sector_tag: 0,
+			sidedefs: [Some(sidedef), None],
+		});
+	}
+
+	let passage_ends = [(3, 4, 0), (7, 6, 2)].map(|(start, end, sector)| {
+		let sidedefs = [sector, 1].map(|sector| {
+			map.add_sidedef(SidedefData {
+				texture_offset: Vector2::zeros(),
+				textures: [Some("testwall".into()), Some("testwall".into()), None],
+				sector,
+			})
+		});
+		map.add_linedef(LinedefData {
+			vertices: [vertices[start], vertices[end]],
+			flags: LinedefFlags::TWOSIDED,
+			special_type: 0,
+			sector_tag: 0,
+			sidedefs: sidedefs.map(Some),
+		})
+	});
+
+	for (position, r#type) in [(Vector2::new(64, 128), 1), (Vector2::new(64, 64), 2)] {
+		map.add_thing(ThingData {
+			position,
+			angle: 0,
+			r#type,
+			flags: ThingFlags::EASY | ThingFlags::NORMAL | ThingFlags::HARD,
+		});
+	}
+
+	(map, passage_ends)
+}
+
+/// The passage is a door, opened by using either side of it.
+pub fn door_map() -> MapBuilder {
+	let (mut map, passage_ends) = two_rooms();
+	map.sectors[1].ceiling_height = 0;
+
+	for index in passage_ends {
+		map.linedefs[index as usize].special_type = 1;
+	}
+
+	map
+}
+
+/// The passage and the second room are raised, and the passage is a lift that is lowered by
+/// using its side in the first room.
+pub fn lift_map() -> MapBuilder {
+	let (mut map, passage_ends) = two_rooms();
+	map.sectors[1].floor_height = 64;
+	map.sectors[1].sector_tag = 1;
+	map.sectors[2].floor_height = 64;
+
+	let linedef = &mut map.linedefs[passage_ends[0] as usize];
+	linedef.special_type = 62;
+	linedef.sector_tag = 1;
+
+	map
+}
+
+/// Walking into the passage teleports the player to the middle of the second room, where a
+/// zombieman is waiting. The zombieman can see the player through the passage, so games on this
+/// map make use of the random number generator.
+pub fn teleporter_map() -> MapBuilder {
+	let (mut map, passage_ends) = two_rooms();
+	map.sectors[2].sector_tag = 2;
+
+	let linedef = &mut map.linedefs[passage_ends[0] as usize];
+	linedef.special_type = 97;
+	linedef.sector_tag = 2;
+
+	for (position, angle, r#type) in [
+		(Vector2::new(416, 128), 0, 14),
+		(Vector2::new(512, 192), 180, 3004),
+	] {
+		map.add_thing(ThingData {
+			position,
+			angle,
+			r#type,
+			flags: ThingFlags::EASY | ThingFlags::NORMAL | ThingFlags::HARD,
+		});
+	}
+
+	map
+}
+
+/// Sets up the resources to run a game without video or sound, with the test WAD loaded.
+pub fn init() -> (World, Resources) {
+	let mut resources = Resources::default();
+
+	// Nothing executes the commands that are bound to inputs
+	let (command_sender, _) = crossbeam_channel::unbounded();
+	resources.insert(InputState::new(
+		bool_values(),
+		float_values(),
+		command_sender,
+	));
+	resources.insert(SpawnMergerHandlerSet::new());
+	resources.insert(Registry::<String>::default());
+	resources.insert(Canon::default());
+	resources.insert(DeltaTime(FRAME_TIME));
+	resources.insert(IWADINFO[0].clone());
+
+	register_assets(&mut resources);
+	register_components(&mut resources);
+
+	{
+		let mut asset_storage = <Write<AssetStorage>>::fetch_mut(&mut resources);
+		let loader = asset_storage
+			.source_mut()
+			.downcast_mut::<WadLoader>()
+			.expect("AssetStorage source was not of type WadLoader");
+		loader.add(test_wad()).expect("Couldn't load test WAD");
+	}
+
+	(World::default(), resources)
+}
+
+/// Builds the schedule that runs one tick of the game, like the main loop does.
+pub fn update_systems(resources: &mut Resources) -> Schedule {
+	let mut builder = Schedule::builder();
+	add_update_systems(&mut builder, resources).expect("Couldn't initialise update systems");
+	builder
+		.add_thread_local_fn(increment_game_time)
+		.flush()
+		.build()
+}
+
+/// Makes the local player walk forward on every tick, by moving the mouse along an axis bound
+/// to walking. The tests never reset the input state, so the movement stays.
+pub fn walk_forward(resources: &mut Resources) {
+	let mut input_state = <Write<InputState>>::fetch_mut(resources);
+	input_state
+		.bindings
+		.bind_axis(Axis::Mouse(MouseAxis::X), ("forward".into(), 1.0));
+	input_state.set_mouse_delta_enabled(true);
+	input_state.process_event(&Event::DeviceEvent {
+		device_id: unsafe { DeviceId::dummy() },
+		event: DeviceEvent::MouseMotion { delta: (1.0, 0.0) },
+	});
+}
@@ -3,13 +3,15 @@ use crate::{
 	doom::{
 		assets::{image::Image, template::AmmoTemplate},
 		game::{
-			client::Client,
-			combat::{weapon::WeaponState, Health},
+			client::Clients,
+			combat::{weapon::WeaponState, Armor, Health},
+			deathmatch::Frags,
+			options::GameOptions,
 		},
-		ui::{UiImage, UiText},
+		ui::{Hidden, UiImage, UiText},
 	},
 };
-use legion::{component, systems::Runnable, IntoQuery, Resources, SystemBuilder};
+use legion::{component, systems::Runnable, Entity, IntoQuery, Resources, SystemBuilder};
 use std::fmt::Write as _;
 
 #[derive(Clone, Debug)]
@@ -21,12 +23,12 @@ pub struct AmmoStat {
 pub fn ammo_stat(_resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("ammo_stat")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.with_query(<(&AmmoStat, &mut UiText)>::query())
 		.with_query(<&WeaponState>::query())
 		.build(move |_command_buffer, world, resources, queries| {
-			let (asset_storage, client) = resources;
-			let client_entity = match client.entity {
+			let (asset_storage, clients) = resources;
+			let client_entity = match clients.local_entity() {
 				Some(e) => e,
 				None => return,
 			};
@@ -68,6 +70,37 @@ pub fn ammo_stat(_resources: &mut Resources) -> impl Runnable {
 		})
 }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ArmorStat;
+
+pub fn armor_stat(_resources: &mut Resources) -> impl Runnable {
+	SystemBuilder::new("armor_stat")
+		.read_resource::<Clients>()
+		.with_query(<&Armor>::query())
+		.with_query(<&mut UiText>::query().filter(component::<ArmorStat>()))
+		.build(move |_command_buffer, world, resources, queries| {
+			let clients = resources;
+			let client_entity = match clients.local_entity() {
+				Some(e) => e,
+				None => return,
+			};
+
+			let armor = queries
+				.0
+				.get(world, client_entity)
+				.ok()
+				.map(|armor| armor.current);
+
+			for ui_text in queries.1.iter_mut(world) {
+				ui_text.text.clear();
+
+				if let Some(armor) = armor {
+					write!(ui_text.text, "{:3}%", armor).ok();
+				}
+			}
+		})
+}
+
 #[derive(Clone, Debug)]
 pub struct ArmsStat {
 	pub weapons: Vec<String>,
@@ -77,14 +110,14 @@ pub struct ArmsStat {
 pub fn arms_stat(_resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("arms_stat")
 		.read_resource::<AssetStorage>()
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.with_query(<&WeaponState>::query())
 		.with_query(<(&ArmsStat, &mut UiImage)>::query())
 		.build(move |_command_buffer, world, resources, queries| {
-			let (asset_storage, client) = resources;
+			let (asset_storage, clients) = resources;
 			let (mut world1, world) = world.split_for_query(&queries.1);
 
-			let client_entity = match client.entity {
+			let client_entity = match clients.local_entity() {
 				Some(e) => e,
 				None => return,
 			};
@@ -109,17 +142,60 @@ pub fn arms_stat(_resources: &mut Resources) -> impl Runnable {
 		})
 }
 
+/// Component for the parts of the status bar that show the weapons in the inventory.
+/// They are hidden in deathmatch, to make room for the frag count.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ArmsPanel;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FragsStat;
+
+pub fn frags_stat(_resources: &mut Resources) -> impl Runnable {
+	SystemBuilder::new("frags_stat")
+		.read_resource::<Clients>()
+		.read_resource::<GameOptions>()
+		.with_query(<&Frags>::query())
+		.with_query(<&mut UiText>::query().filter(component::<FragsStat>()))
+		.with_query(<(Entity, Option<&Hidden>)>::query().filter(component::<ArmsPanel>()))
+		.build(move |command_buffer, world, resources, queries| {
+			let (clients, game_options) = resources;
+			let deathmatch = game_options.deathmatch.is_some();
+
+			let frags = clients
+				.local_entity()
+				.and_then(|entity| queries.0.get(world, entity).ok())
+				.map(|&Frags(frags)| frags)
+				.filter(|_| deathmatch);
+
+			for ui_text in queries.1.iter_mut(world) {
+				ui_text.text.clear();
+
+				if let Some(frags) = frags {
+					write!(ui_text.text, "{:2}", frags).ok();
+				}
+			}
+
+			for (&entity, hidden) in queries.2.iter(world) {
+				if deathmatch && hidden.is_none() {
+					command_buffer.add_component(entity, Hidden);
+				} else if !deathmatch && hidden.is_some() {
+					command_buffer.remove_component::<Hidden>(entity);
+				}
+			}
+		})
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub struct HealthStat;
 
 pub fn health_stat(_resources: &mut Resources) -> impl Runnable {
 	SystemBuilder::new("health_stat")
-		.read_resource::<Client>()
+		.read_resource::<Clients>()
 		.with_query(<&Health>::query())
 		.with_query(<&mut UiText>::query().filter(component::<HealthStat>()))
 		.build(move |_command_buffer, world, resources, queries| {
-			let client = resources;
-			let client_entity = match client.entity {
+			let clients = resources;
+			let client_entity = match clients.local_entity() {
 				Some(e) => e,
 				None => return,
 			};
@@ -0,0 +1,209 @@
+use crate::{
+	common::{
+		assets::AssetStorage,
+		spawn::spawn_helper,
+		time::{GameTime, Timer},
+	},
+	doom::{
+		assets::{process_assets, template::EntityTemplate},
+		data::FRAME_TIME,
+		game::{client::Clients, map::MapDynamic, stats::LevelStats},
+		ui::{UiImage, UiText, UiTransform},
+	},
+};
+use crossbeam_channel::Sender;
+use legion::{
+	systems::{ResourceSet, Runnable},
+	Entity, IntoQuery, Read, Resources, SystemBuilder, World, Write,
+};
+use std::{fmt::Write as _, time::Duration};
+
+/// Resource that is present while the intermission screen is shown at the end of a map.
+#[derive(Clone, Debug)]
+pub struct Intermission {
+	pub next_map: Option<String>,
+	pub entities: Vec<Entity>,
+	pub timer: Timer,
+}
+
+/// Component for UI text that shows one of the statistics of the finished map.
+#[derive(Clone, Copy, Debug)]
+pub enum IntermissionStat {
+	Kills,
+	Items,
+	Secrets,
+	Time,
+	ParTime,
+}
+
+/// Component for UI images that are centered horizontally on their position.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IntermissionCentered;
+
+/// Component for the UI image that shows the name of the finished map.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct IntermissionMapName;
+
+/// Shows the intermission screen for the current map, and pauses the game until the player
+/// moves on to `next_map`.
+pub fn start_intermission(next_map: &str, world: &mut World, resources: &mut Resources) {
+	if resources.contains::<Intermission>() {
+		return;
+	}
+
+	let (template_handle, map_name_handle) = {
+		let mut asset_storage = <Write<AssetStorage>>::fetch_mut(resources);
+		let map_name_handle = <&MapDynamic>::query()
+			.iter(world)
+			.next()
+			.and_then(|map_dynamic| asset_storage.name_for(&map_dynamic.map))
+			.and_then(map_name_patch)
+			.map(|name| asset_storage.load(&name));
+
+		(
+			asset_storage.load::<EntityTemplate>("intermission.entity"),
+			map_name_handle,
+		)
+	};
+
+	let entities: Vec<Entity> = {
+		let asset_storage = <Read<AssetStorage>>::fetch(resources);
+		let template = asset_storage.get(&template_handle).unwrap();
+		spawn_helper(&template.world, world, resources)
+			.into_values()
+			.collect()
+	};
+
+	process_assets(resources);
+
+	let (asset_storage, game_time, level_stats) =
+		<(Read<AssetStorage>, Read<GameTime>, Read<LevelStats>)>::fetch(resources);
+	let par_time = <&MapDynamic>::query()
+		.iter(world)
+		.next()
+		.and_then(|map_dynamic| asset_storage.get(&map_dynamic.map).unwrap().par_time);
+
+	for &entity in &entities {
+		let mut entry = world.entry(entity).unwrap();
+
+		if entry.get_component::<IntermissionMapName>().is_ok() {
+			if let Some(handle) = &map_name_handle {
+				entry.add_component(UiImage {
+					image: handle.clone(),
+				});
+			}
+		}
+
+		// Size images to fit their contents
+		if let Some(image) = entry
+			.get_component::<UiImage>()
+			.ok()
+			.and_then(|ui_image| asset_storage.get(&ui_image.image))
+		{
+			let size = image.size();
+			let centered = entry.get_component::<IntermissionCentered>().is_ok();
+			let ui_transform = entry.get_component_mut::<UiTransform>().unwrap();
+			ui_transform.size = size;
+
+			if centered {
+				ui_transform.position[0] -= (size[0] * 0.5).floor();
+			}
+		}
+
+		if let Ok(&stat) = entry.get_component::<IntermissionStat>() {
+			let ui_text = entry.get_component_mut::<UiText>().unwrap();
+			ui_text.text.clear();
+
+			match stat {
+				IntermissionStat::Kills => write!(
+					ui_text.text,
+					"{}%",
+					percentage(level_stats.kills_found, level_stats.kills_total)
+				),
+				IntermissionStat::Items => write!(
+					ui_text.text,
+					"{}%",
+					percentage(level_stats.items_found, level_stats.items_total)
+				),
+				IntermissionStat::Secrets => write!(
+					ui_text.text,
+					"{}%",
+					percentage(level_stats.secrets_found, level_stats.secrets_total)
+				),
+				IntermissionStat::Time => write_time(&mut ui_text.text, game_time.0),
+				IntermissionStat::ParTime => match par_time {
+					Some(par_time) => write_time(&mut ui_text.text, par_time),
+					None => Ok(()),
+				},
+			}
+			.ok();
+		}
+	}
+
+	let intermission = Intermission {
+		next_map: Some(next_map.to_owned()),
+		entities,
+		timer: Timer::new(*game_time, 35 * FRAME_TIME),
+	};
+
+	drop(asset_storage);
+	drop(game_time);
+	drop(level_stats);
+	resources.insert(intermission);
+}
+
+pub fn intermission(_resources: &mut Resources) -> impl Runnable {
+	SystemBuilder::new("intermission")
+		.read_resource::<Clients>()
+		.read_resource::<Sender<String>>()
+		.read_resource::<GameTime>()
+		.write_resource::<Intermission>()
+		.build(move |_command_buffer, _world, resources, _queries| {
+			let (clients, command_sender, game_time, intermission) = resources;
+
+			if !intermission.timer.is_elapsed(**game_time) {
+				return;
+			}
+
+			// Move on when any player presses attack or use
+			let pressed = clients.clients.iter().any(|client| {
+				(client.command.attack && !client.previous_command.attack)
+					|| (client.command.r#use && !client.previous_command.r#use)
+			});
+
+			if pressed {
+				if let Some(next_map) = intermission.next_map.take() {
+					command_sender.send(format!("change {}", next_map)).ok();
+				}
+			}
+		})
+}
+
+/// Returns the name of the patch containing the title of a map, if there is one.
+fn map_name_patch(map: &str) -> Option<String> {
+	let map = map.strip_suffix(".map").unwrap_or(map);
+
+	if let Some(number) = map.strip_prefix("map") {
+		let number: usize = number.parse().ok()?;
+		Some(format!("cwilv{:02}.patch", number.checked_sub(1)?))
+	} else {
+		let (episode, map) = map.strip_prefix('e')?.split_once('m')?;
+		let episode: usize = episode.parse().ok()?;
+		let map: usize = map.parse().ok()?;
+		Some(format!(
+			"wilv{}{}.patch",
+			episode.checked_sub(1)?,
+			map.checked_sub(1)?
+		))
+	}
+}
+
+#[inline]
+fn percentage(found: usize, total: usize) -> usize {
+	found * 100 / total.max(1)
+}
+
+fn write_time(text: &mut String, time: Duration) -> std::fmt::Result {
+	let seconds = time.as_secs();
+	write!(text, "{}:{:02}", seconds / 60, seconds % 60)
+}
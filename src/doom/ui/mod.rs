@@ -10,6 +10,7 @@ use nalgebra::Vector2;
 use serde::{Deserialize, Serialize};
 
 pub mod hud;
+pub mod intermission;
 
 #[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
 pub struct UiTransform {
@@ -3,14 +3,17 @@
 mod common;
 mod doom;
 
-use crate::common::{
-	assets::AssetStorage,
-	console::{execute_commands, update_console},
-	dirs::config_dir,
-	input::{InputState, RepeatTracker},
-	spawn::{spawn_helper, SpawnMergerHandlerSet},
-	time::increment_game_time,
-	video::{DrawTarget, PresentTarget, RenderContext},
+use crate::{
+	common::{
+		assets::AssetStorage,
+		console::{execute_commands, update_console},
+		dirs::config_dir,
+		input::{InputState, RepeatTracker},
+		spawn::{spawn_helper, SpawnMergerHandlerSet},
+		time::increment_game_time,
+		video::{DrawTarget, PresentTarget, RenderContext},
+	},
+	doom::game::demo::{finish_timedemo, DemoPlayer, Timedemo},
 };
 use anyhow::Context;
 use clap::{App, Arg};
@@ -55,6 +58,52 @@ fn main() -> anyhow::Result<()> {
 				.long("map")
 				.value_name("NAME"),
 		)
+		.args(&doom::game::options::GameOptions::args())
+		.arg(
+			Arg::with_name("host")
+				.help("Let other players connect to the game")
+				.long("host")
+				.conflicts_with("connect"),
+		)
+		.arg(
+			Arg::with_name("connect")
+				.help("Join the game on a server instead of starting a new one")
+				.long("connect")
+				.value_name("ADDRESS"),
+		)
+		.arg(
+			Arg::with_name("record")
+				.help("Record the game to a demo file")
+				.long("record")
+				.value_name("FILE")
+				.conflicts_with("connect"),
+		)
+		.arg(
+			Arg::with_name("playdemo")
+				.help("Play back a demo file instead of starting a new game")
+				.long("playdemo")
+				.value_name("FILE")
+				.conflicts_with_all(&["connect", "record"]),
+		)
+		.arg(
+			Arg::with_name("timedemo")
+				.help("Play back a demo file as fast as possible, and report how long it took")
+				.long("timedemo")
+				.value_name("FILE")
+				.conflicts_with_all(&["connect", "record", "playdemo"]),
+		)
+		.arg(
+			Arg::with_name("timedemo-json")
+				.help("Also write the results of the timedemo to a file, as JSON")
+				.long("timedemo-json")
+				.value_name("FILE")
+				.requires("timedemo"),
+		)
+		.arg(
+			Arg::with_name("headless")
+				.help("Run the game without video or sound, taking commands from stdin")
+				.long("headless"),
+		)
 		.arg(
 			Arg::with_name("log-level")
 				.help("Highest log level to display")
@@ -64,6 +113,7 @@ fn main() -> anyhow::Result<()> {
 		)
 		.get_matches();
 
+	let headless = arg_matches.is_present("headless");
 	let (log_sender, log_receiver) = crossbeam_channel::unbounded();
 	common::logger::init(&arg_matches, log_sender)?;
 	log::info!("Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut aliquip ex ea commodo consequat. Duis aute irure dolor in reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla pariatur. Excepteur sint occaecat cupidatat non proident, sunt in culpa qui officia deserunt mollit anim id est laborum.");
@@ -75,22 +125,28 @@ fn main() -> anyhow::Result<()> {
 	command_sender.send("exec config.cfg".into()).unwrap();
 	resources.insert(command_sender.clone());
 
-	let event_loop = EventLoop::new();
-	let (render_context, _debug_callback) =
-		RenderContext::new(&event_loop).context("Could not create RenderContext")?;
-	let present_target = PresentTarget::new(
-		render_context.surface().clone(),
-		render_context.device().clone(),
-	)
-	.context("Couldn't create PresentTarget")?;
-	let draw_target = DrawTarget::new(&render_context, present_target.dimensions())
-		.context("Couldn't create DrawTarget")?;
-
-	resources.insert(draw_target);
-	resources.insert(present_target);
-	resources.insert(render_context);
-
-	resources.insert(common::sound::init()?);
+	// Set up video and sound, unless running headless
+	let (event_loop, _debug_callback) = if headless {
+		(None, None)
+	} else {
+		let event_loop = EventLoop::new();
+		let (render_context, debug_callback) =
+			RenderContext::new(&event_loop).context("Could not create RenderContext")?;
+		let present_target = PresentTarget::new(
+			render_context.surface().clone(),
+			render_context.device().clone(),
+		)
+		.context("Couldn't create PresentTarget")?;
+		let draw_target = DrawTarget::new(&render_context, present_target.dimensions())
+			.context("Couldn't create DrawTarget")?;
+
+		resources.insert(draw_target);
+		resources.insert(present_target);
+		resources.insert(render_context);
+
+		resources.insert(common::sound::init()?);
+		(Some(event_loop), Some(debug_callback))
+	};
 	resources.insert(InputState::new(
 		doom::input::bool_values(),
 		doom::input::float_values(),
@@ -104,8 +160,11 @@ fn main() -> anyhow::Result<()> {
 
 	#[rustfmt::skip]
 	let mut input_systems = {
-		Schedule::builder()
-			.add_thread_local(process_events(event_loop)).flush()
+		let mut builder = Schedule::builder();
+		if let Some(event_loop) = event_loop {
+			builder.add_thread_local(process_events(event_loop)).flush();
+		}
+		builder
 			.add_thread_local_fn(execute_commands(
 				command_receiver,
 				doom::commands::commands(),
@@ -119,16 +178,22 @@ fn main() -> anyhow::Result<()> {
 		doom::game::add_update_systems(&mut builder, &mut resources)
 			.context("Couldn't initialise update systems")?;
 		builder
-			.add_thread_local(increment_game_time()).flush()
+			.add_thread_local_fn(increment_game_time).flush()
 			.build()
 	};
 
 	#[rustfmt::skip]
 	let mut output_systems = {
 		let mut builder = Schedule::builder();
-		builder.add_system(update_console(log_receiver));
-		doom::add_output_systems(&mut builder, &mut resources)
-			.context("Couldn't initialise output systems")?;
+		if headless {
+			// Nothing to show the log on, it only goes to stdout
+			drop(log_receiver);
+			doom::add_headless_output_systems(&mut builder);
+		} else {
+			builder.add_system(update_console(log_receiver));
+			doom::add_output_systems(&mut builder, &mut resources)
+				.context("Couldn't initialise output systems")?;
+		}
 		builder.build()
 	};
 
@@ -183,17 +248,26 @@ fn main() -> anyhow::Result<()> {
 		let mut delta;
 		let mut new_time;
 
+		// A timedemo runs as fast as it can
+		let timedemo = resources.contains::<Timedemo>();
+
+		// There is no video to keep up with, so wait for the next game frame instead
+		if headless && !timedemo && leftover_time < doom::data::FRAME_TIME {
+			std::thread::sleep(doom::data::FRAME_TIME - leftover_time);
+		}
+
 		// Busy-loop until there is at least a millisecond of delta
 		while {
 			new_time = Instant::now();
 			delta = new_time - old_time;
-			delta.as_millis() < 1
+			!timedemo && delta.as_millis() < 1
 		} {}
 
 		old_time = new_time;
 		//println!("{} fps", 1.0/delta.as_secs_f32());
 
 		input_systems.execute(&mut world, &mut resources);
+		let input_time = new_time.elapsed();
 
 		if resources.contains::<ShouldQuit>() {
 			break;
@@ -201,17 +275,32 @@ fn main() -> anyhow::Result<()> {
 
 		// Run game frames
 		leftover_time += delta;
+		let mut update_time = None;
 
-		if leftover_time >= doom::data::FRAME_TIME {
+		if timedemo || leftover_time >= doom::data::FRAME_TIME {
+			let update_start = Instant::now();
 			update_systems.execute(&mut world, &mut resources);
-			leftover_time -= doom::data::FRAME_TIME;
+			leftover_time = leftover_time.saturating_sub(doom::data::FRAME_TIME);
 
 			let mut input_state = <Write<InputState>>::fetch_mut(&mut resources);
 			input_state.reset();
+			update_time = Some(update_start.elapsed());
 		}
 
 		// Update video and sound
+		let output_start = Instant::now();
 		output_systems.execute(&mut world, &mut resources);
+		let output_time = output_start.elapsed();
+
+		if timedemo {
+			if resources.contains::<DemoPlayer>() {
+				if let Some(mut timedemo) = resources.get_mut::<Timedemo>() {
+					timedemo.add_frame(new_time, input_time, update_time, output_time);
+				}
+			} else {
+				finish_timedemo(&mut resources);
+			}
+		}
 	}
 
 	// Write configuration